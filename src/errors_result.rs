@@ -8,6 +8,7 @@ pub enum VenumError {
     Generic { msg: String }, // TODO: can we get a nice error message here as well?
     Parsing(ParseError),
     Conversion(ConversionError),
+    Arithmetic(ArithmeticError),
 }
 
 // The Error derive also implements the Display trait!
@@ -21,6 +22,26 @@ pub enum ParseError {
         target_type: String,
         details: Option<String>,
     },
+
+    #[error(
+        "Column {column_index} ({column_name:?}) can't parse {src_value:?}. Details: {details:?}"
+    )]
+    ColumnFailed {
+        column_index: usize,
+        column_name: String,
+        src_value: String,
+        details: Box<VenumError>,
+    },
+
+    #[error("Column {column_index} ({column_name:?}) is not nullable, but got {src_value:?}")]
+    NullInNonNullableColumn {
+        column_index: usize,
+        column_name: String,
+        src_value: String,
+    },
+
+    #[error("Record has {actual} values, but the schema has {expected} columns")]
+    RecordLengthMismatch { expected: usize, actual: usize },
 }
 
 // The Error derive also implements the Display trait!
@@ -44,4 +65,55 @@ pub enum ConversionError {
     NotRepresentableAsDecimal { src_type: String, src_value: String },
 }
 
+// The Error derive also implements the Display trait!
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum ArithmeticError {
+    #[error("{lhs:?} {op} {rhs:?} overflows {target_type:?}")]
+    Overflow {
+        op: String,
+        lhs: Value,
+        rhs: Value,
+        target_type: ValueType,
+    },
+
+    #[error("{lhs:?} {op} {rhs:?} is a division by zero")]
+    DivisionByZero { op: String, lhs: Value, rhs: Value },
+
+    #[error("{op} is not supported for {lhs:?} and {rhs:?}")]
+    UnsupportedOperands { op: String, lhs: Value, rhs: Value },
+
+    #[error("{op} of {src:?} overflows {target_type:?}")]
+    UnaryOverflow {
+        op: String,
+        src: Value,
+        target_type: ValueType,
+    },
+
+    #[error("{op} is not supported for {src:?}")]
+    UnsupportedOperand { op: String, src: Value },
+}
+
+// Display comes from strum, Debug is derived
+impl std::error::Error for VenumError {}
+
+// Needed to use Value as a serde Deserializer
+#[cfg(feature = "serde")]
+impl serde::de::Error for VenumError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        VenumError::Generic {
+            msg: msg.to_string(),
+        }
+    }
+}
+
+// Needed for the serde Serializer that produces Values
+#[cfg(feature = "serde")]
+impl serde::ser::Error for VenumError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        VenumError::Generic {
+            msg: msg.to_string(),
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, VenumError>;
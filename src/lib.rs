@@ -1,8 +1,32 @@
 pub mod errors_result;
 pub mod value;
+pub mod value_arithmetic;
+pub mod value_bool_format;
+pub mod value_comparisons;
+pub mod value_conversion_policy;
 pub mod value_conversions;
+pub mod value_date_patterns;
+pub mod value_decimal_options;
+pub mod value_duration;
+pub mod value_epoch;
+#[cfg(feature = "serde")]
+pub mod value_impl_deserializer_for_value;
 pub mod value_impl_from_t_for_value;
 pub mod value_impl_from_value_type_for_value;
+pub mod value_impl_ops_for_value;
 pub mod value_impl_try_from_value_for_t;
 pub mod value_impl_try_from_value_ref_for_t;
+pub mod value_infer;
+pub mod value_number_format;
+pub mod value_parse_options;
+pub mod value_schema;
+#[cfg(feature = "serde")]
+pub mod value_serde;
+#[cfg(feature = "serde_json")]
+pub mod value_serde_json;
+#[cfg(feature = "serde")]
+pub mod value_serializer;
 pub mod value_type;
+
+#[cfg(feature = "serde")]
+pub use value_serializer::to_value;
@@ -1,12 +1,17 @@
+use std::collections::BTreeMap;
 use std::convert::From;
 use std::str::FromStr;
 
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 use rust_decimal::{prelude::FromPrimitive, Decimal};
 use strum_macros::Display; // used to generate names for the enum variants. Used only for error messages (as of now).
 
 use crate::{
     errors_result::{ConversionError, ParseError, Result, VenumError},
+    value_duration,
+    value_epoch::EpochUnit,
+    value_parse_options::{EmptyPolicy, ParseOptions},
     value_type::ValueType,
 };
 
@@ -14,6 +19,21 @@ const VAL_ENUM_NAME: &str = "Value::";
 const ENUM_VAR_ND: &str = "NaiveDate";
 const ENUM_VAR_NDT: &str = "NaiveDateTime";
 const ENUM_VAR_DT: &str = "DateTime";
+const ENUM_VAR_NT: &str = "NaiveTime";
+const ENUM_VAR_DUR: &str = "Duration";
+pub(crate) const DEFAULT_LIST_DELIMITER: &str = "|";
+const LIST_ESCAPE: char = '\\';
+const ENUM_VAR_BYTES: &str = "Bytes";
+#[cfg(feature = "uuid")]
+const ENUM_VAR_UUID: &str = "Uuid";
+
+/// The string representation of `Value::Bytes`, i.e. lower case hex like "cafe" or (standard, padded) base64 like "yv4=".
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BytesEncoding {
+    #[default]
+    Hex,
+    Base64,
+}
 
 #[derive(Display, Debug, Clone, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -38,6 +58,22 @@ pub enum Value {
     NaiveDate(NaiveDate),
     NaiveDateTime(NaiveDateTime),
     DateTime(DateTime<FixedOffset>),
+    /// A missing value that still knows its type, e.g. an empty CSV cell of an Int32 column.
+    /// NOTE: After the original variants, so the derived order and the serde variant indices of those stay the same.
+    Null(ValueType),
+    NaiveTime(NaiveTime),
+    Duration(
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::value_duration::serde_iso8601")
+        )]
+        Duration,
+    ),
+    List(Vec<Value>),
+    Map(BTreeMap<String, Value>),
+    Bytes(Vec<u8>),
+    #[cfg(feature = "uuid")]
+    Uuid(uuid::Uuid),
 }
 
 macro_rules! from_type_string {
@@ -81,6 +117,43 @@ macro_rules! is_type {
     };
 }
 
+// Splits at every delimiter that isn't escaped. Only an escaped delimiter and an escaped escape are unescaped,
+// all other backslashes stay as they are, e.g. `C:\temp` is still `C:\temp`.
+fn split_list(v: &str, delimiter: &str) -> Vec<String> {
+    let mut elements = Vec::new();
+    let mut element = String::new();
+    let mut rest = v;
+    while let Some(c) = rest.chars().next() {
+        let after_escape = &rest[c.len_utf8()..];
+        if c == LIST_ESCAPE && after_escape.starts_with(delimiter) && !delimiter.is_empty() {
+            element.push_str(delimiter);
+            rest = &after_escape[delimiter.len()..];
+        } else if c == LIST_ESCAPE && after_escape.starts_with(LIST_ESCAPE) {
+            element.push(LIST_ESCAPE);
+            rest = &after_escape[LIST_ESCAPE.len_utf8()..];
+        } else if rest.starts_with(delimiter) && !delimiter.is_empty() {
+            elements.push(std::mem::take(&mut element));
+            rest = &rest[delimiter.len()..];
+        } else {
+            element.push(c);
+            rest = after_escape;
+        }
+    }
+    elements.push(element);
+    elements
+}
+
+// The counterpart of `split_list`: escapes every delimiter and every backslash, so that the element survives it.
+pub(crate) fn escape_list_element(element: &str, delimiter: &str) -> String {
+    let escaped_escape = format!("{LIST_ESCAPE}{LIST_ESCAPE}");
+    let escaped = element.replace(LIST_ESCAPE, &escaped_escape);
+    if delimiter.is_empty() {
+        escaped
+    } else {
+        escaped.replace(delimiter, &format!("{LIST_ESCAPE}{delimiter}"))
+    }
+}
+
 impl Value {
     type_defaults!(char_default, Char, char);
     type_defaults!(string_default, String, String);
@@ -98,6 +171,12 @@ impl Value {
     type_defaults!(float64_default, Float64, f64);
     type_defaults!(bool_default, Bool, bool);
     type_defaults!(decimal_default, Decimal, Decimal);
+    type_defaults!(duration_default, Duration, Duration);
+    type_defaults!(list_default, List, Vec<Value>);
+    type_defaults!(map_default, Map, BTreeMap<String, Value>);
+    type_defaults!(bytes_default, Bytes, Vec<u8>);
+    #[cfg(feature = "uuid")]
+    type_defaults!(uuid_default, Uuid, uuid::Uuid); // the nil uuid
 
     /// Default is: 1970-01-01
     pub fn naive_date_default() -> Value {
@@ -125,6 +204,10 @@ impl Value {
         Value::DateTime(d)
     }
 
+    pub fn naive_time_default() -> Value {
+        Value::NaiveTime(NaiveTime::from_hms_opt(0, 0, 0).unwrap()) // This time exists for sure. Unwrap is safe here
+    }
+
     from_type_string!(parse_char_from_str, Char, char);
     from_type_string!(parse_int8_from_str, Int8, i8);
     from_type_string!(parse_int16_from_str, Int16, i16);
@@ -317,6 +400,56 @@ impl Value {
         })?;
         Ok(Value::NaiveDateTime(temp))
     }
+    pub fn parse_naive_time_from_str(v: &str, chrono_pattern: &str) -> Result<Value> {
+        // e.g pattern "%H.%M" to parse "08.30"
+        if v.is_empty() {
+            return Ok(Value::None);
+        }
+        let temp = NaiveTime::parse_from_str(v, chrono_pattern).map_err(|oe| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("{}{}", VAL_ENUM_NAME, ENUM_VAR_NT),
+                details: Some(format!(
+                    "Chrono pattern: {chrono_pattern}. Original error: {oe}"
+                )),
+            })
+        })?;
+        Ok(Value::NaiveTime(temp))
+    }
+    pub fn parse_naive_time_from_str_iso8601_hms(v: &str) -> Result<Value> {
+        // e.g for parsing "08:30:00" or "08:30:00.100"
+        if v.is_empty() {
+            return Ok(Value::None);
+        }
+        let chrono_pattern = "%H:%M:%S%.f";
+        let temp = NaiveTime::parse_from_str(v, chrono_pattern).map_err(|oe| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("{}{}", VAL_ENUM_NAME, ENUM_VAR_NT),
+                details: Some(format!(
+                    "Chrono pattern: {chrono_pattern}. Original error: {oe}"
+                )),
+            })
+        })?;
+        Ok(Value::NaiveTime(temp))
+    }
+    pub fn parse_naive_time_from_str_iso8601_hm(v: &str) -> Result<Value> {
+        // e.g for parsing "08:30"
+        if v.is_empty() {
+            return Ok(Value::None);
+        }
+        let chrono_pattern = "%H:%M";
+        let temp = NaiveTime::parse_from_str(v, chrono_pattern).map_err(|oe| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("{}{}", VAL_ENUM_NAME, ENUM_VAR_NT),
+                details: Some(format!(
+                    "Chrono pattern: {chrono_pattern}. Original error: {oe}"
+                )),
+            })
+        })?;
+        Ok(Value::NaiveTime(temp))
+    }
     pub fn parse_date_time_from_str(v: &str, chrono_pattern: &str) -> Result<Value> {
         if v.is_empty() {
             return Ok(Value::None);
@@ -346,6 +479,132 @@ impl Value {
         })?;
         Ok(Value::DateTime(temp))
     }
+    /// ISO-8601 durations, e.g. "PT1H30M", "P1DT12H" or "-PT0.5S". Years and months are not supported,
+    /// as they don't have a fixed length.
+    pub fn parse_duration_from_str_iso8601(v: &str) -> Result<Value> {
+        if v.is_empty() {
+            return Ok(Value::None);
+        }
+        let temp = value_duration::parse_iso8601(v).ok_or_else(|| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("{}{}", VAL_ENUM_NAME, ENUM_VAR_DUR),
+                details: Some(String::from("not an ISO-8601 duration, e.g. PT1H30M")),
+            })
+        })?;
+        Ok(Value::Duration(temp))
+    }
+    /// e.g. "01:30:00" or "100:00:00.5"
+    pub fn parse_duration_from_str_hms(v: &str) -> Result<Value> {
+        if v.is_empty() {
+            return Ok(Value::None);
+        }
+        let temp = value_duration::parse_hms(v).ok_or_else(|| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("{}{}", VAL_ENUM_NAME, ENUM_VAR_DUR),
+                details: Some(String::from("not a duration like HH:MM:SS")),
+            })
+        })?;
+        Ok(Value::Duration(temp))
+    }
+    /// Whole seconds, e.g. "90"
+    pub fn parse_duration_from_str_secs(v: &str) -> Result<Value> {
+        if v.is_empty() {
+            return Ok(Value::None);
+        }
+        let temp = value_duration::parse_secs(v).ok_or_else(|| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("{}{}", VAL_ENUM_NAME, ENUM_VAR_DUR),
+                details: Some(String::from("not an integer number of seconds")),
+            })
+        })?;
+        Ok(Value::Duration(temp))
+    }
+    /// Whole milliseconds, e.g. "1500"
+    pub fn parse_duration_from_str_millis(v: &str) -> Result<Value> {
+        if v.is_empty() {
+            return Ok(Value::None);
+        }
+        let temp = value_duration::parse_millis(v).ok_or_else(|| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("{}{}", VAL_ENUM_NAME, ENUM_VAR_DUR),
+                details: Some(String::from("not an integer number of milliseconds")),
+            })
+        })?;
+        Ok(Value::Duration(temp))
+    }
+    /// All formats `uuid::Uuid::parse_str` understands, i.e. hyphenated, simple, braced and urn.
+    #[cfg(feature = "uuid")]
+    pub fn parse_uuid_from_str(v: &str) -> Result<Value> {
+        if v.is_empty() {
+            return Ok(Value::None);
+        }
+        let temp = uuid::Uuid::parse_str(v).map_err(|oe| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("{}{}", VAL_ENUM_NAME, ENUM_VAR_UUID),
+                details: Some(format!("Original error: {oe}")),
+            })
+        })?;
+        Ok(Value::Uuid(temp))
+    }
+    /// Hex, with or without leading "0x", e.g. "cafe" or "0xCAFE".
+    pub fn parse_bytes_from_str_hex(v: &str) -> Result<Value> {
+        if v.is_empty() {
+            return Ok(Value::None);
+        }
+        let digits = v
+            .strip_prefix("0x")
+            .or_else(|| v.strip_prefix("0X"))
+            .unwrap_or(v);
+        let temp = if digits.len() % 2 == 0 {
+            digits
+                .as_bytes()
+                .chunks(2)
+                .map(|pair| {
+                    let high = char::from(pair[0]).to_digit(16)?;
+                    let low = char::from(pair[1]).to_digit(16)?;
+                    u8::try_from(high * 16 + low).ok()
+                })
+                .collect::<Option<Vec<u8>>>()
+        } else {
+            None
+        };
+        let temp = temp.ok_or_else(|| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("{}{}", VAL_ENUM_NAME, ENUM_VAR_BYTES),
+                details: Some(String::from(
+                    "not a hex string, i.e. an even number of hex digits",
+                )),
+            })
+        })?;
+        Ok(Value::Bytes(temp))
+    }
+    /// Standard base64 alphabet, with padding, e.g. "yv4=".
+    pub fn parse_bytes_from_str_base64(v: &str) -> Result<Value> {
+        if v.is_empty() {
+            return Ok(Value::None);
+        }
+        let temp = BASE64.decode(v).map_err(|oe| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("{}{}", VAL_ENUM_NAME, ENUM_VAR_BYTES),
+                details: Some(format!("Original error: {oe}")),
+            })
+        })?;
+        Ok(Value::Bytes(temp))
+    }
+    /// There is no guessing, as many strings are valid hex AND valid base64, e.g. "cafe" or "AAAA".
+    pub fn parse_bytes_from_str(v: &str, bytes_encoding: BytesEncoding) -> Result<Value> {
+        match bytes_encoding {
+            BytesEncoding::Hex => Value::parse_bytes_from_str_hex(v),
+            BytesEncoding::Base64 => Value::parse_bytes_from_str_base64(v),
+        }
+    }
     pub fn parse_date_time_from_str_rfc3339(v: &str) -> Result<Value> {
         if v.is_empty() {
             return Ok(Value::None);
@@ -361,6 +620,34 @@ impl Value {
         Ok(Value::DateTime(temp))
     }
 
+    /// Parses a delimited string, e.g. "a|b|c", where every element is parsed as `element_type`.
+    /// Empty elements, e.g. the middle one in "a||c", become `Value::Null(element_type)`.
+    /// A delimiter inside an element is escaped with a backslash, e.g. `a\|b|c` is "a|b" and "c", a backslash itself
+    /// as `\\`. Other backslashes are kept as they are. This is what `try_convert_to_string` produces for lists.
+    pub fn parse_list_from_str(
+        v: &str,
+        element_type: &ValueType,
+        delimiter: &str,
+    ) -> Result<Value> {
+        Value::parse_list_from_str_with_options(v, element_type, delimiter, &ParseOptions::new())
+    }
+
+    fn parse_list_from_str_with_options(
+        v: &str,
+        element_type: &ValueType,
+        delimiter: &str,
+        options: &ParseOptions,
+    ) -> Result<Value> {
+        if v.is_empty() {
+            return Ok(Value::None);
+        }
+        let elements = split_list(v, delimiter)
+            .iter()
+            .map(|e| Value::parse(e, element_type, options))
+            .collect::<Result<Vec<Value>>>()?;
+        Ok(Value::List(elements))
+    }
+
     // For all decimal_from_XXX we assume it is better to error, instead of
     pub fn decimal_from_i8(v: i8) -> Value {
         Value::Decimal(Decimal::from(v))
@@ -449,8 +736,15 @@ impl Value {
         Ok(Value::Decimal(tmp))
     }
 
+    /// True for `Value::None` and typed nulls, i.e. `Value::Null(_)`. Use this to check for "no data",
+    /// as both kinds show up for empty strings: `Value::parse` (and the `from_str_and_type*` functions)
+    /// give typed nulls, most of the lower level `parse_*_from_str` functions give `Value::None` (see: `Value::parse`).
     pub fn is_none(&self) -> bool {
-        matches!(self, Value::None)
+        matches!(self, Value::None | Value::Null(_))
+    }
+    /// True for typed nulls only, i.e. `Value::Null(_)`, but not for `Value::None`
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null(_))
     }
     pub fn is_some(&self) -> bool {
         !self.is_none()
@@ -474,32 +768,102 @@ impl Value {
     is_type!(is_naive_date, NaiveDate);
     is_type!(is_naive_date_time, NaiveDateTime);
     is_type!(is_date_time, DateTime);
+    is_type!(is_naive_time, NaiveTime);
+    is_type!(is_duration, Duration);
+    is_type!(is_list, List);
+    is_type!(is_map, Map);
+    is_type!(is_bytes, Bytes);
+    #[cfg(feature = "uuid")]
+    is_type!(is_uuid, Uuid);
+
+    /// Looks up `key`, if this is a `Value::Map`. Returns `None` for all other variants.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Map(m) => m.get(key),
+            _ => None,
+        }
+    }
+
+    /// Same as `get`, but mutable.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
+        match self {
+            Value::Map(m) => m.get_mut(key),
+            _ => None,
+        }
+    }
 
+    /// The one parser for everything, configured via `ParseOptions`. The `from_str_and_type*` functions are shortcuts for it.
+    ///
     /// NOTE: We decided against Option<String> here as the type of the value since the intention is to create a typed version of a stringy-input we read from some CSV.
     ///       In that case, when a CSV column contains a "" as an entry, e.g. like this: `a,,c` or this `"a","","c"`, where the middle column would translate to empty / "",
-    ///       we map it to a None internally, representing the absence of data.
-    /// NOTE2: For date types, when no chrono_pattern is supplied, parsing is still tried, using: iso8601_ymd, iso8601_ymdhms and rfc3339.
-    pub fn from_str_and_type_with_chrono_pattern_with_none_map(
+    ///       we map it to a typed null internally, i.e. `Value::Null(target_value_type)`, representing the absence of data (see: `ParseOptions::empty`).
+    /// NOTE2: For date types, when no chrono_pattern is supplied, parsing is still tried, using: iso8601_ymd, iso8601_ymdhms, rfc3339 and rfc2822.
+    ///        See `ParseOptions::date_patterns` for other (and multiple) patterns.
+    ///        For NaiveTime, iso8601_hms (with optional fraction of a second) and iso8601_hm are tried.
+    /// NOTE3: Lists are parsed from strings delimited by `|`, e.g. "a|b|c". The options are applied to every element.
+    /// NOTE4: Maps can't be parsed from strings, so this always fails for ValueType::Map.
+    /// NOTE5: Bytes are parsed from hex by default, see: `ParseOptions::bytes_encoding`.
+    /// NOTE6: Durations are parsed from ISO-8601 (e.g. "PT1H30M"), HH:MM:SS or whole seconds, in that order.
+    ///        Use `Value::parse_duration_from_str_millis` for milliseconds.
+    /// NOTE7: Numeric strings are only Unix timestamps with `ParseOptions::epoch_unit`.
+    /// NOTE8: Empty strings differ between this and the lower level `parse_*_from_str` functions, which are kept as they were:
+    ///        this gives `Value::Null(target type)` (by default), the ones for decimals, dates, times, durations, bytes,
+    ///        uuids and lists give the untyped `Value::None`, and the ones for chars, ints, floats and bools fail.
+    ///        `Value::is_none` is true for both kinds of nulls.
+    pub fn parse(
         value: &str,
         target_value_type: &ValueType,
-        chrono_pattern: Option<&str>,
-        as_none: Option<Vec<&str>>,
+        options: &ParseOptions,
     ) -> Result<Value> {
+        let value = if options.trim { value.trim() } else { value };
         if value.is_empty() {
-            return Ok(Value::None); // Caller must remember the desired type!
+            return match options.empty {
+                EmptyPolicy::Null => Ok(Value::Null(target_value_type.clone())),
+                EmptyPolicy::None => Ok(Value::None),
+                EmptyPolicy::EmptyString if *target_value_type == ValueType::String => {
+                    Ok(Value::String(String::new()))
+                }
+                EmptyPolicy::EmptyString => Ok(Value::Null(target_value_type.clone())),
+                EmptyPolicy::Error => Err(VenumError::Parsing(ParseError::ValueFromStringFailed {
+                    src_value: String::new(),
+                    target_type: format!("{}{}", VAL_ENUM_NAME, target_value_type),
+                    details: Some(String::from("Empty string")),
+                })),
+            };
+        }
+        if options.is_none_marker(value) {
+            return Ok(Value::Null(target_value_type.clone()));
         }
-        if let Some(none_check_vals) = as_none {
-            if !none_check_vals.is_empty() && none_check_vals.contains(&value) {
-                return Ok(Value::None); // Caller must remember the desired type!
+        if let Some(epoch_unit) = &options.epoch_unit {
+            if matches!(
+                target_value_type,
+                ValueType::NaiveDateTime | ValueType::DateTime
+            ) && Decimal::from_str_exact(value).is_ok()
+            {
+                return Value::parse_date_time_from_str_epoch(value, target_value_type, epoch_unit);
             }
         }
-        if let Some(chrono_pattern) = chrono_pattern {
+        if let Some(date_patterns) = &options.date_patterns {
+            if target_value_type.is_some_date_type() || *target_value_type == ValueType::NaiveTime {
+                return date_patterns
+                    .parse(value, target_value_type)
+                    .map(|(v, _pattern)| v);
+            }
+        }
+        if let Some(chrono_pattern) = options.chrono_pattern.as_deref() {
             match target_value_type {
                 ValueType::NaiveDate => Value::parse_naive_date_from_str(value, chrono_pattern),
                 ValueType::NaiveDateTime => {
                     Value::parse_naive_date_time_from_str(value, chrono_pattern)
                 }
                 ValueType::DateTime => Value::parse_date_time_from_str(value, chrono_pattern),
+                ValueType::NaiveTime => Value::parse_naive_time_from_str(value, chrono_pattern),
+                ValueType::List(element_type) => Value::parse_list_from_str_with_options(
+                    value,
+                    element_type,
+                    DEFAULT_LIST_DELIMITER,
+                    options,
+                ),
                 _ => Err(VenumError::Parsing(ParseError::ValueFromStringFailed {
                     src_value: String::from(value),
                     target_type: format!("{}{}", VAL_ENUM_NAME, target_value_type),
@@ -510,22 +874,22 @@ impl Value {
             match target_value_type {
                 ValueType::Char => Value::parse_char_from_str(value),
                 ValueType::String => Ok(Value::String(value.to_owned())),
-                ValueType::Int8 => Value::parse_int8_from_str(value),
-                ValueType::Int16 => Value::parse_int16_from_str(value),
-                ValueType::Int32 => Value::parse_int32_from_str(value),
-                ValueType::Int64 => Value::parse_int64_from_str(value),
-                ValueType::Int128 => Value::parse_int128_from_str(value),
-                ValueType::UInt8 => Value::parse_uint8_from_str(value),
-                ValueType::UInt16 => Value::parse_uint16_from_str(value),
-                ValueType::UInt32 => Value::parse_uint32_from_str(value),
-                ValueType::UInt64 => Value::parse_uint64_from_str(value),
-                ValueType::UInt128 => Value::parse_uint128_from_str(value),
-                ValueType::Float32 => Value::parse_float32_from_str(value),
-                ValueType::Float64 => Value::parse_float64_from_str(value),
-                ValueType::Bool => Value::parse_bool_from_str(value),
-                ValueType::Decimal => match Value::parse_decimal_from_str(value) {
-                    Ok(v) => Ok(v),
-                    Err(_) => Value::parse_decimal_from_str_scientific(value),
+                ValueType::Int8
+                | ValueType::Int16
+                | ValueType::Int32
+                | ValueType::Int64
+                | ValueType::Int128
+                | ValueType::UInt8
+                | ValueType::UInt16
+                | ValueType::UInt32
+                | ValueType::UInt64
+                | ValueType::UInt128
+                | ValueType::Float32
+                | ValueType::Float64
+                | ValueType::Decimal => Value::parse_number(value, target_value_type, options),
+                ValueType::Bool => match &options.bool_format {
+                    Some(bool_format) => Value::parse_bool_from_str_with_format(value, bool_format),
+                    None => Value::parse_bool_from_str(value),
                 },
                 ValueType::NaiveDate => Value::parse_naive_date_from_str_iso8601_ymd(value),
                 ValueType::NaiveDateTime => {
@@ -536,11 +900,109 @@ impl Value {
                         }
                     }
                 }
-                ValueType::DateTime => Value::parse_date_time_from_str_rfc3339(value),
+                ValueType::DateTime => Value::parse_date_time_from_str_rfc3339(value)
+                    .or_else(|err| Value::parse_date_time_from_str_rfc2822(value).map_err(|_| err)),
+                ValueType::NaiveTime => match Value::parse_naive_time_from_str_iso8601_hms(value) {
+                    Ok(v) => Ok(v),
+                    Err(_) => Value::parse_naive_time_from_str_iso8601_hm(value),
+                },
+                ValueType::Duration => Value::parse_duration_from_str_iso8601(value)
+                    .or_else(|_| Value::parse_duration_from_str_hms(value))
+                    .or_else(|_| Value::parse_duration_from_str_secs(value)),
+                ValueType::List(element_type) => Value::parse_list_from_str_with_options(
+                    value,
+                    element_type,
+                    DEFAULT_LIST_DELIMITER,
+                    options,
+                ),
+                ValueType::Bytes => Value::parse_bytes_from_str(value, options.bytes_encoding),
+                #[cfg(feature = "uuid")]
+                ValueType::Uuid => Value::parse_uuid_from_str(value),
+                ValueType::Map => Err(VenumError::Parsing(ParseError::ValueFromStringFailed {
+                    src_value: String::from(value),
+                    target_type: format!("{}{}", VAL_ENUM_NAME, ValueType::Map),
+                    details: Some(String::from("maps can't be parsed from strings")),
+                })),
             }
         }
     }
 
+    // Ints, floats and decimals, with the number format and inf / NaN of the options
+    fn parse_number(
+        value: &str,
+        target_value_type: &ValueType,
+        options: &ParseOptions,
+    ) -> Result<Value> {
+        if options.allow_inf_nan {
+            let non_finite = match target_value_type {
+                ValueType::Float32 => Value::parse_float32_from_str_allow_inf_allow_nan(value)
+                    .ok()
+                    .filter(|v| matches!(v, Value::Float32(f) if !f.is_finite())),
+                ValueType::Float64 => Value::parse_float64_from_str_allow_inf_allow_nan(value)
+                    .ok()
+                    .filter(|v| matches!(v, Value::Float64(f) if !f.is_finite())),
+                _ => None,
+            };
+            if let Some(v) = non_finite {
+                return Ok(v);
+            }
+        }
+        let normalized = match &options.number_format {
+            Some(number_format) => Some(number_format.normalize_for(value, target_value_type)?),
+            None => None,
+        };
+        let v = normalized.as_deref().unwrap_or(value);
+        let parsed = match target_value_type {
+            ValueType::Int8 => Value::parse_int8_from_str(v),
+            ValueType::Int16 => Value::parse_int16_from_str(v),
+            ValueType::Int32 => Value::parse_int32_from_str(v),
+            ValueType::Int64 => Value::parse_int64_from_str(v),
+            ValueType::Int128 => Value::parse_int128_from_str(v),
+            ValueType::UInt8 => Value::parse_uint8_from_str(v),
+            ValueType::UInt16 => Value::parse_uint16_from_str(v),
+            ValueType::UInt32 => Value::parse_uint32_from_str(v),
+            ValueType::UInt64 => Value::parse_uint64_from_str(v),
+            ValueType::UInt128 => Value::parse_uint128_from_str(v),
+            ValueType::Float32 => Value::parse_float32_from_str(v),
+            ValueType::Float64 => Value::parse_float64_from_str(v),
+            _ => match Value::parse_decimal_from_str(v) {
+                Ok(d) => Ok(d),
+                Err(_) => Value::parse_decimal_from_str_scientific(v),
+            },
+        };
+        match (parsed, normalized) {
+            // Report the original value, not the normalized one
+            (
+                Err(VenumError::Parsing(ParseError::ValueFromStringFailed {
+                    target_type,
+                    details,
+                    ..
+                })),
+                Some(_),
+            ) => Err(VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(value),
+                target_type,
+                details,
+            })),
+            (parsed, _) => parsed,
+        }
+    }
+
+    /// See: `Value::parse`
+    pub fn from_str_and_type_with_chrono_pattern_with_none_map(
+        value: &str,
+        target_value_type: &ValueType,
+        chrono_pattern: Option<&str>,
+        as_none: Option<Vec<&str>>,
+    ) -> Result<Value> {
+        let mut options = ParseOptions::new();
+        options.chrono_pattern = chrono_pattern.map(String::from);
+        if let Some(none_markers) = as_none {
+            options = options.with_none_markers(&none_markers);
+        }
+        Value::parse(value, target_value_type, &options)
+    }
+
     pub fn from_str_and_type(value: &str, target_value_type: &ValueType) -> Result<Value> {
         Self::from_str_and_type_with_chrono_pattern_with_none_map(
             value,
@@ -563,6 +1025,20 @@ impl Value {
         )
     }
 
+    /// Numeric strings are Unix timestamps in the given unit for `NaiveDateTime` and `DateTime`,
+    /// see: `ParseOptions::epoch_unit`
+    pub fn from_str_and_type_with_epoch_unit(
+        value: &str,
+        target_value_type: &ValueType,
+        epoch_unit: &EpochUnit,
+    ) -> Result<Value> {
+        Value::parse(
+            value,
+            target_value_type,
+            &ParseOptions::new().with_epoch_unit(*epoch_unit),
+        )
+    }
+
     pub fn from_str_and_type_with_none_map(
         value: &str,
         target_value_type: &ValueType,
@@ -1192,6 +1668,7 @@ mod tests {
         use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
 
         use crate::value::Value;
+        use crate::value_type::ValueType;
 
         #[test]
         pub fn parse_naive_date_from_str_w_pattern() {
@@ -1462,8 +1939,12 @@ mod tests {
             let res = Value::parse_date_time_from_str_rfc2822(date_str);
             assert_eq!(Ok(Value::DateTime(exp)), res);
 
+            // newer chrono versions don't zero-pad the day anymore, so we compare the round trip instead of the raw string
             let dt = DateTime::try_from(res.unwrap()).unwrap();
-            assert_eq!(dt.to_rfc2822(), String::from(date_str));
+            assert_eq!(
+                Ok(Value::DateTime(dt)),
+                Value::parse_date_time_from_str_rfc2822(&dt.to_rfc2822())
+            );
         }
 
         #[test]
@@ -1479,82 +1960,472 @@ mod tests {
         pub fn parse_date_time_from_str_rfc2822_err() {
             Value::parse_date_time_from_str_rfc2822("foobar").unwrap();
         }
+
+        #[test]
+        pub fn from_str_and_type_date_time_rfc2822_fallback() {
+            let expected = NaiveDate::from_ymd_opt(2003, 7, 1)
+                .unwrap()
+                .and_hms_opt(10, 52, 37)
+                .unwrap()
+                .and_local_timezone(FixedOffset::east_opt(7200).unwrap())
+                .unwrap();
+            assert_eq!(
+                Ok(Value::DateTime(expected)),
+                Value::from_str_and_type("Tue, 1 Jul 2003 10:52:37 +0200", &ValueType::DateTime)
+            );
+            // the error is still the one of rfc3339
+            assert_eq!(
+                Value::parse_date_time_from_str_rfc3339("foobar"),
+                Value::from_str_and_type("foobar", &ValueType::DateTime)
+            );
+        }
     }
 
-    mod default_values {
-        use chrono::{FixedOffset, NaiveDate};
-        use rust_decimal::Decimal;
+    mod parse_from_str_time {
+        use chrono::NaiveTime;
 
         use crate::value::Value;
+        use crate::value_type::ValueType;
 
         #[test]
-        pub fn char_default() {
-            assert_eq!(Value::Char('\0'), Value::char_default());
+        pub fn parse_naive_time_from_str_w_pattern() {
+            assert_eq!(
+                Ok(Value::NaiveTime(NaiveTime::from_hms_opt(8, 30, 0).unwrap())),
+                Value::parse_naive_time_from_str("08.30", "%H.%M")
+            );
+            assert_eq!(
+                Ok(Value::None),
+                Value::parse_naive_time_from_str("", "%H.%M")
+            );
         }
 
         #[test]
-        pub fn string_default() {
-            assert_eq!(Value::String("".to_string()), Value::string_default());
+        #[should_panic(
+            expected = "Parsing(ValueFromStringFailed { src_value: \"08:30\", target_type: \"Value::NaiveTime\", details: Some(\"Chrono pattern: %H.%M. Original error: input contains invalid characters\") })"
+        )]
+        pub fn parse_naive_time_from_str_w_pattern_err() {
+            Value::parse_naive_time_from_str("08:30", "%H.%M").unwrap();
         }
 
         #[test]
-        pub fn int8_default() {
-            assert_eq!(Value::Int8(0), Value::int8_default());
+        pub fn parse_naive_time_from_str_iso8601_hms() {
+            assert_eq!(
+                Ok(Value::NaiveTime(NaiveTime::from_hms_opt(8, 30, 5).unwrap())),
+                Value::parse_naive_time_from_str_iso8601_hms("08:30:05")
+            );
+            assert_eq!(
+                Ok(Value::NaiveTime(
+                    NaiveTime::from_hms_milli_opt(8, 30, 5, 100).unwrap()
+                )),
+                Value::parse_naive_time_from_str_iso8601_hms("08:30:05.100")
+            );
         }
 
         #[test]
-        pub fn int16_default() {
-            assert_eq!(Value::Int16(0), Value::int16_default());
+        pub fn parse_naive_time_from_str_iso8601_hm() {
+            assert_eq!(
+                Ok(Value::NaiveTime(NaiveTime::from_hms_opt(8, 30, 0).unwrap())),
+                Value::parse_naive_time_from_str_iso8601_hm("08:30")
+            );
         }
 
         #[test]
-        pub fn int32_default() {
-            assert_eq!(Value::Int32(0), Value::int32_default());
+        pub fn from_str_and_type() {
+            assert_eq!(
+                Ok(Value::NaiveTime(NaiveTime::from_hms_opt(8, 30, 0).unwrap())),
+                Value::from_str_and_type("08:30", &ValueType::NaiveTime)
+            );
+            assert_eq!(
+                Ok(Value::NaiveTime(
+                    NaiveTime::from_hms_milli_opt(23, 59, 59, 999).unwrap()
+                )),
+                Value::from_str_and_type("23:59:59.999", &ValueType::NaiveTime)
+            );
+            assert_eq!(
+                Ok(Value::NaiveTime(
+                    NaiveTime::from_hms_opt(20, 15, 0).unwrap()
+                )),
+                Value::from_str_and_type_with_chrono_pattern(
+                    "8:15 PM",
+                    &ValueType::NaiveTime,
+                    "%-I:%M %p"
+                )
+            );
         }
 
         #[test]
-        pub fn int64_default() {
-            assert_eq!(Value::Int64(0), Value::int64_default());
+        #[should_panic(expected = "Parsing(ValueFromStringFailed")]
+        pub fn from_str_and_type_err() {
+            Value::from_str_and_type("25:00", &ValueType::NaiveTime).unwrap();
         }
+    }
 
-        #[test]
-        pub fn int128_default() {
-            assert_eq!(Value::Int128(0), Value::int128_default());
-        }
+    mod parse_from_str_duration {
+        use chrono::Duration;
+
+        use crate::value::Value;
+        use crate::value_type::ValueType;
 
         #[test]
-        pub fn uint8_default() {
-            assert_eq!(Value::UInt8(0), Value::uint8_default());
+        pub fn parse_duration_from_str_iso8601() {
+            assert_eq!(
+                Ok(Value::Duration(Duration::try_minutes(90).unwrap())),
+                Value::parse_duration_from_str_iso8601("PT1H30M")
+            );
+            assert_eq!(Ok(Value::None), Value::parse_duration_from_str_iso8601(""));
         }
 
         #[test]
-        pub fn uint16_default() {
-            assert_eq!(Value::UInt16(0), Value::uint16_default());
+        #[should_panic(
+            expected = "Parsing(ValueFromStringFailed { src_value: \"P1Y\", target_type: \"Value::Duration\""
+        )]
+        pub fn parse_duration_from_str_iso8601_err() {
+            Value::parse_duration_from_str_iso8601("P1Y").unwrap();
         }
 
         #[test]
-        pub fn uint32_default() {
-            assert_eq!(Value::UInt32(0), Value::uint32_default());
+        pub fn parse_duration_from_str_hms() {
+            assert_eq!(
+                Ok(Value::Duration(Duration::try_minutes(90).unwrap())),
+                Value::parse_duration_from_str_hms("01:30:00")
+            );
         }
 
         #[test]
-        pub fn uint64_default() {
-            assert_eq!(Value::UInt64(0), Value::uint64_default());
+        pub fn parse_duration_from_str_secs_and_millis() {
+            assert_eq!(
+                Ok(Value::Duration(Duration::try_seconds(90).unwrap())),
+                Value::parse_duration_from_str_secs("90")
+            );
+            assert_eq!(
+                Ok(Value::Duration(Duration::try_milliseconds(1500).unwrap())),
+                Value::parse_duration_from_str_millis("1500")
+            );
         }
 
         #[test]
-        pub fn uint128_default() {
-            assert_eq!(Value::UInt128(0), Value::uint128_default());
+        #[should_panic(expected = "Parsing(ValueFromStringFailed")]
+        pub fn parse_duration_from_str_secs_err() {
+            Value::parse_duration_from_str_secs("1.5").unwrap();
         }
 
         #[test]
-        pub fn float32_default() {
-            assert_eq!(Value::Float32(0.0), Value::float32_default());
+        pub fn from_str_and_type() {
+            let exp = Ok(Value::Duration(Duration::try_minutes(90).unwrap()));
+            assert_eq!(
+                exp,
+                Value::from_str_and_type("PT1H30M", &ValueType::Duration)
+            );
+            assert_eq!(
+                exp,
+                Value::from_str_and_type("01:30:00", &ValueType::Duration)
+            );
+            assert_eq!(exp, Value::from_str_and_type("5400", &ValueType::Duration));
         }
 
         #[test]
-        pub fn float64_default() {
-            assert_eq!(Value::Float64(0.0), Value::float64_default());
+        #[should_panic(expected = "Parsing(ValueFromStringFailed")]
+        pub fn from_str_and_type_err() {
+            Value::from_str_and_type("1h30m", &ValueType::Duration).unwrap();
+        }
+    }
+
+    #[cfg(feature = "uuid")]
+    mod parse_from_str_uuid {
+        use crate::value::Value;
+        use crate::value_type::ValueType;
+
+        const UUID: uuid::Uuid = uuid::Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+        #[test]
+        pub fn parse_uuid_from_str() {
+            for s in [
+                "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                "67e5504410b1426f9247bb680e5fe0c8",
+                "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+                "urn:uuid:67E55044-10B1-426F-9247-BB680E5FE0C8",
+            ] {
+                assert_eq!(Ok(Value::Uuid(UUID)), Value::parse_uuid_from_str(s));
+            }
+            assert_eq!(Ok(Value::None), Value::parse_uuid_from_str(""));
+            assert_eq!(
+                Ok(Value::Uuid(UUID)),
+                Value::from_str_and_type("67e5504410b1426f9247bb680e5fe0c8", &ValueType::Uuid)
+            );
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Parsing(ValueFromStringFailed { src_value: \"67e55044\", target_type: \"Value::Uuid\""
+        )]
+        pub fn parse_uuid_from_str_err() {
+            Value::parse_uuid_from_str("67e55044").unwrap();
+        }
+    }
+
+    mod parse_from_str_bytes {
+        use crate::value::Value;
+        use crate::value_type::ValueType;
+
+        #[test]
+        pub fn parse_bytes_from_str_hex() {
+            assert_eq!(
+                Ok(Value::Bytes(vec![0xca, 0xfe])),
+                Value::parse_bytes_from_str_hex("cafe")
+            );
+            assert_eq!(
+                Ok(Value::Bytes(vec![0xca, 0xfe])),
+                Value::parse_bytes_from_str_hex("0xCAFE")
+            );
+            assert_eq!(Ok(Value::None), Value::parse_bytes_from_str_hex(""));
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Parsing(ValueFromStringFailed { src_value: \"caf\", target_type: \"Value::Bytes\""
+        )]
+        pub fn parse_bytes_from_str_hex_odd_len_err() {
+            Value::parse_bytes_from_str_hex("caf").unwrap();
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Parsing(ValueFromStringFailed { src_value: \"+f\", target_type: \"Value::Bytes\""
+        )]
+        pub fn parse_bytes_from_str_hex_err() {
+            Value::parse_bytes_from_str_hex("+f").unwrap();
+        }
+
+        #[test]
+        pub fn parse_bytes_from_str_base64() {
+            assert_eq!(
+                Ok(Value::Bytes(vec![0xca, 0xfe])),
+                Value::parse_bytes_from_str_base64("yv4=")
+            );
+            assert_eq!(Ok(Value::None), Value::parse_bytes_from_str_base64(""));
+        }
+
+        #[test]
+        #[should_panic(expected = "Parsing(ValueFromStringFailed")]
+        pub fn parse_bytes_from_str_base64_err() {
+            Value::parse_bytes_from_str_base64("yv4").unwrap();
+        }
+
+        #[test]
+        pub fn from_str_and_type() {
+            // hex only, base64 needs `ParseOptions::bytes_encoding`
+            assert_eq!(
+                Ok(Value::Bytes(vec![0xca, 0xfe])),
+                Value::from_str_and_type("cafe", &ValueType::Bytes)
+            );
+            assert!(Value::from_str_and_type("yv4=", &ValueType::Bytes).is_err());
+        }
+
+        #[test]
+        #[should_panic(expected = "Parsing(ValueFromStringFailed")]
+        pub fn from_str_and_type_err() {
+            Value::from_str_and_type("foo!", &ValueType::Bytes).unwrap();
+        }
+    }
+
+    mod parse_from_str_list {
+        use chrono::NaiveDate;
+
+        use crate::value::{BytesEncoding, Value};
+        use crate::value_type::ValueType;
+
+        #[test]
+        pub fn parse_list_from_str() {
+            assert_eq!(
+                Ok(Value::List(vec![
+                    Value::Int8(1),
+                    Value::Null(ValueType::Int8),
+                    Value::Int8(3)
+                ])),
+                Value::parse_list_from_str("1;;3", &ValueType::Int8, ";")
+            );
+        }
+
+        #[test]
+        pub fn parse_list_from_str_escaped() {
+            assert_eq!(
+                Ok(Value::List(vec![
+                    Value::String(String::from("a;b")),
+                    Value::String(String::from(r"C:\temp")),
+                    Value::String(String::from(r"x\")),
+                    Value::Null(ValueType::String),
+                ])),
+                Value::parse_list_from_str(r"a\;b;C:\temp;x\\;", &ValueType::String, ";")
+            );
+        }
+
+        #[test]
+        pub fn parse_empty_str() {
+            // see: NOTE8 of `Value::parse`
+            assert_eq!(
+                Ok(Value::Null(ValueType::Decimal)),
+                Value::from_str_and_type("", &ValueType::Decimal)
+            );
+            assert_eq!(Ok(Value::None), Value::parse_decimal_from_str(""));
+            assert_eq!(
+                Ok(Value::None),
+                Value::parse_naive_date_from_str_iso8601_ymd("")
+            );
+            assert_eq!(
+                Ok(Value::None),
+                Value::parse_bytes_from_str("", BytesEncoding::Hex)
+            );
+            assert!(Value::parse_int8_from_str("").is_err());
+            assert!(Value::parse_float64_from_str("").is_err());
+            assert!(Value::parse_bool_from_str("").is_err());
+            assert!(Value::None.is_none() && !Value::None.is_null());
+            assert!(Value::Null(ValueType::Decimal).is_none());
+            assert!(Value::Null(ValueType::Decimal).is_null());
+        }
+
+        #[test]
+        pub fn parse_list_from_str_none() {
+            assert_eq!(
+                Ok(Value::None),
+                Value::parse_list_from_str("", &ValueType::Int8, ";")
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Parsing(ValueFromStringFailed")]
+        pub fn parse_list_from_str_err() {
+            Value::parse_list_from_str("1;a;3", &ValueType::Int8, ";").unwrap();
+        }
+
+        #[test]
+        pub fn from_str_and_type() {
+            assert_eq!(
+                Ok(Value::List(vec![
+                    Value::String(String::from("a")),
+                    Value::String(String::from("b")),
+                    Value::String(String::from("c"))
+                ])),
+                Value::from_str_and_type("a|b|c", &ValueType::List(Box::new(ValueType::String)))
+            );
+        }
+
+        #[test]
+        pub fn from_str_and_type_with_chrono_pattern_with_none_map() {
+            assert_eq!(
+                Ok(Value::List(vec![
+                    Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap()),
+                    Value::Null(ValueType::NaiveDate)
+                ])),
+                Value::from_str_and_type_with_chrono_pattern_with_none_map(
+                    "31.12.2022|n/a",
+                    &ValueType::List(Box::new(ValueType::NaiveDate)),
+                    Some("%d.%m.%Y"),
+                    Some(vec!["n/a"])
+                )
+            );
+        }
+    }
+
+    mod map {
+        use std::collections::BTreeMap;
+
+        use crate::value::Value;
+        use crate::value_type::ValueType;
+
+        #[test]
+        pub fn get() {
+            let mut v = Value::Map(BTreeMap::from([(String::from("a"), Value::Int8(1))]));
+            assert_eq!(Some(&Value::Int8(1)), v.get("a"));
+            assert_eq!(None, v.get("b"));
+            assert_eq!(None, Value::Int8(1).get("a"));
+
+            *v.get_mut("a").unwrap() = Value::Bool(true);
+            assert_eq!(Some(&Value::Bool(true)), v.get("a"));
+            assert!(v.is_map());
+            assert!(!Value::List(vec![]).is_map());
+        }
+
+        #[test]
+        #[should_panic(expected = "Parsing(ValueFromStringFailed")]
+        pub fn from_str_and_type_err() {
+            Value::from_str_and_type("a=1", &ValueType::Map).unwrap();
+        }
+    }
+
+    mod default_values {
+        use chrono::{FixedOffset, NaiveDate};
+        use rust_decimal::Decimal;
+
+        use crate::value::Value;
+
+        #[test]
+        pub fn char_default() {
+            assert_eq!(Value::Char('\0'), Value::char_default());
+        }
+
+        #[test]
+        pub fn string_default() {
+            assert_eq!(Value::String("".to_string()), Value::string_default());
+        }
+
+        #[test]
+        pub fn int8_default() {
+            assert_eq!(Value::Int8(0), Value::int8_default());
+        }
+
+        #[test]
+        pub fn int16_default() {
+            assert_eq!(Value::Int16(0), Value::int16_default());
+        }
+
+        #[test]
+        pub fn int32_default() {
+            assert_eq!(Value::Int32(0), Value::int32_default());
+        }
+
+        #[test]
+        pub fn int64_default() {
+            assert_eq!(Value::Int64(0), Value::int64_default());
+        }
+
+        #[test]
+        pub fn int128_default() {
+            assert_eq!(Value::Int128(0), Value::int128_default());
+        }
+
+        #[test]
+        pub fn uint8_default() {
+            assert_eq!(Value::UInt8(0), Value::uint8_default());
+        }
+
+        #[test]
+        pub fn uint16_default() {
+            assert_eq!(Value::UInt16(0), Value::uint16_default());
+        }
+
+        #[test]
+        pub fn uint32_default() {
+            assert_eq!(Value::UInt32(0), Value::uint32_default());
+        }
+
+        #[test]
+        pub fn uint64_default() {
+            assert_eq!(Value::UInt64(0), Value::uint64_default());
+        }
+
+        #[test]
+        pub fn uint128_default() {
+            assert_eq!(Value::UInt128(0), Value::uint128_default());
+        }
+
+        #[test]
+        pub fn float32_default() {
+            assert_eq!(Value::Float32(0.0), Value::float32_default());
+        }
+
+        #[test]
+        pub fn float64_default() {
+            assert_eq!(Value::Float64(0.0), Value::float64_default());
         }
 
         #[test]
@@ -1573,7 +2444,7 @@ mod tests {
         #[test]
         pub fn naive_date_default() {
             assert_eq!(
-                Value::NaiveDate(NaiveDate::from_ymd_opt(1970, 01, 01).unwrap()),
+                Value::NaiveDate(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()),
                 Value::naive_date_default()
             );
         }
@@ -1582,7 +2453,7 @@ mod tests {
         pub fn naive_date_time_default() {
             assert_eq!(
                 Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(1970, 01, 01)
+                    NaiveDate::from_ymd_opt(1970, 1, 1)
                         .unwrap()
                         .and_hms_opt(00, 00, 00)
                         .unwrap()
@@ -1602,6 +2473,14 @@ mod tests {
 
             assert_eq!(Value::DateTime(exp), Value::date_time_default());
         }
+
+        #[test]
+        pub fn naive_time_default() {
+            assert_eq!(
+                Value::NaiveTime(chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+                Value::naive_time_default()
+            );
+        }
     }
 
     mod from_number_type_to_value_decimal {
@@ -1769,1142 +2648,902 @@ mod tests {
 
         use crate::value::Value;
 
+        #[test]
+        pub fn is_type_null() {
+            assert!(Value::Null(crate::value_type::ValueType::Int8).is_null());
+            assert!(Value::Null(crate::value_type::ValueType::Int8).is_none());
+            assert!(!Value::None.is_null());
+            assert!(!Value::Int8(0).is_null());
+        }
+
         #[test]
         pub fn is_type_none() {
-            assert_eq!(true, Value::None.is_none()); // <-- This one is the only "true" test.
+            assert!(Value::None.is_none()); // <-- This one is the only "true" test.
+
+            assert!(!Value::Int8(i8::MIN).is_none());
+            assert!(!Value::Int16(i16::MIN).is_none());
+            assert!(!Value::Int32(i32::MIN).is_none());
+            assert!(!Value::Int64(i64::MIN).is_none());
+            assert!(!Value::Int128(i128::MIN).is_none());
+
+            assert!(!Value::UInt8(u8::MIN).is_none());
+            assert!(!Value::UInt16(u16::MIN).is_none());
+            assert!(!Value::UInt32(u32::MIN).is_none());
+            assert!(!Value::UInt64(u64::MIN).is_none());
+            assert!(!Value::UInt128(u128::MIN).is_none());
+
+            assert!(!Value::Float32(f32::MIN).is_none());
+            assert!(!Value::Float64(f64::MIN).is_none());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_none());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_none());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_none());
 
-            assert_eq!(false, Value::Int8(i8::MIN).is_none());
-            assert_eq!(false, Value::Int16(i16::MIN).is_none());
-            assert_eq!(false, Value::Int32(i32::MIN).is_none());
-            assert_eq!(false, Value::Int64(i64::MIN).is_none());
-            assert_eq!(false, Value::Int128(i128::MIN).is_none());
+            assert!(!Value::Char('a').is_none());
+            assert!(!Value::Bool(true).is_none());
+            assert!(!Value::String(String::from("string")).is_none());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_none());
+        }
 
-            assert_eq!(false, Value::UInt8(u8::MIN).is_none());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_none());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_none());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_none());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_none());
+        #[test]
+        pub fn is_type_some() {
+            assert!(!Value::None.is_some()); // <-- This one is the only "true" test.
+
+            assert!(Value::Int8(i8::MIN).is_some());
+            assert!(Value::Int16(i16::MIN).is_some());
+            assert!(Value::Int32(i32::MIN).is_some());
+            assert!(Value::Int64(i64::MIN).is_some());
+            assert!(Value::Int128(i128::MIN).is_some());
+
+            assert!(Value::UInt8(u8::MIN).is_some());
+            assert!(Value::UInt16(u16::MIN).is_some());
+            assert!(Value::UInt32(u32::MIN).is_some());
+            assert!(Value::UInt64(u64::MIN).is_some());
+            assert!(Value::UInt128(u128::MIN).is_some());
+
+            assert!(Value::Float32(f32::MIN).is_some());
+            assert!(Value::Float64(f64::MIN).is_some());
+
+            assert!(Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_some());
+            assert!(Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_some());
+            assert!(Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_some());
 
-            assert_eq!(false, Value::Float32(f32::MIN).is_none());
-            assert_eq!(false, Value::Float64(f64::MIN).is_none());
+            assert!(Value::Char('a').is_some());
+            assert!(Value::Bool(true).is_some());
+            assert!(Value::String(String::from("string")).is_some());
+            assert!(Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_some());
+        }
 
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_none()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_none()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_none()
-            );
+        #[test]
+        pub fn is_type_int8() {
+            assert!(Value::Int8(i8::MIN).is_int8()); // <-- This one is the only "true" test.
+
+            assert!(!Value::Int16(i16::MIN).is_int8());
+            assert!(!Value::Int32(i32::MIN).is_int8());
+            assert!(!Value::Int64(i64::MIN).is_int8());
+            assert!(!Value::Int128(i128::MIN).is_int8());
+
+            assert!(!Value::UInt8(u8::MIN).is_int8());
+            assert!(!Value::UInt16(u16::MIN).is_int8());
+            assert!(!Value::UInt32(u32::MIN).is_int8());
+            assert!(!Value::UInt64(u64::MIN).is_int8());
+            assert!(!Value::UInt128(u128::MIN).is_int8());
+
+            assert!(!Value::Float32(f32::MIN).is_int8());
+            assert!(!Value::Float64(f64::MIN).is_int8());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_int8());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_int8());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_int8());
 
-            assert_eq!(false, Value::Char('a').is_none());
-            assert_eq!(false, Value::Bool(true).is_none());
-            assert_eq!(false, Value::String(String::from("string")).is_none());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_none()
-            );
+            assert!(!Value::Char('a').is_int8());
+            assert!(!Value::Bool(true).is_int8());
+            assert!(!Value::String(String::from("string")).is_int8());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_int8());
         }
 
         #[test]
-        pub fn is_type_some() {
-            assert_eq!(false, Value::None.is_some()); // <-- This one is the only "true" test.
+        pub fn is_type_int16() {
+            assert!(!Value::Int8(i8::MIN).is_int16());
 
-            assert_eq!(true, Value::Int8(i8::MIN).is_some());
-            assert_eq!(true, Value::Int16(i16::MIN).is_some());
-            assert_eq!(true, Value::Int32(i32::MIN).is_some());
-            assert_eq!(true, Value::Int64(i64::MIN).is_some());
-            assert_eq!(true, Value::Int128(i128::MIN).is_some());
+            assert!(Value::Int16(i16::MIN).is_int16()); // <-- This one is the only "true" test.
 
-            assert_eq!(true, Value::UInt8(u8::MIN).is_some());
-            assert_eq!(true, Value::UInt16(u16::MIN).is_some());
-            assert_eq!(true, Value::UInt32(u32::MIN).is_some());
-            assert_eq!(true, Value::UInt64(u64::MIN).is_some());
-            assert_eq!(true, Value::UInt128(u128::MIN).is_some());
+            assert!(!Value::Int32(i32::MIN).is_int16());
+            assert!(!Value::Int64(i64::MIN).is_int16());
+            assert!(!Value::Int128(i128::MIN).is_int16());
 
-            assert_eq!(true, Value::Float32(f32::MIN).is_some());
-            assert_eq!(true, Value::Float64(f64::MIN).is_some());
+            assert!(!Value::UInt8(u8::MIN).is_int16());
+            assert!(!Value::UInt16(u16::MIN).is_int16());
+            assert!(!Value::UInt32(u32::MIN).is_int16());
+            assert!(!Value::UInt64(u64::MIN).is_int16());
+            assert!(!Value::UInt128(u128::MIN).is_int16());
 
-            assert_eq!(
-                true,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_some()
-            );
-            assert_eq!(
-                true,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_some()
-            );
-            assert_eq!(
-                true,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_some()
-            );
+            assert!(!Value::Float32(f32::MIN).is_int16());
+            assert!(!Value::Float64(f64::MIN).is_int16());
 
-            assert_eq!(true, Value::Char('a').is_some());
-            assert_eq!(true, Value::Bool(true).is_some());
-            assert_eq!(true, Value::String(String::from("string")).is_some());
-            assert_eq!(
-                true,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_some()
-            );
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_int16());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_int16());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_int16());
+
+            assert!(!Value::Char('a').is_int16());
+            assert!(!Value::Bool(true).is_int16());
+            assert!(!Value::String(String::from("string")).is_int16());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_int16());
         }
 
         #[test]
-        pub fn is_type_int8() {
-            assert_eq!(true, Value::Int8(i8::MIN).is_int8()); // <-- This one is the only "true" test.
+        pub fn is_type_int32() {
+            assert!(!Value::Int8(i8::MIN).is_int32());
+            assert!(!Value::Int16(i16::MIN).is_int32());
 
-            assert_eq!(false, Value::Int16(i16::MIN).is_int8());
-            assert_eq!(false, Value::Int32(i32::MIN).is_int8());
-            assert_eq!(false, Value::Int64(i64::MIN).is_int8());
-            assert_eq!(false, Value::Int128(i128::MIN).is_int8());
+            assert!(Value::Int32(i32::MIN).is_int32()); // <-- This one is the only "true" test.
 
-            assert_eq!(false, Value::UInt8(u8::MIN).is_int8());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_int8());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_int8());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_int8());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_int8());
+            assert!(!Value::Int64(i64::MIN).is_int32());
+            assert!(!Value::Int128(i128::MIN).is_int32());
 
-            assert_eq!(false, Value::Float32(f32::MIN).is_int8());
-            assert_eq!(false, Value::Float64(f64::MIN).is_int8());
+            assert!(!Value::UInt8(u8::MIN).is_int32());
+            assert!(!Value::UInt16(u16::MIN).is_int32());
+            assert!(!Value::UInt32(u32::MIN).is_int32());
+            assert!(!Value::UInt64(u64::MIN).is_int32());
+            assert!(!Value::UInt128(u128::MIN).is_int32());
 
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_int8()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_int8()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_int8()
-            );
-
-            assert_eq!(false, Value::Char('a').is_int8());
-            assert_eq!(false, Value::Bool(true).is_int8());
-            assert_eq!(false, Value::String(String::from("string")).is_int8());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_int8()
-            );
-        }
-
-        #[test]
-        pub fn is_type_int16() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_int16());
-
-            assert_eq!(true, Value::Int16(i16::MIN).is_int16()); // <-- This one is the only "true" test.
-
-            assert_eq!(false, Value::Int32(i32::MIN).is_int16());
-            assert_eq!(false, Value::Int64(i64::MIN).is_int16());
-            assert_eq!(false, Value::Int128(i128::MIN).is_int16());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_int16());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_int16());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_int16());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_int16());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_int16());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_int16());
-            assert_eq!(false, Value::Float64(f64::MIN).is_int16());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_int16()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_int16()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_int16()
-            );
+            assert!(!Value::Float32(f32::MIN).is_int32());
+            assert!(!Value::Float64(f64::MIN).is_int32());
 
-            assert_eq!(false, Value::Char('a').is_int16());
-            assert_eq!(false, Value::Bool(true).is_int16());
-            assert_eq!(false, Value::String(String::from("string")).is_int16());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_int16()
-            );
-        }
-
-        #[test]
-        pub fn is_type_int32() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_int32());
-            assert_eq!(false, Value::Int16(i16::MIN).is_int32());
-
-            assert_eq!(true, Value::Int32(i32::MIN).is_int32()); // <-- This one is the only "true" test.
-
-            assert_eq!(false, Value::Int64(i64::MIN).is_int32());
-            assert_eq!(false, Value::Int128(i128::MIN).is_int32());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_int32());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_int32());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_int32());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_int32());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_int32());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_int32());
-            assert_eq!(false, Value::Float64(f64::MIN).is_int32());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_int32()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_int32()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_int32()
-            );
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_int32());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_int32());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_int32());
 
-            assert_eq!(false, Value::Char('a').is_int32());
-            assert_eq!(false, Value::Bool(true).is_int32());
-            assert_eq!(false, Value::String(String::from("string")).is_int32());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_int32()
-            );
+            assert!(!Value::Char('a').is_int32());
+            assert!(!Value::Bool(true).is_int32());
+            assert!(!Value::String(String::from("string")).is_int32());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_int32());
         }
 
         #[test]
         pub fn is_type_int64() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_int64());
-            assert_eq!(false, Value::Int16(i16::MIN).is_int64());
-            assert_eq!(false, Value::Int32(i32::MIN).is_int64());
+            assert!(!Value::Int8(i8::MIN).is_int64());
+            assert!(!Value::Int16(i16::MIN).is_int64());
+            assert!(!Value::Int32(i32::MIN).is_int64());
 
-            assert_eq!(true, Value::Int64(i64::MIN).is_int64()); // <-- This one is the only "true" test.
+            assert!(Value::Int64(i64::MIN).is_int64()); // <-- This one is the only "true" test.
 
-            assert_eq!(false, Value::Int128(i128::MIN).is_int64());
+            assert!(!Value::Int128(i128::MIN).is_int64());
 
-            assert_eq!(false, Value::UInt8(u8::MIN).is_int64());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_int64());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_int64());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_int64());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_int64());
+            assert!(!Value::UInt8(u8::MIN).is_int64());
+            assert!(!Value::UInt16(u16::MIN).is_int64());
+            assert!(!Value::UInt32(u32::MIN).is_int64());
+            assert!(!Value::UInt64(u64::MIN).is_int64());
+            assert!(!Value::UInt128(u128::MIN).is_int64());
 
-            assert_eq!(false, Value::Float32(f32::MIN).is_int64());
-            assert_eq!(false, Value::Float64(f64::MIN).is_int64());
+            assert!(!Value::Float32(f32::MIN).is_int64());
+            assert!(!Value::Float64(f64::MIN).is_int64());
 
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_int64()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_int64()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_int64()
-            );
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_int64());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_int64());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_int64());
 
-            assert_eq!(false, Value::Char('a').is_int64());
-            assert_eq!(false, Value::Bool(true).is_int64());
-            assert_eq!(false, Value::String(String::from("string")).is_int64());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_int64()
-            );
+            assert!(!Value::Char('a').is_int64());
+            assert!(!Value::Bool(true).is_int64());
+            assert!(!Value::String(String::from("string")).is_int64());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_int64());
         }
 
         #[test]
         pub fn is_type_int128() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_int128());
-            assert_eq!(false, Value::Int16(i16::MIN).is_int128());
-            assert_eq!(false, Value::Int32(i32::MIN).is_int128());
-            assert_eq!(false, Value::Int64(i64::MIN).is_int128());
-
-            assert_eq!(true, Value::Int128(i128::MIN).is_int128()); // <-- This one is the only "true" test.
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_int128());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_int128());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_int128());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_int128());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_int128());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_int128());
-            assert_eq!(false, Value::Float64(f64::MIN).is_int128());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_int128()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_int128()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_int128()
-            );
+            assert!(!Value::Int8(i8::MIN).is_int128());
+            assert!(!Value::Int16(i16::MIN).is_int128());
+            assert!(!Value::Int32(i32::MIN).is_int128());
+            assert!(!Value::Int64(i64::MIN).is_int128());
+
+            assert!(Value::Int128(i128::MIN).is_int128()); // <-- This one is the only "true" test.
+
+            assert!(!Value::UInt8(u8::MIN).is_int128());
+            assert!(!Value::UInt16(u16::MIN).is_int128());
+            assert!(!Value::UInt32(u32::MIN).is_int128());
+            assert!(!Value::UInt64(u64::MIN).is_int128());
+            assert!(!Value::UInt128(u128::MIN).is_int128());
+
+            assert!(!Value::Float32(f32::MIN).is_int128());
+            assert!(!Value::Float64(f64::MIN).is_int128());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_int128());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_int128());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_int128());
 
-            assert_eq!(false, Value::Char('a').is_int128());
-            assert_eq!(false, Value::Bool(true).is_int128());
-            assert_eq!(false, Value::String(String::from("string")).is_int128());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_int128()
-            );
+            assert!(!Value::Char('a').is_int128());
+            assert!(!Value::Bool(true).is_int128());
+            assert!(!Value::String(String::from("string")).is_int128());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_int128());
         }
 
         #[test]
         pub fn is_type_uint8() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_uint8());
-            assert_eq!(false, Value::Int16(i16::MIN).is_uint8());
-            assert_eq!(false, Value::Int32(i32::MIN).is_uint8());
-            assert_eq!(false, Value::Int64(i64::MIN).is_uint8());
-            assert_eq!(false, Value::Int128(i128::MIN).is_uint8());
-
-            assert_eq!(true, Value::UInt8(u8::MIN).is_uint8()); // <-- This one is the only "true" test.
-            assert_eq!(false, Value::UInt16(u16::MIN).is_uint8());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_uint8());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_uint8());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_uint8());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_uint8());
-            assert_eq!(false, Value::Float64(f64::MIN).is_uint8());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_uint8()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_uint8()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_uint8()
-            );
+            assert!(!Value::Int8(i8::MIN).is_uint8());
+            assert!(!Value::Int16(i16::MIN).is_uint8());
+            assert!(!Value::Int32(i32::MIN).is_uint8());
+            assert!(!Value::Int64(i64::MIN).is_uint8());
+            assert!(!Value::Int128(i128::MIN).is_uint8());
+
+            assert!(Value::UInt8(u8::MIN).is_uint8()); // <-- This one is the only "true" test.
+            assert!(!Value::UInt16(u16::MIN).is_uint8());
+            assert!(!Value::UInt32(u32::MIN).is_uint8());
+            assert!(!Value::UInt64(u64::MIN).is_uint8());
+            assert!(!Value::UInt128(u128::MIN).is_uint8());
+
+            assert!(!Value::Float32(f32::MIN).is_uint8());
+            assert!(!Value::Float64(f64::MIN).is_uint8());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_uint8());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_uint8());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_uint8());
 
-            assert_eq!(false, Value::Char('a').is_uint8());
-            assert_eq!(false, Value::Bool(true).is_uint8());
-            assert_eq!(false, Value::String(String::from("string")).is_uint8());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_uint8()
-            );
+            assert!(!Value::Char('a').is_uint8());
+            assert!(!Value::Bool(true).is_uint8());
+            assert!(!Value::String(String::from("string")).is_uint8());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_uint8());
         }
 
         #[test]
         pub fn is_type_uint16() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_uint16());
-            assert_eq!(false, Value::Int16(i16::MIN).is_uint16());
-            assert_eq!(false, Value::Int32(i32::MIN).is_uint16());
-            assert_eq!(false, Value::Int64(i64::MIN).is_uint16());
-            assert_eq!(false, Value::Int128(i128::MIN).is_uint16());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_uint16());
-            assert_eq!(true, Value::UInt16(u16::MIN).is_uint16()); // <-- This one is the only "true" test.
-            assert_eq!(false, Value::UInt32(u32::MIN).is_uint16());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_uint16());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_uint16());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_uint16());
-            assert_eq!(false, Value::Float64(f64::MIN).is_uint16());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_uint16()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_uint16()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_uint16()
-            );
+            assert!(!Value::Int8(i8::MIN).is_uint16());
+            assert!(!Value::Int16(i16::MIN).is_uint16());
+            assert!(!Value::Int32(i32::MIN).is_uint16());
+            assert!(!Value::Int64(i64::MIN).is_uint16());
+            assert!(!Value::Int128(i128::MIN).is_uint16());
+
+            assert!(!Value::UInt8(u8::MIN).is_uint16());
+            assert!(Value::UInt16(u16::MIN).is_uint16()); // <-- This one is the only "true" test.
+            assert!(!Value::UInt32(u32::MIN).is_uint16());
+            assert!(!Value::UInt64(u64::MIN).is_uint16());
+            assert!(!Value::UInt128(u128::MIN).is_uint16());
+
+            assert!(!Value::Float32(f32::MIN).is_uint16());
+            assert!(!Value::Float64(f64::MIN).is_uint16());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_uint16());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_uint16());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_uint16());
 
-            assert_eq!(false, Value::Char('a').is_uint16());
-            assert_eq!(false, Value::Bool(true).is_uint16());
-            assert_eq!(false, Value::String(String::from("string")).is_uint16());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_uint16()
-            );
+            assert!(!Value::Char('a').is_uint16());
+            assert!(!Value::Bool(true).is_uint16());
+            assert!(!Value::String(String::from("string")).is_uint16());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_uint16());
         }
 
         #[test]
         pub fn is_type_uint32() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_uint32());
-            assert_eq!(false, Value::Int16(i16::MIN).is_uint32());
-            assert_eq!(false, Value::Int32(i32::MIN).is_uint32());
-            assert_eq!(false, Value::Int64(i64::MIN).is_uint32());
-            assert_eq!(false, Value::Int128(i128::MIN).is_uint32());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_uint32());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_uint32());
-            assert_eq!(true, Value::UInt32(u32::MIN).is_uint32()); // <-- This one is the only "true" test.
-            assert_eq!(false, Value::UInt64(u64::MIN).is_uint32());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_uint32());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_uint32());
-            assert_eq!(false, Value::Float64(f64::MIN).is_uint32());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_uint32()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_uint32()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_uint32()
-            );
+            assert!(!Value::Int8(i8::MIN).is_uint32());
+            assert!(!Value::Int16(i16::MIN).is_uint32());
+            assert!(!Value::Int32(i32::MIN).is_uint32());
+            assert!(!Value::Int64(i64::MIN).is_uint32());
+            assert!(!Value::Int128(i128::MIN).is_uint32());
+
+            assert!(!Value::UInt8(u8::MIN).is_uint32());
+            assert!(!Value::UInt16(u16::MIN).is_uint32());
+            assert!(Value::UInt32(u32::MIN).is_uint32()); // <-- This one is the only "true" test.
+            assert!(!Value::UInt64(u64::MIN).is_uint32());
+            assert!(!Value::UInt128(u128::MIN).is_uint32());
+
+            assert!(!Value::Float32(f32::MIN).is_uint32());
+            assert!(!Value::Float64(f64::MIN).is_uint32());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_uint32());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_uint32());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_uint32());
 
-            assert_eq!(false, Value::Char('a').is_uint32());
-            assert_eq!(false, Value::Bool(true).is_uint32());
-            assert_eq!(false, Value::String(String::from("string")).is_uint32());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_uint32()
-            );
+            assert!(!Value::Char('a').is_uint32());
+            assert!(!Value::Bool(true).is_uint32());
+            assert!(!Value::String(String::from("string")).is_uint32());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_uint32());
         }
 
         #[test]
         pub fn is_type_uint64() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_uint64());
-            assert_eq!(false, Value::Int16(i16::MIN).is_uint64());
-            assert_eq!(false, Value::Int32(i32::MIN).is_uint64());
-            assert_eq!(false, Value::Int64(i64::MIN).is_uint64());
-            assert_eq!(false, Value::Int128(i128::MIN).is_uint64());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_uint64());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_uint64());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_uint64());
-            assert_eq!(true, Value::UInt64(u64::MIN).is_uint64()); // <-- This one is the only "true" test.
-            assert_eq!(false, Value::UInt128(u128::MIN).is_uint64());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_uint64());
-            assert_eq!(false, Value::Float64(f64::MIN).is_uint64());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_uint64()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_uint64()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_uint64()
-            );
+            assert!(!Value::Int8(i8::MIN).is_uint64());
+            assert!(!Value::Int16(i16::MIN).is_uint64());
+            assert!(!Value::Int32(i32::MIN).is_uint64());
+            assert!(!Value::Int64(i64::MIN).is_uint64());
+            assert!(!Value::Int128(i128::MIN).is_uint64());
+
+            assert!(!Value::UInt8(u8::MIN).is_uint64());
+            assert!(!Value::UInt16(u16::MIN).is_uint64());
+            assert!(!Value::UInt32(u32::MIN).is_uint64());
+            assert!(Value::UInt64(u64::MIN).is_uint64()); // <-- This one is the only "true" test.
+            assert!(!Value::UInt128(u128::MIN).is_uint64());
+
+            assert!(!Value::Float32(f32::MIN).is_uint64());
+            assert!(!Value::Float64(f64::MIN).is_uint64());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_uint64());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_uint64());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_uint64());
 
-            assert_eq!(false, Value::Char('a').is_uint64());
-            assert_eq!(false, Value::Bool(true).is_uint64());
-            assert_eq!(false, Value::String(String::from("string")).is_uint64());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_uint64()
-            );
+            assert!(!Value::Char('a').is_uint64());
+            assert!(!Value::Bool(true).is_uint64());
+            assert!(!Value::String(String::from("string")).is_uint64());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_uint64());
         }
 
         #[test]
         pub fn is_type_uint128() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_uint128());
-            assert_eq!(false, Value::Int16(i16::MIN).is_uint128());
-            assert_eq!(false, Value::Int32(i32::MIN).is_uint128());
-            assert_eq!(false, Value::Int64(i64::MIN).is_uint128());
-            assert_eq!(false, Value::Int128(i128::MIN).is_uint128());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_uint128());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_uint128());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_uint128());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_uint128());
-            assert_eq!(true, Value::UInt128(u128::MIN).is_uint128()); // <-- This one is the only "true" test.
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_uint128());
-            assert_eq!(false, Value::Float64(f64::MIN).is_uint128());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_uint128()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_uint128()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_uint128()
-            );
+            assert!(!Value::Int8(i8::MIN).is_uint128());
+            assert!(!Value::Int16(i16::MIN).is_uint128());
+            assert!(!Value::Int32(i32::MIN).is_uint128());
+            assert!(!Value::Int64(i64::MIN).is_uint128());
+            assert!(!Value::Int128(i128::MIN).is_uint128());
+
+            assert!(!Value::UInt8(u8::MIN).is_uint128());
+            assert!(!Value::UInt16(u16::MIN).is_uint128());
+            assert!(!Value::UInt32(u32::MIN).is_uint128());
+            assert!(!Value::UInt64(u64::MIN).is_uint128());
+            assert!(Value::UInt128(u128::MIN).is_uint128()); // <-- This one is the only "true" test.
+
+            assert!(!Value::Float32(f32::MIN).is_uint128());
+            assert!(!Value::Float64(f64::MIN).is_uint128());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_uint128());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_uint128());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_uint128());
 
-            assert_eq!(false, Value::Char('a').is_uint128());
-            assert_eq!(false, Value::Bool(true).is_uint128());
-            assert_eq!(false, Value::String(String::from("string")).is_uint128());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_uint128()
-            );
+            assert!(!Value::Char('a').is_uint128());
+            assert!(!Value::Bool(true).is_uint128());
+            assert!(!Value::String(String::from("string")).is_uint128());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_uint128());
         }
 
         #[test]
         pub fn is_type_float32() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_float32());
-            assert_eq!(false, Value::Int16(i16::MIN).is_float32());
-            assert_eq!(false, Value::Int32(i32::MIN).is_float32());
-            assert_eq!(false, Value::Int64(i64::MIN).is_float32());
-            assert_eq!(false, Value::Int128(i128::MIN).is_float32());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_float32());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_float32());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_float32());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_float32());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_float32());
-
-            assert_eq!(true, Value::Float32(f32::MIN).is_float32()); // <-- This one is the only "true" test.
-            assert_eq!(false, Value::Float64(f64::MIN).is_float32());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_float32()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_float32()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_float32()
-            );
+            assert!(!Value::Int8(i8::MIN).is_float32());
+            assert!(!Value::Int16(i16::MIN).is_float32());
+            assert!(!Value::Int32(i32::MIN).is_float32());
+            assert!(!Value::Int64(i64::MIN).is_float32());
+            assert!(!Value::Int128(i128::MIN).is_float32());
+
+            assert!(!Value::UInt8(u8::MIN).is_float32());
+            assert!(!Value::UInt16(u16::MIN).is_float32());
+            assert!(!Value::UInt32(u32::MIN).is_float32());
+            assert!(!Value::UInt64(u64::MIN).is_float32());
+            assert!(!Value::UInt128(u128::MIN).is_float32());
+
+            assert!(Value::Float32(f32::MIN).is_float32()); // <-- This one is the only "true" test.
+            assert!(!Value::Float64(f64::MIN).is_float32());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_float32());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_float32());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_float32());
 
-            assert_eq!(false, Value::Char('a').is_float32());
-            assert_eq!(false, Value::Bool(true).is_float32());
-            assert_eq!(false, Value::String(String::from("string")).is_float32());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_float32()
-            );
+            assert!(!Value::Char('a').is_float32());
+            assert!(!Value::Bool(true).is_float32());
+            assert!(!Value::String(String::from("string")).is_float32());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_float32());
         }
 
         #[test]
         pub fn is_type_float64() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_float64());
-            assert_eq!(false, Value::Int16(i16::MIN).is_float64());
-            assert_eq!(false, Value::Int32(i32::MIN).is_float64());
-            assert_eq!(false, Value::Int64(i64::MIN).is_float64());
-            assert_eq!(false, Value::Int128(i128::MIN).is_float64());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_float64());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_float64());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_float64());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_float64());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_float64());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_float64());
-            assert_eq!(true, Value::Float64(f64::MIN).is_float64()); // <-- This one is the only "true" test.
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_float64()
-            );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_float64()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_float64()
-            );
+            assert!(!Value::Int8(i8::MIN).is_float64());
+            assert!(!Value::Int16(i16::MIN).is_float64());
+            assert!(!Value::Int32(i32::MIN).is_float64());
+            assert!(!Value::Int64(i64::MIN).is_float64());
+            assert!(!Value::Int128(i128::MIN).is_float64());
+
+            assert!(!Value::UInt8(u8::MIN).is_float64());
+            assert!(!Value::UInt16(u16::MIN).is_float64());
+            assert!(!Value::UInt32(u32::MIN).is_float64());
+            assert!(!Value::UInt64(u64::MIN).is_float64());
+            assert!(!Value::UInt128(u128::MIN).is_float64());
+
+            assert!(!Value::Float32(f32::MIN).is_float64());
+            assert!(Value::Float64(f64::MIN).is_float64()); // <-- This one is the only "true" test.
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_float64());
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_float64());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_float64());
 
-            assert_eq!(false, Value::Char('a').is_float64());
-            assert_eq!(false, Value::Bool(true).is_float64());
-            assert_eq!(false, Value::String(String::from("string")).is_float64());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_float64()
-            );
+            assert!(!Value::Char('a').is_float64());
+            assert!(!Value::Bool(true).is_float64());
+            assert!(!Value::String(String::from("string")).is_float64());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_float64());
         }
 
         #[test]
         pub fn is_type_naive_date() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_naive_date());
-            assert_eq!(false, Value::Int16(i16::MIN).is_naive_date());
-            assert_eq!(false, Value::Int32(i32::MIN).is_naive_date());
-            assert_eq!(false, Value::Int64(i64::MIN).is_naive_date());
-            assert_eq!(false, Value::Int128(i128::MIN).is_naive_date());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_naive_date());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_naive_date());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_naive_date());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_naive_date());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_naive_date());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_naive_date());
-            assert_eq!(false, Value::Float64(f64::MIN).is_naive_date());
-
-            assert_eq!(
-                true,
+            assert!(!Value::Int8(i8::MIN).is_naive_date());
+            assert!(!Value::Int16(i16::MIN).is_naive_date());
+            assert!(!Value::Int32(i32::MIN).is_naive_date());
+            assert!(!Value::Int64(i64::MIN).is_naive_date());
+            assert!(!Value::Int128(i128::MIN).is_naive_date());
+
+            assert!(!Value::UInt8(u8::MIN).is_naive_date());
+            assert!(!Value::UInt16(u16::MIN).is_naive_date());
+            assert!(!Value::UInt32(u32::MIN).is_naive_date());
+            assert!(!Value::UInt64(u64::MIN).is_naive_date());
+            assert!(!Value::UInt128(u128::MIN).is_naive_date());
+
+            assert!(!Value::Float32(f32::MIN).is_naive_date());
+            assert!(!Value::Float64(f64::MIN).is_naive_date());
+
+            assert!(
                 Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_naive_date() // <-- This one is the only "true" test.
             );
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_naive_date()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_naive_date()
-            );
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_naive_date());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_naive_date());
 
-            assert_eq!(false, Value::Char('a').is_naive_date());
-            assert_eq!(false, Value::Bool(true).is_naive_date());
-            assert_eq!(false, Value::String(String::from("string")).is_naive_date());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_naive_date()
-            );
+            assert!(!Value::Char('a').is_naive_date());
+            assert!(!Value::Bool(true).is_naive_date());
+            assert!(!Value::String(String::from("string")).is_naive_date());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_naive_date());
         }
 
         #[test]
         pub fn is_type_naive_date_time() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_naive_date_time());
-            assert_eq!(false, Value::Int16(i16::MIN).is_naive_date_time());
-            assert_eq!(false, Value::Int32(i32::MIN).is_naive_date_time());
-            assert_eq!(false, Value::Int64(i64::MIN).is_naive_date_time());
-            assert_eq!(false, Value::Int128(i128::MIN).is_naive_date_time());
+            assert!(!Value::Int8(i8::MIN).is_naive_date_time());
+            assert!(!Value::Int16(i16::MIN).is_naive_date_time());
+            assert!(!Value::Int32(i32::MIN).is_naive_date_time());
+            assert!(!Value::Int64(i64::MIN).is_naive_date_time());
+            assert!(!Value::Int128(i128::MIN).is_naive_date_time());
 
-            assert_eq!(false, Value::UInt8(u8::MIN).is_naive_date_time());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_naive_date_time());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_naive_date_time());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_naive_date_time());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_naive_date_time());
+            assert!(!Value::UInt8(u8::MIN).is_naive_date_time());
+            assert!(!Value::UInt16(u16::MIN).is_naive_date_time());
+            assert!(!Value::UInt32(u32::MIN).is_naive_date_time());
+            assert!(!Value::UInt64(u64::MIN).is_naive_date_time());
+            assert!(!Value::UInt128(u128::MIN).is_naive_date_time());
 
-            assert_eq!(false, Value::Float32(f32::MIN).is_naive_date_time());
-            assert_eq!(false, Value::Float64(f64::MIN).is_naive_date_time());
+            assert!(!Value::Float32(f32::MIN).is_naive_date_time());
+            assert!(!Value::Float64(f64::MIN).is_naive_date_time());
 
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_naive_date_time()
+            assert!(
+                !Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap())
+                    .is_naive_date_time()
             );
 
             // This one is the only "true" test.
-            assert_eq!(
-                true,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_naive_date_time()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_naive_date_time()
-            );
+            assert!(Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_naive_date_time());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_naive_date_time());
 
-            assert_eq!(false, Value::Char('a').is_naive_date_time());
-            assert_eq!(false, Value::Bool(true).is_naive_date_time());
-            assert_eq!(
-                false,
-                Value::String(String::from("string")).is_naive_date_time()
-            );
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_naive_date_time()
-            );
+            assert!(!Value::Char('a').is_naive_date_time());
+            assert!(!Value::Bool(true).is_naive_date_time());
+            assert!(!Value::String(String::from("string")).is_naive_date_time());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_naive_date_time());
         }
 
         #[test]
         pub fn is_type_date_time() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_date_time());
-            assert_eq!(false, Value::Int16(i16::MIN).is_date_time());
-            assert_eq!(false, Value::Int32(i32::MIN).is_date_time());
-            assert_eq!(false, Value::Int64(i64::MIN).is_date_time());
-            assert_eq!(false, Value::Int128(i128::MIN).is_date_time());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_date_time());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_date_time());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_date_time());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_date_time());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_date_time());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_date_time());
-            assert_eq!(false, Value::Float64(f64::MIN).is_date_time());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_date_time()
-            );
-
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_date_time()
-            );
+            assert!(!Value::Int8(i8::MIN).is_date_time());
+            assert!(!Value::Int16(i16::MIN).is_date_time());
+            assert!(!Value::Int32(i32::MIN).is_date_time());
+            assert!(!Value::Int64(i64::MIN).is_date_time());
+            assert!(!Value::Int128(i128::MIN).is_date_time());
+
+            assert!(!Value::UInt8(u8::MIN).is_date_time());
+            assert!(!Value::UInt16(u16::MIN).is_date_time());
+            assert!(!Value::UInt32(u32::MIN).is_date_time());
+            assert!(!Value::UInt64(u64::MIN).is_date_time());
+            assert!(!Value::UInt128(u128::MIN).is_date_time());
+
+            assert!(!Value::Float32(f32::MIN).is_date_time());
+            assert!(!Value::Float64(f64::MIN).is_date_time());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_date_time());
+
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_date_time());
 
             // This one is the only "true" test.
-            assert_eq!(
-                true,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_date_time()
-            );
+            assert!(Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_date_time());
 
-            assert_eq!(false, Value::Char('a').is_date_time());
-            assert_eq!(false, Value::Bool(true).is_date_time());
-            assert_eq!(false, Value::String(String::from("string")).is_date_time());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_date_time()
-            );
+            assert!(!Value::Char('a').is_date_time());
+            assert!(!Value::Bool(true).is_date_time());
+            assert!(!Value::String(String::from("string")).is_date_time());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_date_time());
         }
 
         #[test]
         pub fn is_type_char() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_char());
-            assert_eq!(false, Value::Int16(i16::MIN).is_char());
-            assert_eq!(false, Value::Int32(i32::MIN).is_char());
-            assert_eq!(false, Value::Int64(i64::MIN).is_char());
-            assert_eq!(false, Value::Int128(i128::MIN).is_char());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_char());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_char());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_char());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_char());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_char());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_char());
-            assert_eq!(false, Value::Float64(f64::MIN).is_char());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_char()
-            );
-
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_char()
-            );
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_char()
-            );
+            assert!(!Value::Int8(i8::MIN).is_char());
+            assert!(!Value::Int16(i16::MIN).is_char());
+            assert!(!Value::Int32(i32::MIN).is_char());
+            assert!(!Value::Int64(i64::MIN).is_char());
+            assert!(!Value::Int128(i128::MIN).is_char());
+
+            assert!(!Value::UInt8(u8::MIN).is_char());
+            assert!(!Value::UInt16(u16::MIN).is_char());
+            assert!(!Value::UInt32(u32::MIN).is_char());
+            assert!(!Value::UInt64(u64::MIN).is_char());
+            assert!(!Value::UInt128(u128::MIN).is_char());
+
+            assert!(!Value::Float32(f32::MIN).is_char());
+            assert!(!Value::Float64(f64::MIN).is_char());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_char());
+
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_char());
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_char());
 
-            assert_eq!(true, Value::Char('a').is_char()); // <-- This one is the only "true" test.
-            assert_eq!(false, Value::Bool(true).is_char());
-            assert_eq!(false, Value::String(String::from("string")).is_char());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_char()
-            );
+            assert!(Value::Char('a').is_char()); // <-- This one is the only "true" test.
+            assert!(!Value::Bool(true).is_char());
+            assert!(!Value::String(String::from("string")).is_char());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_char());
         }
 
         #[test]
         pub fn is_type_bool() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_bool());
-            assert_eq!(false, Value::Int16(i16::MIN).is_bool());
-            assert_eq!(false, Value::Int32(i32::MIN).is_bool());
-            assert_eq!(false, Value::Int64(i64::MIN).is_bool());
-            assert_eq!(false, Value::Int128(i128::MIN).is_bool());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_bool());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_bool());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_bool());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_bool());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_bool());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_bool());
-            assert_eq!(false, Value::Float64(f64::MIN).is_bool());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_bool()
-            );
-
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_bool()
-            );
-
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_bool()
-            );
+            assert!(!Value::Int8(i8::MIN).is_bool());
+            assert!(!Value::Int16(i16::MIN).is_bool());
+            assert!(!Value::Int32(i32::MIN).is_bool());
+            assert!(!Value::Int64(i64::MIN).is_bool());
+            assert!(!Value::Int128(i128::MIN).is_bool());
+
+            assert!(!Value::UInt8(u8::MIN).is_bool());
+            assert!(!Value::UInt16(u16::MIN).is_bool());
+            assert!(!Value::UInt32(u32::MIN).is_bool());
+            assert!(!Value::UInt64(u64::MIN).is_bool());
+            assert!(!Value::UInt128(u128::MIN).is_bool());
+
+            assert!(!Value::Float32(f32::MIN).is_bool());
+            assert!(!Value::Float64(f64::MIN).is_bool());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_bool());
+
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_bool());
+
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_bool());
 
-            assert_eq!(false, Value::Char('a').is_bool());
-            assert_eq!(true, Value::Bool(true).is_bool()); // <-- This one is the only "true" test.
-            assert_eq!(false, Value::String(String::from("string")).is_bool());
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_bool()
-            );
+            assert!(!Value::Char('a').is_bool());
+            assert!(Value::Bool(true).is_bool()); // <-- This one is the only "true" test.
+            assert!(!Value::String(String::from("string")).is_bool());
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_bool());
         }
 
         #[test]
         pub fn is_type_string() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_string());
-            assert_eq!(false, Value::Int16(i16::MIN).is_string());
-            assert_eq!(false, Value::Int32(i32::MIN).is_string());
-            assert_eq!(false, Value::Int64(i64::MIN).is_string());
-            assert_eq!(false, Value::Int128(i128::MIN).is_string());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_string());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_string());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_string());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_string());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_string());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_string());
-            assert_eq!(false, Value::Float64(f64::MIN).is_string());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_string()
-            );
-
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_string()
-            );
-
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_string()
-            );
+            assert!(!Value::Int8(i8::MIN).is_string());
+            assert!(!Value::Int16(i16::MIN).is_string());
+            assert!(!Value::Int32(i32::MIN).is_string());
+            assert!(!Value::Int64(i64::MIN).is_string());
+            assert!(!Value::Int128(i128::MIN).is_string());
+
+            assert!(!Value::UInt8(u8::MIN).is_string());
+            assert!(!Value::UInt16(u16::MIN).is_string());
+            assert!(!Value::UInt32(u32::MIN).is_string());
+            assert!(!Value::UInt64(u64::MIN).is_string());
+            assert!(!Value::UInt128(u128::MIN).is_string());
+
+            assert!(!Value::Float32(f32::MIN).is_string());
+            assert!(!Value::Float64(f64::MIN).is_string());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_string());
+
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_string());
+
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_string());
 
-            assert_eq!(false, Value::Char('a').is_string());
-            assert_eq!(false, Value::Bool(true).is_string());
-            assert_eq!(true, Value::String(String::from("string")).is_string()); // <-- This one is the only "true" test.
-            assert_eq!(
-                false,
-                Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_string()
-            );
+            assert!(!Value::Char('a').is_string());
+            assert!(!Value::Bool(true).is_string());
+            assert!(Value::String(String::from("string")).is_string()); // <-- This one is the only "true" test.
+            assert!(!Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_string());
         }
 
         #[test]
         pub fn is_type_decimal() {
-            assert_eq!(false, Value::Int8(i8::MIN).is_decimal());
-            assert_eq!(false, Value::Int16(i16::MIN).is_decimal());
-            assert_eq!(false, Value::Int32(i32::MIN).is_decimal());
-            assert_eq!(false, Value::Int64(i64::MIN).is_decimal());
-            assert_eq!(false, Value::Int128(i128::MIN).is_decimal());
-
-            assert_eq!(false, Value::UInt8(u8::MIN).is_decimal());
-            assert_eq!(false, Value::UInt16(u16::MIN).is_decimal());
-            assert_eq!(false, Value::UInt32(u32::MIN).is_decimal());
-            assert_eq!(false, Value::UInt64(u64::MIN).is_decimal());
-            assert_eq!(false, Value::UInt128(u128::MIN).is_decimal());
-
-            assert_eq!(false, Value::Float32(f32::MIN).is_decimal());
-            assert_eq!(false, Value::Float64(f64::MIN).is_decimal());
-
-            assert_eq!(
-                false,
-                Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_decimal()
-            );
-
-            assert_eq!(
-                false,
-                Value::NaiveDateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                )
-                .is_decimal()
-            );
-
-            assert_eq!(
-                false,
-                Value::DateTime(
-                    NaiveDate::from_ymd_opt(2023, 1, 1)
-                        .unwrap()
-                        .and_hms_opt(12, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
-                        .unwrap()
-                )
-                .is_decimal()
-            );
+            assert!(!Value::Int8(i8::MIN).is_decimal());
+            assert!(!Value::Int16(i16::MIN).is_decimal());
+            assert!(!Value::Int32(i32::MIN).is_decimal());
+            assert!(!Value::Int64(i64::MIN).is_decimal());
+            assert!(!Value::Int128(i128::MIN).is_decimal());
+
+            assert!(!Value::UInt8(u8::MIN).is_decimal());
+            assert!(!Value::UInt16(u16::MIN).is_decimal());
+            assert!(!Value::UInt32(u32::MIN).is_decimal());
+            assert!(!Value::UInt64(u64::MIN).is_decimal());
+            assert!(!Value::UInt128(u128::MIN).is_decimal());
+
+            assert!(!Value::Float32(f32::MIN).is_decimal());
+            assert!(!Value::Float64(f64::MIN).is_decimal());
+
+            assert!(!Value::NaiveDate(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()).is_decimal());
+
+            assert!(!Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+            .is_decimal());
+
+            assert!(!Value::DateTime(
+                NaiveDate::from_ymd_opt(2023, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            )
+            .is_decimal());
 
-            assert_eq!(false, Value::Char('a').is_decimal());
-            assert_eq!(false, Value::Bool(true).is_decimal());
-            assert_eq!(false, Value::String(String::from("string")).is_decimal());
-            assert_eq!(
-                true,
+            assert!(!Value::Char('a').is_decimal());
+            assert!(!Value::Bool(true).is_decimal());
+            assert!(!Value::String(String::from("string")).is_decimal());
+            assert!(
                 Value::Decimal(Decimal::from_f32(1.1).unwrap()).is_decimal() // <-- This one is the only "true" test.
             );
         }
@@ -2918,7 +3557,7 @@ mod tests {
         #[test]
         pub fn from_str_and_type_int8_ok_none() {
             let test = Value::from_str_and_type("", &ValueType::Int8);
-            assert_eq!(Ok(Value::None), test);
+            assert_eq!(Ok(Value::Null(ValueType::Int8)), test);
         }
 
         #[test]
@@ -2937,7 +3576,7 @@ mod tests {
         pub fn from_str_and_type_with_chrono_naive_date_ok_none() {
             let test =
                 Value::from_str_and_type_with_chrono_pattern("", &ValueType::NaiveDate, "%d.%m.%Y");
-            assert_eq!(Ok(Value::None), test);
+            assert_eq!(Ok(Value::Null(ValueType::NaiveDate)), test);
         }
 
         #[test]
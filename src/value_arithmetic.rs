@@ -0,0 +1,818 @@
+use rust_decimal::Decimal;
+use strum_macros::Display; // used to generate the operator symbols for error messages
+
+use crate::{
+    errors_result::{ArithmeticError, Result, VenumError},
+    value::Value,
+    value_type::ValueType,
+};
+
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ArithmeticOp {
+    #[strum(serialize = "+")]
+    Add,
+    #[strum(serialize = "-")]
+    Sub,
+    #[strum(serialize = "*")]
+    Mul,
+    #[strum(serialize = "/")]
+    Div,
+    #[strum(serialize = "%")]
+    Rem,
+}
+
+impl ArithmeticOp {
+    fn is_division(&self) -> bool {
+        matches!(self, ArithmeticOp::Div | ArithmeticOp::Rem)
+    }
+}
+
+fn mk_unsupported_err(op: ArithmeticOp, lhs: &Value, rhs: &Value) -> VenumError {
+    VenumError::Arithmetic(ArithmeticError::UnsupportedOperands {
+        op: op.to_string(),
+        lhs: lhs.clone(),
+        rhs: rhs.clone(),
+    })
+}
+
+fn is_zero(v: &Value) -> bool {
+    match v {
+        Value::Int8(v) => *v == 0,
+        Value::Int16(v) => *v == 0,
+        Value::Int32(v) => *v == 0,
+        Value::Int64(v) => *v == 0,
+        Value::Int128(v) => *v == 0,
+        Value::UInt8(v) => *v == 0,
+        Value::UInt16(v) => *v == 0,
+        Value::UInt32(v) => *v == 0,
+        Value::UInt64(v) => *v == 0,
+        Value::UInt128(v) => *v == 0,
+        Value::Float32(v) => *v == 0.0,
+        Value::Float64(v) => *v == 0.0,
+        Value::Decimal(v) => v.is_zero(),
+        _ => false,
+    }
+}
+
+/// Promotes both operands to their common numeric type. See: `ValueType::numeric_supertype`
+fn promote(op: ArithmeticOp, lhs: &Value, rhs: &Value) -> Result<(ValueType, Value, Value)> {
+    // typed nulls do have a type, but nothing to calculate with
+    if lhs.is_null() || rhs.is_null() {
+        return Err(mk_unsupported_err(op, lhs, rhs));
+    }
+    let lhs_type = ValueType::try_from(lhs).map_err(|_err| mk_unsupported_err(op, lhs, rhs))?;
+    let rhs_type = ValueType::try_from(rhs).map_err(|_err| mk_unsupported_err(op, lhs, rhs))?;
+    let target_type = lhs_type
+        .numeric_supertype(&rhs_type)
+        .ok_or_else(|| mk_unsupported_err(op, lhs, rhs))?;
+    let mk_overflow_err = || {
+        VenumError::Arithmetic(ArithmeticError::Overflow {
+            op: op.to_string(),
+            lhs: lhs.clone(),
+            rhs: rhs.clone(),
+            target_type: target_type.clone(),
+        })
+    };
+    let lhs_promoted = promote_exact(lhs, &target_type).ok_or_else(mk_overflow_err)?;
+    let rhs_promoted = promote_exact(rhs, &target_type).ok_or_else(mk_overflow_err)?;
+    Ok((target_type, lhs_promoted, rhs_promoted))
+}
+
+/// Converts the value to the target type, but only if that doesn't change it, e.g. ints that would be rounded as floats.
+fn promote_exact(value: &Value, target_type: &ValueType) -> Option<Value> {
+    let promoted = value.try_convert_to(target_type).ok()?;
+    if !value.is_some_int_type() || !target_type.is_some_float_type() {
+        return Some(promoted);
+    }
+    let f = match promoted {
+        Value::Float32(f) => f as f64,
+        Value::Float64(f) => f,
+        _ => return Some(promoted),
+    };
+    // the range checks are needed, as `as` saturates, e.g. `i64::MAX as f64 as i64 == i64::MAX`
+    let exact = match value.try_convert_to(&ValueType::Int128) {
+        Ok(Value::Int128(i)) => f >= i128::MIN as f64 && f < -(i128::MIN as f64) && f as i128 == i,
+        _ => match value.try_convert_to(&ValueType::UInt128) {
+            Ok(Value::UInt128(u)) => f < u128::MAX as f64 && f as u128 == u,
+            _ => false,
+        },
+    };
+    exact.then_some(promoted)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OverflowMode {
+    Checked,
+    Wrapping,
+    Saturating,
+}
+
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnaryOp {
+    #[strum(serialize = "neg")]
+    Neg,
+    #[strum(serialize = "abs")]
+    Abs,
+}
+
+// Works for all ints and Decimal, as they share the same names for the checked functions.
+macro_rules! checked_op {
+    ($op:expr, $lhs:expr, $rhs:expr) => {
+        match $op {
+            ArithmeticOp::Add => $lhs.checked_add($rhs),
+            ArithmeticOp::Sub => $lhs.checked_sub($rhs),
+            ArithmeticOp::Mul => $lhs.checked_mul($rhs),
+            ArithmeticOp::Div => $lhs.checked_div($rhs),
+            ArithmeticOp::Rem => $lhs.checked_rem($rhs),
+        }
+    };
+}
+
+// There is no saturating_rem on the primitives. The remainder can only "overflow" for MIN % -1,
+// where the mathematically correct result is 0, which is exactly what wrapping_rem gives us.
+macro_rules! int_op {
+    ($op:expr, $mode:expr, $lhs:expr, $rhs:expr) => {
+        match ($mode, $op) {
+            (OverflowMode::Checked, _) => checked_op!($op, $lhs, $rhs),
+            (OverflowMode::Wrapping, ArithmeticOp::Add) => Some($lhs.wrapping_add($rhs)),
+            (OverflowMode::Wrapping, ArithmeticOp::Sub) => Some($lhs.wrapping_sub($rhs)),
+            (OverflowMode::Wrapping, ArithmeticOp::Mul) => Some($lhs.wrapping_mul($rhs)),
+            (OverflowMode::Wrapping, ArithmeticOp::Div) => Some($lhs.wrapping_div($rhs)),
+            (OverflowMode::Wrapping, ArithmeticOp::Rem) => Some($lhs.wrapping_rem($rhs)),
+            (OverflowMode::Saturating, ArithmeticOp::Add) => Some($lhs.saturating_add($rhs)),
+            (OverflowMode::Saturating, ArithmeticOp::Sub) => Some($lhs.saturating_sub($rhs)),
+            (OverflowMode::Saturating, ArithmeticOp::Mul) => Some($lhs.saturating_mul($rhs)),
+            (OverflowMode::Saturating, ArithmeticOp::Div) => Some($lhs.saturating_div($rhs)),
+            (OverflowMode::Saturating, ArithmeticOp::Rem) => Some($lhs.wrapping_rem($rhs)),
+        }
+    };
+}
+
+// Floats don't overflow, they become infinite. We treat a non-finite result of finite operands as an overflow
+// and clamp it to MIN / MAX when saturating. Wrapping makes no sense for floats.
+macro_rules! float_op {
+    ($op:expr, $mode:expr, $lhs:expr, $rhs:expr, $float_type:ty) => {{
+        let res = match $op {
+            ArithmeticOp::Add => $lhs + $rhs,
+            ArithmeticOp::Sub => $lhs - $rhs,
+            ArithmeticOp::Mul => $lhs * $rhs,
+            ArithmeticOp::Div => $lhs / $rhs,
+            ArithmeticOp::Rem => $lhs % $rhs,
+        };
+        if res.is_finite() || !$lhs.is_finite() || !$rhs.is_finite() {
+            Some(res)
+        } else if $mode == OverflowMode::Saturating {
+            if res.is_sign_positive() {
+                Some(<$float_type>::MAX)
+            } else {
+                Some(<$float_type>::MIN)
+            }
+        } else {
+            None
+        }
+    }};
+}
+
+fn decimal_op(op: ArithmeticOp, mode: OverflowMode, lhs: Decimal, rhs: Decimal) -> Option<Decimal> {
+    match (mode, op) {
+        (OverflowMode::Saturating, ArithmeticOp::Add) => Some(lhs.saturating_add(rhs)),
+        (OverflowMode::Saturating, ArithmeticOp::Sub) => Some(lhs.saturating_sub(rhs)),
+        (OverflowMode::Saturating, ArithmeticOp::Mul) => Some(lhs.saturating_mul(rhs)),
+        (OverflowMode::Saturating, ArithmeticOp::Div) => lhs.checked_div(rhs).or_else(|| {
+            if lhs.is_sign_negative() == rhs.is_sign_negative() {
+                Some(Decimal::MAX)
+            } else {
+                Some(Decimal::MIN)
+            }
+        }),
+        _ => checked_op!(op, lhs, rhs),
+    }
+}
+
+/// Arithmetic on the date and time variants and durations:
+/// - date (time) - date (time) of the same variant becomes a Duration, same for times (of day)
+/// - date (time) + / - Duration becomes a date (time). For NaiveDate, only the whole days of the duration count.
+/// - Duration + / - Duration becomes a Duration
+///
+/// Returns `None`, if the operands are none of the above.
+fn temporal_arith(op: ArithmeticOp, lhs: &Value, rhs: &Value) -> Option<Result<Value>> {
+    let (target_type, res) = match (op, lhs, rhs) {
+        (ArithmeticOp::Sub, Value::NaiveDate(l), Value::NaiveDate(r)) => (
+            ValueType::Duration,
+            Some(Value::Duration(l.signed_duration_since(*r))),
+        ),
+        (ArithmeticOp::Sub, Value::NaiveDateTime(l), Value::NaiveDateTime(r)) => (
+            ValueType::Duration,
+            Some(Value::Duration(l.signed_duration_since(*r))),
+        ),
+        (ArithmeticOp::Sub, Value::DateTime(l), Value::DateTime(r)) => (
+            ValueType::Duration,
+            Some(Value::Duration(l.signed_duration_since(*r))),
+        ),
+        (ArithmeticOp::Sub, Value::NaiveTime(l), Value::NaiveTime(r)) => (
+            ValueType::Duration,
+            Some(Value::Duration(l.signed_duration_since(*r))),
+        ),
+        (ArithmeticOp::Add, Value::NaiveDate(l), Value::Duration(r))
+        | (ArithmeticOp::Add, Value::Duration(r), Value::NaiveDate(l)) => (
+            ValueType::NaiveDate,
+            l.checked_add_signed(*r).map(Value::NaiveDate),
+        ),
+        (ArithmeticOp::Sub, Value::NaiveDate(l), Value::Duration(r)) => (
+            ValueType::NaiveDate,
+            l.checked_sub_signed(*r).map(Value::NaiveDate),
+        ),
+        (ArithmeticOp::Add, Value::NaiveDateTime(l), Value::Duration(r))
+        | (ArithmeticOp::Add, Value::Duration(r), Value::NaiveDateTime(l)) => (
+            ValueType::NaiveDateTime,
+            l.checked_add_signed(*r).map(Value::NaiveDateTime),
+        ),
+        (ArithmeticOp::Sub, Value::NaiveDateTime(l), Value::Duration(r)) => (
+            ValueType::NaiveDateTime,
+            l.checked_sub_signed(*r).map(Value::NaiveDateTime),
+        ),
+        (ArithmeticOp::Add, Value::DateTime(l), Value::Duration(r))
+        | (ArithmeticOp::Add, Value::Duration(r), Value::DateTime(l)) => (
+            ValueType::DateTime,
+            l.checked_add_signed(*r).map(Value::DateTime),
+        ),
+        (ArithmeticOp::Sub, Value::DateTime(l), Value::Duration(r)) => (
+            ValueType::DateTime,
+            l.checked_sub_signed(*r).map(Value::DateTime),
+        ),
+        (ArithmeticOp::Add, Value::Duration(l), Value::Duration(r)) => {
+            (ValueType::Duration, l.checked_add(r).map(Value::Duration))
+        }
+        (ArithmeticOp::Sub, Value::Duration(l), Value::Duration(r)) => {
+            (ValueType::Duration, l.checked_sub(r).map(Value::Duration))
+        }
+        _ => return None,
+    };
+    Some(res.ok_or_else(|| {
+        VenumError::Arithmetic(ArithmeticError::Overflow {
+            op: op.to_string(),
+            lhs: lhs.clone(),
+            rhs: rhs.clone(),
+            target_type,
+        })
+    }))
+}
+
+/// Does the arithmetic operation on the promoted operands. Overflows are handled according to the given mode
+/// (reported as errors, when checked). Divisions by zero are always reported as errors, instead of panicking
+/// like the primitives do.
+pub(crate) fn arith(
+    op: ArithmeticOp,
+    mode: OverflowMode,
+    lhs: &Value,
+    rhs: &Value,
+) -> Result<Value> {
+    // NOTE: Only checked arithmetic is supported for dates, times and durations.
+    if mode == OverflowMode::Checked {
+        if let Some(res) = temporal_arith(op, lhs, rhs) {
+            return res;
+        }
+    }
+    let (target_type, lhs_promoted, rhs_promoted) = promote(op, lhs, rhs)?;
+
+    if op.is_division() && is_zero(&rhs_promoted) {
+        return Err(VenumError::Arithmetic(ArithmeticError::DivisionByZero {
+            op: op.to_string(),
+            lhs: lhs.clone(),
+            rhs: rhs.clone(),
+        }));
+    }
+
+    let res = match (&lhs_promoted, &rhs_promoted) {
+        (Value::Int8(l), Value::Int8(r)) => int_op!(op, mode, l, *r).map(Value::Int8),
+        (Value::Int16(l), Value::Int16(r)) => int_op!(op, mode, l, *r).map(Value::Int16),
+        (Value::Int32(l), Value::Int32(r)) => int_op!(op, mode, l, *r).map(Value::Int32),
+        (Value::Int64(l), Value::Int64(r)) => int_op!(op, mode, l, *r).map(Value::Int64),
+        (Value::Int128(l), Value::Int128(r)) => int_op!(op, mode, l, *r).map(Value::Int128),
+        (Value::UInt8(l), Value::UInt8(r)) => int_op!(op, mode, l, *r).map(Value::UInt8),
+        (Value::UInt16(l), Value::UInt16(r)) => int_op!(op, mode, l, *r).map(Value::UInt16),
+        (Value::UInt32(l), Value::UInt32(r)) => int_op!(op, mode, l, *r).map(Value::UInt32),
+        (Value::UInt64(l), Value::UInt64(r)) => int_op!(op, mode, l, *r).map(Value::UInt64),
+        (Value::UInt128(l), Value::UInt128(r)) => int_op!(op, mode, l, *r).map(Value::UInt128),
+        (Value::Float32(_), Value::Float32(_))
+        | (Value::Float64(_), Value::Float64(_))
+        | (Value::Decimal(_), Value::Decimal(_))
+            if mode == OverflowMode::Wrapping =>
+        {
+            return Err(mk_unsupported_err(op, lhs, rhs));
+        }
+        (Value::Float32(l), Value::Float32(r)) => {
+            float_op!(op, mode, l, r, f32).map(Value::Float32)
+        }
+        (Value::Float64(l), Value::Float64(r)) => {
+            float_op!(op, mode, l, r, f64).map(Value::Float64)
+        }
+        (Value::Decimal(l), Value::Decimal(r)) => decimal_op(op, mode, *l, *r).map(Value::Decimal),
+        _ => return Err(mk_unsupported_err(op, lhs, rhs)), // can't happen after promotion
+    };
+
+    res.ok_or_else(|| {
+        VenumError::Arithmetic(ArithmeticError::Overflow {
+            op: op.to_string(),
+            lhs: lhs.clone(),
+            rhs: rhs.clone(),
+            target_type,
+        })
+    })
+}
+
+macro_rules! signed_int_unary_op {
+    ($op:expr, $mode:expr, $v:expr) => {
+        match ($mode, $op) {
+            (OverflowMode::Checked, UnaryOp::Neg) => $v.checked_neg(),
+            (OverflowMode::Checked, UnaryOp::Abs) => $v.checked_abs(),
+            (OverflowMode::Wrapping, UnaryOp::Neg) => Some($v.wrapping_neg()),
+            (OverflowMode::Wrapping, UnaryOp::Abs) => Some($v.wrapping_abs()),
+            (OverflowMode::Saturating, UnaryOp::Neg) => Some($v.saturating_neg()),
+            (OverflowMode::Saturating, UnaryOp::Abs) => Some($v.saturating_abs()),
+        }
+    };
+}
+
+// For unsigned ints, abs is always the value itself and only 0 can be negated without overflowing.
+macro_rules! unsigned_int_unary_op {
+    ($op:expr, $mode:expr, $v:expr) => {
+        match ($mode, $op) {
+            (_, UnaryOp::Abs) => Some(*$v),
+            (OverflowMode::Checked, UnaryOp::Neg) => $v.checked_neg(),
+            (OverflowMode::Wrapping, UnaryOp::Neg) => Some($v.wrapping_neg()),
+            (OverflowMode::Saturating, UnaryOp::Neg) => Some(0),
+        }
+    };
+}
+
+pub(crate) fn unary_arith(op: UnaryOp, mode: OverflowMode, v: &Value) -> Result<Value> {
+    let res = match v {
+        Value::Int8(v) => signed_int_unary_op!(op, mode, v).map(Value::Int8),
+        Value::Int16(v) => signed_int_unary_op!(op, mode, v).map(Value::Int16),
+        Value::Int32(v) => signed_int_unary_op!(op, mode, v).map(Value::Int32),
+        Value::Int64(v) => signed_int_unary_op!(op, mode, v).map(Value::Int64),
+        Value::Int128(v) => signed_int_unary_op!(op, mode, v).map(Value::Int128),
+        Value::UInt8(v) => unsigned_int_unary_op!(op, mode, v).map(Value::UInt8),
+        Value::UInt16(v) => unsigned_int_unary_op!(op, mode, v).map(Value::UInt16),
+        Value::UInt32(v) => unsigned_int_unary_op!(op, mode, v).map(Value::UInt32),
+        Value::UInt64(v) => unsigned_int_unary_op!(op, mode, v).map(Value::UInt64),
+        Value::UInt128(v) => unsigned_int_unary_op!(op, mode, v).map(Value::UInt128),
+        Value::Float32(_) | Value::Float64(_) | Value::Decimal(_)
+            if mode == OverflowMode::Wrapping =>
+        {
+            None
+        }
+        // Negation and abs of floats and decimals are symmetric and can't overflow
+        Value::Float32(v) => match op {
+            UnaryOp::Neg => Some(Value::Float32(-v)),
+            UnaryOp::Abs => Some(Value::Float32(v.abs())),
+        },
+        Value::Float64(v) => match op {
+            UnaryOp::Neg => Some(Value::Float64(-v)),
+            UnaryOp::Abs => Some(Value::Float64(v.abs())),
+        },
+        Value::Decimal(v) => match op {
+            UnaryOp::Neg => Some(Value::Decimal(-v)),
+            UnaryOp::Abs => Some(Value::Decimal(v.abs())),
+        },
+        _ => None,
+    };
+
+    match res {
+        Some(res) => Ok(res),
+        None => match ValueType::try_from(v) {
+            Ok(target_type) if target_type.is_some_int_type() && !v.is_null() => {
+                Err(VenumError::Arithmetic(ArithmeticError::UnaryOverflow {
+                    op: op.to_string(),
+                    src: v.clone(),
+                    target_type,
+                }))
+            }
+            _ => Err(VenumError::Arithmetic(
+                ArithmeticError::UnsupportedOperand {
+                    op: op.to_string(),
+                    src: v.clone(),
+                },
+            )),
+        },
+    }
+}
+
+macro_rules! arith_fn {
+    ($fn_name:ident, $op:expr, $mode:expr) => {
+        pub fn $fn_name(&self, rhs: &Value) -> Result<Value> {
+            arith($op, $mode, self, rhs)
+        }
+    };
+}
+
+macro_rules! unary_arith_fn {
+    ($fn_name:ident, $op:expr, $mode:expr) => {
+        pub fn $fn_name(&self) -> Result<Value> {
+            unary_arith($op, $mode, self)
+        }
+    };
+}
+
+/// Arithmetic with explicit overflow semantics. Both operands are promoted to a common numeric type first
+/// (see: `ValueType::numeric_supertype`), just like with the operators.
+/// - checked: overflows are reported as errors (this is what the operators do)
+/// - wrapping: overflows wrap around at the boundary of the type. Only supported for ints.
+/// - saturating: overflows are clamped to the MIN / MAX of the type (floats and Decimal included)
+///
+/// Division by zero and non-numeric operands are errors, in all modes.
+impl Value {
+    arith_fn!(checked_add, ArithmeticOp::Add, OverflowMode::Checked);
+    arith_fn!(checked_sub, ArithmeticOp::Sub, OverflowMode::Checked);
+    arith_fn!(checked_mul, ArithmeticOp::Mul, OverflowMode::Checked);
+    arith_fn!(checked_div, ArithmeticOp::Div, OverflowMode::Checked);
+    arith_fn!(checked_rem, ArithmeticOp::Rem, OverflowMode::Checked);
+    unary_arith_fn!(checked_neg, UnaryOp::Neg, OverflowMode::Checked);
+    unary_arith_fn!(checked_abs, UnaryOp::Abs, OverflowMode::Checked);
+
+    arith_fn!(wrapping_add, ArithmeticOp::Add, OverflowMode::Wrapping);
+    arith_fn!(wrapping_sub, ArithmeticOp::Sub, OverflowMode::Wrapping);
+    arith_fn!(wrapping_mul, ArithmeticOp::Mul, OverflowMode::Wrapping);
+    arith_fn!(wrapping_div, ArithmeticOp::Div, OverflowMode::Wrapping);
+    arith_fn!(wrapping_rem, ArithmeticOp::Rem, OverflowMode::Wrapping);
+    unary_arith_fn!(wrapping_neg, UnaryOp::Neg, OverflowMode::Wrapping);
+    unary_arith_fn!(wrapping_abs, UnaryOp::Abs, OverflowMode::Wrapping);
+
+    arith_fn!(saturating_add, ArithmeticOp::Add, OverflowMode::Saturating);
+    arith_fn!(saturating_sub, ArithmeticOp::Sub, OverflowMode::Saturating);
+    arith_fn!(saturating_mul, ArithmeticOp::Mul, OverflowMode::Saturating);
+    arith_fn!(saturating_div, ArithmeticOp::Div, OverflowMode::Saturating);
+    unary_arith_fn!(saturating_neg, UnaryOp::Neg, OverflowMode::Saturating);
+    unary_arith_fn!(saturating_abs, UnaryOp::Abs, OverflowMode::Saturating);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod numeric_supertype {
+        use super::*;
+
+        #[test]
+        pub fn same_types() {
+            assert_eq!(
+                Some(ValueType::Int8),
+                ValueType::Int8.numeric_supertype(&ValueType::Int8)
+            );
+            assert_eq!(
+                Some(ValueType::Decimal),
+                ValueType::Decimal.numeric_supertype(&ValueType::Decimal)
+            );
+        }
+
+        #[test]
+        pub fn ints() {
+            assert_eq!(
+                Some(ValueType::Int64),
+                ValueType::Int8.numeric_supertype(&ValueType::Int64)
+            );
+            assert_eq!(
+                Some(ValueType::UInt32),
+                ValueType::UInt32.numeric_supertype(&ValueType::UInt16)
+            );
+            assert_eq!(
+                Some(ValueType::Int16),
+                ValueType::Int8.numeric_supertype(&ValueType::UInt8)
+            );
+            assert_eq!(
+                Some(ValueType::Int32),
+                ValueType::UInt16.numeric_supertype(&ValueType::Int8)
+            );
+            assert_eq!(
+                Some(ValueType::Int128),
+                ValueType::Int32.numeric_supertype(&ValueType::UInt64)
+            );
+            assert_eq!(
+                Some(ValueType::Int128),
+                ValueType::UInt128.numeric_supertype(&ValueType::Int8)
+            );
+        }
+
+        #[test]
+        pub fn floats() {
+            assert_eq!(
+                Some(ValueType::Float64),
+                ValueType::Float32.numeric_supertype(&ValueType::Float64)
+            );
+            assert_eq!(
+                Some(ValueType::Float32),
+                ValueType::Int16.numeric_supertype(&ValueType::Float32)
+            );
+            assert_eq!(
+                Some(ValueType::Float64),
+                ValueType::Float32.numeric_supertype(&ValueType::UInt32)
+            );
+            assert_eq!(
+                Some(ValueType::Float64),
+                ValueType::Int128.numeric_supertype(&ValueType::Float64)
+            );
+        }
+
+        #[test]
+        pub fn decimal() {
+            assert_eq!(
+                Some(ValueType::Decimal),
+                ValueType::Float64.numeric_supertype(&ValueType::Decimal)
+            );
+            assert_eq!(
+                Some(ValueType::Decimal),
+                ValueType::Decimal.numeric_supertype(&ValueType::UInt8)
+            );
+        }
+
+        #[test]
+        pub fn non_numeric() {
+            assert_eq!(None, ValueType::Bool.numeric_supertype(&ValueType::Int8));
+            assert_eq!(
+                None,
+                ValueType::Int8.numeric_supertype(&ValueType::NaiveDate)
+            );
+            assert_eq!(
+                None,
+                ValueType::String.numeric_supertype(&ValueType::String)
+            );
+        }
+    }
+
+    mod checked {
+        use super::*;
+
+        #[test]
+        pub fn checked_add() {
+            assert_eq!(
+                Ok(Value::Int8(3)),
+                Value::Int8(1).checked_add(&Value::Int8(2))
+            );
+            assert_eq!(
+                Err(VenumError::Arithmetic(ArithmeticError::Overflow {
+                    op: String::from("+"),
+                    lhs: Value::UInt8(u8::MAX),
+                    rhs: Value::UInt8(1),
+                    target_type: ValueType::UInt8
+                })),
+                Value::UInt8(u8::MAX).checked_add(&Value::UInt8(1))
+            );
+        }
+
+        #[test]
+        pub fn checked_neg() {
+            assert_eq!(Ok(Value::Int16(-5)), Value::Int16(5).checked_neg());
+            assert_eq!(Ok(Value::UInt16(0)), Value::UInt16(0).checked_neg());
+            assert_eq!(
+                Err(VenumError::Arithmetic(ArithmeticError::UnaryOverflow {
+                    op: String::from("neg"),
+                    src: Value::Int8(i8::MIN),
+                    target_type: ValueType::Int8
+                })),
+                Value::Int8(i8::MIN).checked_neg()
+            );
+            assert_eq!(
+                Err(VenumError::Arithmetic(ArithmeticError::UnaryOverflow {
+                    op: String::from("neg"),
+                    src: Value::UInt32(1),
+                    target_type: ValueType::UInt32
+                })),
+                Value::UInt32(1).checked_neg()
+            );
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::new(-15, 1))),
+                Value::Decimal(Decimal::new(15, 1)).checked_neg()
+            );
+        }
+
+        #[test]
+        pub fn checked_abs() {
+            assert_eq!(Ok(Value::Int64(5)), Value::Int64(-5).checked_abs());
+            assert_eq!(Ok(Value::UInt64(5)), Value::UInt64(5).checked_abs());
+            assert_eq!(Ok(Value::Float32(1.5)), Value::Float32(-1.5).checked_abs());
+            assert_eq!(
+                Err(VenumError::Arithmetic(ArithmeticError::UnaryOverflow {
+                    op: String::from("abs"),
+                    src: Value::Int128(i128::MIN),
+                    target_type: ValueType::Int128
+                })),
+                Value::Int128(i128::MIN).checked_abs()
+            );
+        }
+
+        #[test]
+        pub fn unary_unsupported() {
+            assert_eq!(
+                Err(VenumError::Arithmetic(
+                    ArithmeticError::UnsupportedOperand {
+                        op: String::from("abs"),
+                        src: Value::Bool(true),
+                    }
+                )),
+                Value::Bool(true).checked_abs()
+            );
+            assert_eq!(
+                Err(VenumError::Arithmetic(
+                    ArithmeticError::UnsupportedOperand {
+                        op: String::from("neg"),
+                        src: Value::None,
+                    }
+                )),
+                Value::None.checked_neg()
+            );
+            assert_eq!(
+                Err(VenumError::Arithmetic(
+                    ArithmeticError::UnsupportedOperand {
+                        op: String::from("neg"),
+                        src: Value::Null(ValueType::Int8),
+                    }
+                )),
+                Value::Null(ValueType::Int8).checked_neg()
+            );
+        }
+
+        #[test]
+        pub fn null_unsupported() {
+            assert_eq!(
+                Err(VenumError::Arithmetic(
+                    ArithmeticError::UnsupportedOperands {
+                        op: String::from("+"),
+                        lhs: Value::Null(ValueType::Int8),
+                        rhs: Value::Int8(1),
+                    }
+                )),
+                Value::Null(ValueType::Int8).checked_add(&Value::Int8(1))
+            );
+        }
+    }
+
+    mod wrapping {
+        use super::*;
+
+        #[test]
+        pub fn wrapping_ints() {
+            assert_eq!(
+                Ok(Value::Int8(i8::MIN)),
+                Value::Int8(i8::MAX).wrapping_add(&Value::Int8(1))
+            );
+            assert_eq!(
+                Ok(Value::UInt16(u16::MAX)),
+                Value::UInt16(0).wrapping_sub(&Value::UInt16(1))
+            );
+            assert_eq!(
+                Ok(Value::UInt8(0)),
+                Value::UInt8(128).wrapping_mul(&Value::UInt8(2))
+            );
+            assert_eq!(
+                Ok(Value::Int32(i32::MIN)),
+                Value::Int32(i32::MIN).wrapping_div(&Value::Int32(-1))
+            );
+            assert_eq!(
+                Ok(Value::Int32(0)),
+                Value::Int32(i32::MIN).wrapping_rem(&Value::Int32(-1))
+            );
+            assert_eq!(
+                Ok(Value::Int8(i8::MIN)),
+                Value::Int8(i8::MIN).wrapping_neg()
+            );
+            assert_eq!(
+                Ok(Value::Int8(i8::MIN)),
+                Value::Int8(i8::MIN).wrapping_abs()
+            );
+            assert_eq!(Ok(Value::UInt8(255)), Value::UInt8(1).wrapping_neg());
+        }
+
+        #[test]
+        pub fn wrapping_promoted() {
+            assert_eq!(
+                Ok(Value::Int16(i16::MIN)),
+                Value::Int16(i16::MAX).wrapping_add(&Value::Int8(1))
+            );
+        }
+
+        #[test]
+        pub fn wrapping_division_by_zero() {
+            assert_eq!(
+                Err(VenumError::Arithmetic(ArithmeticError::DivisionByZero {
+                    op: String::from("/"),
+                    lhs: Value::Int8(1),
+                    rhs: Value::Int8(0),
+                })),
+                Value::Int8(1).wrapping_div(&Value::Int8(0))
+            );
+        }
+
+        #[test]
+        pub fn wrapping_unsupported() {
+            assert_eq!(
+                Err(VenumError::Arithmetic(
+                    ArithmeticError::UnsupportedOperands {
+                        op: String::from("+"),
+                        lhs: Value::Decimal(Decimal::ONE),
+                        rhs: Value::Decimal(Decimal::ONE),
+                    }
+                )),
+                Value::Decimal(Decimal::ONE).wrapping_add(&Value::Decimal(Decimal::ONE))
+            );
+            assert_eq!(
+                Err(VenumError::Arithmetic(
+                    ArithmeticError::UnsupportedOperands {
+                        op: String::from("*"),
+                        lhs: Value::Float64(1.0),
+                        rhs: Value::Int8(1),
+                    }
+                )),
+                Value::Float64(1.0).wrapping_mul(&Value::Int8(1))
+            );
+            assert_eq!(
+                Err(VenumError::Arithmetic(
+                    ArithmeticError::UnsupportedOperand {
+                        op: String::from("neg"),
+                        src: Value::Float32(1.0),
+                    }
+                )),
+                Value::Float32(1.0).wrapping_neg()
+            );
+        }
+    }
+
+    mod saturating {
+        use super::*;
+
+        #[test]
+        pub fn saturating_ints() {
+            assert_eq!(
+                Ok(Value::Int8(i8::MAX)),
+                Value::Int8(i8::MAX).saturating_add(&Value::Int8(1))
+            );
+            assert_eq!(
+                Ok(Value::UInt32(0)),
+                Value::UInt32(0).saturating_sub(&Value::UInt32(1))
+            );
+            assert_eq!(
+                Ok(Value::Int64(i64::MIN)),
+                Value::Int64(i64::MIN).saturating_mul(&Value::Int64(2))
+            );
+            assert_eq!(
+                Ok(Value::Int32(i32::MAX)),
+                Value::Int32(i32::MIN).saturating_div(&Value::Int32(-1))
+            );
+            assert_eq!(
+                Ok(Value::Int8(i8::MAX)),
+                Value::Int8(i8::MIN).saturating_neg()
+            );
+            assert_eq!(
+                Ok(Value::Int8(i8::MAX)),
+                Value::Int8(i8::MIN).saturating_abs()
+            );
+            assert_eq!(Ok(Value::UInt8(0)), Value::UInt8(1).saturating_neg());
+        }
+
+        #[test]
+        pub fn saturating_decimal() {
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::MAX)),
+                Value::Decimal(Decimal::MAX).saturating_add(&Value::Decimal(Decimal::ONE))
+            );
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::MIN)),
+                Value::Decimal(Decimal::MIN).saturating_sub(&Value::Decimal(Decimal::ONE))
+            );
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::MIN)),
+                Value::Decimal(Decimal::MAX)
+                    .saturating_mul(&Value::Decimal(Decimal::NEGATIVE_ONE))
+                    .and_then(|v| v.saturating_mul(&Value::Decimal(Decimal::TWO)))
+            );
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::MAX)),
+                Value::Decimal(Decimal::MAX).saturating_div(&Value::Decimal(Decimal::new(1, 1)))
+            );
+        }
+
+        #[test]
+        pub fn saturating_floats() {
+            assert_eq!(
+                Ok(Value::Float64(f64::MAX)),
+                Value::Float64(f64::MAX).saturating_mul(&Value::Float64(2.0))
+            );
+            assert_eq!(
+                Ok(Value::Float32(f32::MIN)),
+                Value::Float32(f32::MIN).saturating_add(&Value::Float32(f32::MIN))
+            );
+        }
+
+        #[test]
+        pub fn saturating_division_by_zero() {
+            assert_eq!(
+                Err(VenumError::Arithmetic(ArithmeticError::DivisionByZero {
+                    op: String::from("/"),
+                    lhs: Value::UInt8(1),
+                    rhs: Value::UInt64(0),
+                })),
+                Value::UInt8(1).saturating_div(&Value::UInt64(0))
+            );
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::errors_result::{ConversionError, ParseError, Result, VenumError};
+use crate::value::Value;
+use crate::value_type::ValueType;
+
+/// Which strings are booleans, e.g. "yes"/"no", "Y"/"N", "1"/"0", "on"/"off" or "wahr"/"falsch".
+/// The first true (false) value is the one used when formatting a `Value::Bool` as string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoolFormat {
+    pub true_values: Vec<String>,
+    pub false_values: Vec<String>,
+    pub case_insensitive: bool,
+}
+
+/// The plain format, i.e. only lowercase "true" and "false", like `parse_bool_from_str`
+impl Default for BoolFormat {
+    fn default() -> Self {
+        BoolFormat::new(&["true"], &["false"])
+    }
+}
+
+impl BoolFormat {
+    /// NOTE: Both lists should not be empty, as the first entries are used for formatting.
+    ///       An empty list formats as "".
+    pub fn new(true_values: &[&str], false_values: &[&str]) -> Self {
+        BoolFormat {
+            true_values: true_values.iter().map(|v| String::from(*v)).collect(),
+            false_values: false_values.iter().map(|v| String::from(*v)).collect(),
+            case_insensitive: false,
+        }
+    }
+
+    /// The usual suspects, ignoring case: true/yes/y/1/on and false/no/n/0/off
+    pub fn common() -> Self {
+        BoolFormat::new(
+            &["true", "yes", "y", "1", "on"],
+            &["false", "no", "n", "0", "off"],
+        )
+        .case_insensitive()
+    }
+
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    fn matches(&self, candidates: &[String], v: &str) -> bool {
+        candidates.iter().any(|c| {
+            if self.case_insensitive {
+                c.to_lowercase() == v.to_lowercase()
+            } else {
+                c == v
+            }
+        })
+    }
+
+    /// `None`, if the string is neither a true nor a false value
+    pub fn parse(&self, v: &str) -> Option<bool> {
+        if self.matches(&self.true_values, v) {
+            Some(true)
+        } else if self.matches(&self.false_values, v) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    pub fn format(&self, b: bool) -> &str {
+        let values = if b {
+            &self.true_values
+        } else {
+            &self.false_values
+        };
+        values.first().map(String::as_str).unwrap_or_default()
+    }
+}
+
+impl Value {
+    pub fn parse_bool_from_str_with_format(v: &str, bool_format: &BoolFormat) -> Result<Value> {
+        bool_format.parse(v).map(Value::Bool).ok_or_else(|| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: String::from("Value::Bool"),
+                details: Some(format!(
+                    "Expected one of {:?} or {:?}",
+                    bool_format.true_values, bool_format.false_values
+                )),
+            })
+        })
+    }
+
+    /// Like `try_convert_to_bool`, but a `Value::String` is expected in the given bool format.
+    pub fn try_convert_to_bool_with_format(&self, bool_format: &BoolFormat) -> Result<Value> {
+        match self {
+            Value::String(s) => bool_format.parse(s).map(Value::Bool).ok_or_else(|| {
+                VenumError::Conversion(ConversionError::NotRepresentableAs {
+                    src: self.clone(),
+                    target_type: ValueType::Bool,
+                })
+            }),
+            _ => self.try_convert_to_bool(),
+        }
+    }
+
+    /// Like `try_convert_to_string`, but a `Value::Bool` is formatted with the given bool format.
+    /// NOTE: Only plain bools, i.e. not the ones inside lists.
+    pub fn try_convert_to_string_with_bool_format(
+        &self,
+        bool_format: &BoolFormat,
+    ) -> Result<Value> {
+        match self {
+            Value::Bool(b) => Ok(Value::String(String::from(bool_format.format(*b)))),
+            _ => self.try_convert_to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod bool_format {
+        use super::*;
+
+        #[test]
+        pub fn default_format() {
+            let f = BoolFormat::default();
+            assert_eq!(Some(true), f.parse("true"));
+            assert_eq!(Some(false), f.parse("false"));
+            assert_eq!(None, f.parse("TRUE"));
+            assert_eq!(None, f.parse(""));
+            assert_eq!("true", f.format(true));
+            assert_eq!("false", f.format(false));
+        }
+
+        #[test]
+        pub fn custom() {
+            let f = BoolFormat::new(&["Y"], &["N"]);
+            assert_eq!(Some(true), f.parse("Y"));
+            assert_eq!(Some(false), f.parse("N"));
+            assert_eq!(None, f.parse("y"));
+
+            let f = BoolFormat::new(&["wahr"], &["falsch"]).case_insensitive();
+            assert_eq!(Some(true), f.parse("WAHR"));
+            assert_eq!(Some(false), f.parse("Falsch"));
+            assert_eq!("wahr", f.format(true));
+            assert_eq!("falsch", f.format(false));
+
+            assert_eq!("", BoolFormat::new(&[], &[]).format(true));
+        }
+
+        #[test]
+        pub fn common() {
+            let f = BoolFormat::common();
+            for v in ["true", "TRUE", "yes", "Yes", "y", "Y", "1", "on", "ON"] {
+                assert_eq!(Some(true), f.parse(v), "{v}");
+            }
+            for v in ["false", "False", "no", "NO", "n", "N", "0", "off", "Off"] {
+                assert_eq!(Some(false), f.parse(v), "{v}");
+            }
+            assert_eq!(None, f.parse("maybe"));
+        }
+    }
+
+    mod parse_and_convert {
+        use super::*;
+
+        #[test]
+        pub fn parse_bool_from_str_with_format() {
+            let f = BoolFormat::new(&["1"], &["0"]);
+            assert_eq!(
+                Ok(Value::Bool(true)),
+                Value::parse_bool_from_str_with_format("1", &f)
+            );
+            assert_eq!(
+                Err(VenumError::Parsing(ParseError::ValueFromStringFailed {
+                    src_value: String::from("true"),
+                    target_type: String::from("Value::Bool"),
+                    details: Some(String::from(r#"Expected one of ["1"] or ["0"]"#)),
+                })),
+                Value::parse_bool_from_str_with_format("true", &f)
+            );
+        }
+
+        #[test]
+        pub fn try_convert_to_bool_with_format() {
+            let f = BoolFormat::new(&["on"], &["off"]).case_insensitive();
+            assert_eq!(
+                Ok(Value::Bool(false)),
+                Value::String(String::from("OFF")).try_convert_to_bool_with_format(&f)
+            );
+            assert_eq!(
+                Ok(Value::Bool(true)),
+                Value::Bool(true).try_convert_to_bool_with_format(&f)
+            );
+            assert_eq!(
+                Ok(Value::Null(ValueType::Bool)),
+                Value::Null(ValueType::String).try_convert_to_bool_with_format(&f)
+            );
+            assert_eq!(
+                Err(VenumError::Conversion(
+                    ConversionError::NotRepresentableAs {
+                        src: Value::String(String::from("true")),
+                        target_type: ValueType::Bool,
+                    }
+                )),
+                Value::String(String::from("true")).try_convert_to_bool_with_format(&f)
+            );
+        }
+
+        #[test]
+        pub fn try_convert_to_string_with_bool_format() {
+            let f = BoolFormat::new(&["yes", "y"], &["no", "n"]);
+            assert_eq!(
+                Ok(Value::String(String::from("yes"))),
+                Value::Bool(true).try_convert_to_string_with_bool_format(&f)
+            );
+            assert_eq!(
+                Ok(Value::String(String::from("no"))),
+                Value::Bool(false).try_convert_to_string_with_bool_format(&f)
+            );
+            assert_eq!(
+                Ok(Value::String(String::from("1"))),
+                Value::Int8(1).try_convert_to_string_with_bool_format(&f)
+            );
+        }
+    }
+}
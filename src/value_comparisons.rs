@@ -0,0 +1,805 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use chrono::NaiveDateTime;
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+
+use crate::value::Value;
+
+// 2^127 and 2^128 are exactly representable as f64, unlike i128::MAX and u128::MAX
+const TWO_POW_127: f64 = 170141183460469231731687303715884105728.0;
+const TWO_POW_128: f64 = 340282366920938463463374607431768211456.0;
+
+// The "mathematical" representation of all numeric variants. Every int fits into one of the int variants
+// and a f32 is exactly representable as f64.
+enum Number {
+    Int(i128),
+    UInt(u128),
+    Float(f64),
+    Decimal(Decimal),
+}
+
+impl Number {
+    fn from_value(v: &Value) -> Option<Number> {
+        match v {
+            Value::Int8(v) => Some(Number::Int((*v).into())),
+            Value::Int16(v) => Some(Number::Int((*v).into())),
+            Value::Int32(v) => Some(Number::Int((*v).into())),
+            Value::Int64(v) => Some(Number::Int((*v).into())),
+            Value::Int128(v) => Some(Number::Int(*v)),
+            Value::UInt8(v) => Some(Number::UInt((*v).into())),
+            Value::UInt16(v) => Some(Number::UInt((*v).into())),
+            Value::UInt32(v) => Some(Number::UInt((*v).into())),
+            Value::UInt64(v) => Some(Number::UInt((*v).into())),
+            Value::UInt128(v) => Some(Number::UInt(*v)),
+            Value::Float32(v) => Some(Number::Float((*v).into())),
+            Value::Float64(v) => Some(Number::Float(*v)),
+            Value::Decimal(v) => Some(Number::Decimal(*v)),
+            _ => None,
+        }
+    }
+
+    fn cmp(&self, other: &Number) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Int(l), Number::Int(r)) => Some(l.cmp(r)),
+            (Number::UInt(l), Number::UInt(r)) => Some(l.cmp(r)),
+            (Number::Float(l), Number::Float(r)) => l.partial_cmp(r),
+            (Number::Decimal(l), Number::Decimal(r)) => Some(l.cmp(r)),
+            (Number::Int(l), Number::UInt(r)) => Some(cmp_i128_u128(*l, *r)),
+            (Number::Float(l), Number::Int(r)) => cmp_f64_i128(*l, *r),
+            (Number::Float(l), Number::UInt(r)) => cmp_f64_u128(*l, *r),
+            (Number::Decimal(l), Number::Int(r)) => match Decimal::from_i128(*r) {
+                Some(r) => Some(l.cmp(&r)),
+                None => Some(if *r > 0 {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }),
+            },
+            (Number::Decimal(l), Number::UInt(r)) => match Decimal::from_u128(*r) {
+                Some(r) => Some(l.cmp(&r)),
+                None => Some(Ordering::Less),
+            },
+            (Number::Decimal(l), Number::Float(r)) => cmp_decimal_f64(*l, *r),
+            // the remaining combinations are the mirrored ones from above
+            (Number::UInt(_), _) | (Number::Int(_), _) | (Number::Float(_), _) => {
+                other.cmp(self).map(Ordering::reverse)
+            }
+        }
+    }
+}
+
+fn cmp_i128_u128(l: i128, r: u128) -> Ordering {
+    if l < 0 {
+        Ordering::Less
+    } else {
+        (l as u128).cmp(&r)
+    }
+}
+
+// compares the integral part first and the fractional part only if the integral parts are equal.
+fn cmp_f64_i128(l: f64, r: i128) -> Option<Ordering> {
+    if l.is_nan() {
+        return None;
+    }
+    if l >= TWO_POW_127 {
+        return Some(Ordering::Greater);
+    }
+    if l < -TWO_POW_127 {
+        return Some(Ordering::Less);
+    }
+    match (l.trunc() as i128).cmp(&r) {
+        Ordering::Equal => l.fract().partial_cmp(&0.0),
+        ord => Some(ord),
+    }
+}
+
+fn cmp_f64_u128(l: f64, r: u128) -> Option<Ordering> {
+    if l.is_nan() {
+        return None;
+    }
+    if l < 0.0 {
+        return Some(Ordering::Less);
+    }
+    if l >= TWO_POW_128 {
+        return Some(Ordering::Greater);
+    }
+    match (l.trunc() as u128).cmp(&r) {
+        Ordering::Equal => l.fract().partial_cmp(&0.0),
+        ord => Some(ord),
+    }
+}
+
+// NOTE: The float is converted like `try_convert_to_decimal` does it, i.e. 0.1f64 equals Decimal 0.1,
+//       even though 0.1 isn't exactly representable as a float.
+fn cmp_decimal_f64(l: Decimal, r: f64) -> Option<Ordering> {
+    if r.is_nan() {
+        return None;
+    }
+    match Decimal::from_f64(r) {
+        Some(r) => Some(l.cmp(&r)),
+        // out of the range of Decimal (this includes infinity)
+        None => Some(if r > 0.0 {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }),
+    }
+}
+
+// Naive dates (and date times) are treated as UTC, when compared to a DateTime with offset.
+// A NaiveDate is treated as midnight, when compared to a date time.
+fn as_utc_instant(v: &Value) -> Option<NaiveDateTime> {
+    match v {
+        Value::NaiveDate(v) => v.and_hms_opt(0, 0, 0),
+        Value::NaiveDateTime(v) => Some(*v),
+        Value::DateTime(v) => Some(v.naive_utc()),
+        _ => None,
+    }
+}
+
+impl Value {
+    /// Compares two values by their "meaning" instead of their variant, i.e.:
+    /// - all numeric variants (ints, floats and Decimal) are compared by their mathematical value,
+    ///   i.e. `Int8(1)`, `UInt128(1)`, `Float64(1.0)` and `Decimal(1)` are all equal
+    /// - all date variants are compared by the instant in time they represent (see `as_utc_instant`)
+    /// - strings, chars, bools, bytes, times (of day), durations and uuids are only comparable with the same variant
+    ///
+    /// Returns `None` if the values are not comparable, which is the case for:
+    /// - different, non-numeric variants, e.g. `Bool(true)` and `Int8(1)`
+    /// - NaN, which is not comparable to anything (not even to itself), just like the primitive
+    /// - `Value::None` and typed nulls, which are only comparable to (and equal to) each other, regardless of the type
+    pub fn numeric_cmp(&self, other: &Value) -> Option<Ordering> {
+        if let (Some(l), Some(r)) = (Number::from_value(self), Number::from_value(other)) {
+            return l.cmp(&r);
+        }
+        if let (Some(l), Some(r)) = (as_utc_instant(self), as_utc_instant(other)) {
+            return Some(l.cmp(&r));
+        }
+        match (self, other) {
+            (Value::None | Value::Null(_), Value::None | Value::Null(_)) => Some(Ordering::Equal),
+            (Value::Char(l), Value::Char(r)) => Some(l.cmp(r)),
+            (Value::String(l), Value::String(r)) => Some(l.cmp(r)),
+            (Value::Bool(l), Value::Bool(r)) => Some(l.cmp(r)),
+            (Value::Bytes(l), Value::Bytes(r)) => Some(l.cmp(r)),
+            #[cfg(feature = "uuid")]
+            (Value::Uuid(l), Value::Uuid(r)) => Some(l.cmp(r)),
+            (Value::NaiveTime(l), Value::NaiveTime(r)) => Some(l.cmp(r)),
+            (Value::Duration(l), Value::Duration(r)) => Some(l.cmp(r)),
+            // lexicographically, the first non-equal pair of elements decides
+            (Value::List(l), Value::List(r)) => {
+                for (l, r) in l.iter().zip(r) {
+                    match l.numeric_cmp(r)? {
+                        Ordering::Equal => continue,
+                        ord => return Some(ord),
+                    }
+                }
+                Some(l.len().cmp(&r.len()))
+            }
+            // like lists of (key, value) pairs, ordered by key
+            (Value::Map(l), Value::Map(r)) => {
+                for ((lk, lv), (rk, rv)) in l.iter().zip(r) {
+                    let ord = match lk.cmp(rk) {
+                        Ordering::Equal => lv.numeric_cmp(rv)?,
+                        ord => ord,
+                    };
+                    if ord != Ordering::Equal {
+                        return Some(ord);
+                    }
+                }
+                Some(l.len().cmp(&r.len()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Equality in the sense of `numeric_cmp`, i.e. `Int8(1)` is loosely equal to `Float64(1.0)`.
+    pub fn loose_eq(&self, other: &Value) -> bool {
+        self.numeric_cmp(other) == Some(Ordering::Equal)
+    }
+
+    /// A total order over all values, i.e. it's the same as the derived `PartialOrd`, except for floats,
+    /// which are ordered by the IEEE 754 total order (`f64::total_cmp`). This means:
+    /// - different variants are ordered by their position in the enum, so `Value::None` comes first
+    /// - -NaN < -inf < ... < -0.0 < +0.0 < ... < +inf < +NaN
+    /// - lists are ordered lexicographically, using this order for their elements
+    /// - maps are ordered like lists of (key, value) pairs, sorted by key
+    pub fn total_cmp(&self, other: &Value) -> Ordering {
+        match (self, other) {
+            (Value::Float32(l), Value::Float32(r)) => l.total_cmp(r),
+            (Value::Float64(l), Value::Float64(r)) => l.total_cmp(r),
+            (Value::List(l), Value::List(r)) => l
+                .iter()
+                .zip(r)
+                .map(|(l, r)| l.total_cmp(r))
+                .find(|ord| *ord != Ordering::Equal)
+                .unwrap_or_else(|| l.len().cmp(&r.len())),
+            (Value::Map(l), Value::Map(r)) => l
+                .iter()
+                .zip(r)
+                .map(|((lk, lv), (rk, rv))| lk.cmp(rk).then_with(|| lv.total_cmp(rv)))
+                .find(|ord| *ord != Ordering::Equal)
+                .unwrap_or_else(|| l.len().cmp(&r.len())),
+            // All other variants are totally ordered already. For different variants, the derived
+            // PartialOrd orders by the position of the variant, so this can't fail either.
+            _ => self
+                .partial_cmp(other)
+                .expect("only floats can be unordered"),
+        }
+    }
+}
+
+// Must be consistent with `Value::total_cmp`, i.e. floats are hashed by their bits, as the total order
+// considers two floats equal only if their bits are equal.
+fn hash_value<H: Hasher>(v: &Value, state: &mut H) {
+    std::mem::discriminant(v).hash(state);
+    match v {
+        Value::None => {}
+        Value::Null(v) => v.hash(state),
+        Value::Char(v) => v.hash(state),
+        Value::String(v) => v.hash(state),
+        Value::Int8(v) => v.hash(state),
+        Value::Int16(v) => v.hash(state),
+        Value::Int32(v) => v.hash(state),
+        Value::Int64(v) => v.hash(state),
+        Value::Int128(v) => v.hash(state),
+        Value::UInt8(v) => v.hash(state),
+        Value::UInt16(v) => v.hash(state),
+        Value::UInt32(v) => v.hash(state),
+        Value::UInt64(v) => v.hash(state),
+        Value::UInt128(v) => v.hash(state),
+        Value::Float32(v) => v.to_bits().hash(state),
+        Value::Float64(v) => v.to_bits().hash(state),
+        Value::Bool(v) => v.hash(state),
+        Value::Decimal(v) => v.hash(state), // 1.0 and 1.00 are equal and have the same hash
+        Value::NaiveDate(v) => v.hash(state),
+        Value::NaiveDateTime(v) => v.hash(state),
+        Value::DateTime(v) => v.hash(state), // same instant, different offset: equal and same hash
+        Value::List(v) => {
+            v.len().hash(state);
+            v.iter().for_each(|e| hash_value(e, state));
+        }
+        Value::NaiveTime(v) => v.hash(state),
+        Value::Duration(v) => v.hash(state),
+        Value::Bytes(v) => v.hash(state),
+        #[cfg(feature = "uuid")]
+        Value::Uuid(v) => v.hash(state),
+        Value::Map(v) => {
+            v.len().hash(state);
+            v.iter().for_each(|(k, e)| {
+                k.hash(state);
+                hash_value(e, state);
+            });
+        }
+    }
+}
+
+/// Wrapper around a `Value` that implements `PartialEq` and `PartialOrd` via `Value::loose_eq`
+/// and `Value::numeric_cmp`, instead of comparing the variants first, like `Value` itself does.
+#[derive(Debug, Clone)]
+pub struct LooseValue(pub Value);
+
+impl PartialEq for LooseValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.loose_eq(&other.0)
+    }
+}
+
+impl PartialOrd for LooseValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.numeric_cmp(&other.0)
+    }
+}
+
+impl From<Value> for LooseValue {
+    fn from(v: Value) -> Self {
+        LooseValue(v)
+    }
+}
+
+/// Wrapper around a `Value` that implements `Eq`, `Ord` and `Hash` (via `Value::total_cmp`),
+/// so it can be used as key in a `HashMap` or `BTreeMap`. Unlike `LooseValue`, different variants
+/// are never equal, i.e. `Int8(1)` and `Int16(1)` are two different keys.
+#[derive(Debug, Clone)]
+pub struct OrdValue(pub Value);
+
+impl PartialEq for OrdValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrdValue {}
+
+impl PartialOrd for OrdValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrdValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for OrdValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_value(&self.0, state)
+    }
+}
+
+impl From<Value> for OrdValue {
+    fn from(v: Value) -> Self {
+        OrdValue(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, FixedOffset, NaiveDate, NaiveTime};
+
+    use super::*;
+    use crate::value_type::ValueType;
+
+    mod numeric_cmp {
+        use super::*;
+
+        #[test]
+        pub fn ints() {
+            assert_eq!(
+                Some(Ordering::Equal),
+                Value::Int8(1).numeric_cmp(&Value::Int64(1))
+            );
+            assert_eq!(
+                Some(Ordering::Less),
+                Value::Int8(-1).numeric_cmp(&Value::UInt8(0))
+            );
+            assert_eq!(
+                Some(Ordering::Greater),
+                Value::UInt128(u128::MAX).numeric_cmp(&Value::Int128(i128::MAX))
+            );
+            assert_eq!(
+                Some(Ordering::Less),
+                Value::Int128(i128::MIN).numeric_cmp(&Value::UInt8(0))
+            );
+        }
+
+        #[test]
+        pub fn ints_and_floats() {
+            assert_eq!(
+                Some(Ordering::Equal),
+                Value::Int32(1).numeric_cmp(&Value::Float64(1.0))
+            );
+            assert_eq!(
+                Some(Ordering::Equal),
+                Value::Float32(-0.0).numeric_cmp(&Value::UInt8(0))
+            );
+            assert_eq!(
+                Some(Ordering::Less),
+                Value::Int32(1).numeric_cmp(&Value::Float32(1.5))
+            );
+            assert_eq!(
+                Some(Ordering::Greater),
+                Value::Int32(-1).numeric_cmp(&Value::Float32(-1.5))
+            );
+            assert_eq!(
+                Some(Ordering::Greater),
+                Value::Float64(-0.5).numeric_cmp(&Value::Int8(-1))
+            );
+            assert_eq!(
+                Some(Ordering::Less),
+                Value::Float64(-0.5).numeric_cmp(&Value::UInt8(0))
+            );
+            assert_eq!(
+                Some(Ordering::Greater),
+                Value::Float64(f64::MAX).numeric_cmp(&Value::UInt128(u128::MAX))
+            );
+            assert_eq!(
+                Some(Ordering::Less),
+                Value::Float64(f64::NEG_INFINITY).numeric_cmp(&Value::Int128(i128::MIN))
+            );
+            // 2^53 + 1 is not representable as f64, but we still get the correct answer
+            assert_eq!(
+                Some(Ordering::Greater),
+                Value::Int64(9_007_199_254_740_993)
+                    .numeric_cmp(&Value::Float64(9_007_199_254_740_992.0))
+            );
+        }
+
+        #[test]
+        pub fn decimals() {
+            assert_eq!(
+                Some(Ordering::Equal),
+                Value::Float64(1.0).numeric_cmp(&Value::Decimal(Decimal::ONE))
+            );
+            assert_eq!(
+                Some(Ordering::Equal),
+                Value::Decimal(Decimal::new(1, 1)).numeric_cmp(&Value::Float64(0.1))
+            );
+            assert_eq!(
+                Some(Ordering::Equal),
+                Value::Decimal(Decimal::new(100, 2)).numeric_cmp(&Value::UInt16(1))
+            );
+            assert_eq!(
+                Some(Ordering::Less),
+                Value::Decimal(Decimal::MAX).numeric_cmp(&Value::UInt128(u128::MAX))
+            );
+            assert_eq!(
+                Some(Ordering::Greater),
+                Value::Decimal(Decimal::MIN).numeric_cmp(&Value::Int128(i128::MIN))
+            );
+            assert_eq!(
+                Some(Ordering::Less),
+                Value::Decimal(Decimal::MAX).numeric_cmp(&Value::Float64(f64::INFINITY))
+            );
+        }
+
+        #[test]
+        pub fn nan() {
+            assert_eq!(
+                None,
+                Value::Float64(f64::NAN).numeric_cmp(&Value::Float64(f64::NAN))
+            );
+            assert_eq!(None, Value::Float32(f32::NAN).numeric_cmp(&Value::Int8(1)));
+            assert_eq!(
+                None,
+                Value::Decimal(Decimal::ONE).numeric_cmp(&Value::Float32(f32::NAN))
+            );
+            assert!(!Value::Float64(f64::NAN).loose_eq(&Value::Float64(f64::NAN)));
+        }
+
+        #[test]
+        pub fn none() {
+            assert_eq!(Some(Ordering::Equal), Value::None.numeric_cmp(&Value::None));
+            assert_eq!(None, Value::None.numeric_cmp(&Value::Int8(0)));
+            assert_eq!(None, Value::String(String::new()).numeric_cmp(&Value::None));
+            assert_eq!(
+                Some(Ordering::Equal),
+                Value::Null(ValueType::Int8).numeric_cmp(&Value::None)
+            );
+            assert_eq!(
+                Some(Ordering::Equal),
+                Value::Null(ValueType::Int8).numeric_cmp(&Value::Null(ValueType::Bool))
+            );
+            assert_eq!(
+                None,
+                Value::Null(ValueType::Int8).numeric_cmp(&Value::Int8(0))
+            );
+        }
+
+        #[test]
+        pub fn dates() {
+            let nd = Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap());
+            let ndt = Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2022, 12, 31)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap(),
+            );
+            let dt_utc_plus_one = Value::DateTime(
+                NaiveDate::from_ymd_opt(2022, 12, 31)
+                    .unwrap()
+                    .and_hms_opt(1, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(3600).unwrap())
+                    .unwrap(),
+            );
+            let dt_utc = Value::DateTime(
+                NaiveDate::from_ymd_opt(2022, 12, 31)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 1)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap(),
+            );
+
+            assert_eq!(Some(Ordering::Equal), nd.numeric_cmp(&ndt));
+            assert_eq!(Some(Ordering::Equal), ndt.numeric_cmp(&dt_utc_plus_one));
+            assert_eq!(Some(Ordering::Less), dt_utc_plus_one.numeric_cmp(&dt_utc));
+            assert_eq!(Some(Ordering::Greater), dt_utc.numeric_cmp(&nd));
+        }
+
+        #[test]
+        pub fn non_numeric() {
+            assert_eq!(
+                Some(Ordering::Less),
+                Value::String(String::from("a")).numeric_cmp(&Value::String(String::from("b")))
+            );
+            assert_eq!(
+                Some(Ordering::Greater),
+                Value::Bool(true).numeric_cmp(&Value::Bool(false))
+            );
+            assert_eq!(
+                Some(Ordering::Equal),
+                Value::Char('a').numeric_cmp(&Value::Char('a'))
+            );
+            assert_eq!(None, Value::Bool(true).numeric_cmp(&Value::Int8(1)));
+            assert_eq!(None, Value::Char('1').numeric_cmp(&Value::Int8(1)));
+            assert_eq!(
+                None,
+                Value::String(String::from("a")).numeric_cmp(&Value::Char('a'))
+            );
+        }
+
+        #[test]
+        pub fn lists() {
+            let ints = Value::List(vec![Value::Int8(1), Value::UInt64(2)]);
+            assert_eq!(
+                Some(Ordering::Equal),
+                ints.numeric_cmp(&Value::List(vec![Value::Float32(1.0), Value::Int16(2)]))
+            );
+            assert_eq!(
+                Some(Ordering::Less),
+                ints.numeric_cmp(&Value::List(vec![
+                    Value::Int8(1),
+                    Value::Int8(2),
+                    Value::Int8(0)
+                ]))
+            );
+            assert_eq!(
+                Some(Ordering::Greater),
+                ints.numeric_cmp(&Value::List(vec![Value::Decimal(Decimal::new(5, 1))]))
+            );
+            assert_eq!(
+                None,
+                ints.numeric_cmp(&Value::List(vec![Value::Int8(1), Value::Bool(true)]))
+            );
+        }
+    }
+
+    mod numeric_cmp_maps {
+        use std::collections::BTreeMap;
+
+        use super::*;
+
+        fn map(entries: Vec<(&str, Value)>) -> Value {
+            Value::Map(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (String::from(k), v))
+                    .collect(),
+            )
+        }
+
+        #[test]
+        pub fn maps() {
+            let m = map(vec![("a", Value::Int8(1)), ("b", Value::Int8(2))]);
+            assert_eq!(
+                Some(Ordering::Equal),
+                m.numeric_cmp(&map(vec![
+                    ("a", Value::Float64(1.0)),
+                    ("b", Value::UInt8(2))
+                ]))
+            );
+            assert_eq!(
+                Some(Ordering::Less),
+                m.numeric_cmp(&map(vec![("a", Value::Int8(1)), ("b", Value::Int8(3))]))
+            );
+            // the keys decide first
+            assert_eq!(
+                Some(Ordering::Less),
+                m.numeric_cmp(&map(vec![("b", Value::Bool(true))]))
+            );
+            assert_eq!(None, m.numeric_cmp(&map(vec![("a", Value::Bool(true))])));
+            assert_eq!(
+                Some(Ordering::Equal),
+                Value::Map(BTreeMap::new()).numeric_cmp(&Value::Map(BTreeMap::new()))
+            );
+        }
+    }
+
+    mod loose_value {
+        use super::*;
+
+        #[test]
+        pub fn eq() {
+            assert_eq!(LooseValue(Value::Int8(1)), LooseValue(Value::Int64(1)));
+            assert_ne!(LooseValue(Value::Int8(1)), LooseValue(Value::Bool(true)));
+        }
+
+        #[test]
+        pub fn ord() {
+            assert!(LooseValue(Value::Int8(1)) < LooseValue(Value::Float64(1.5)));
+            assert!(LooseValue(Value::Decimal(Decimal::TWO)) > LooseValue(Value::Float64(1.5)));
+            // derived PartialOrd of Value orders by variant first
+            assert!(Value::Int8(2) < Value::Int64(1));
+            assert!(LooseValue(Value::Int8(2)) > LooseValue(Value::Int64(1)));
+        }
+
+        #[test]
+        pub fn sort() {
+            let mut values: Vec<LooseValue> = vec![
+                Value::Float64(2.5).into(),
+                Value::UInt8(3).into(),
+                Value::Int64(-1).into(),
+                Value::Decimal(Decimal::ONE).into(),
+            ];
+            values.sort_by(|l, r| l.partial_cmp(r).unwrap());
+            assert_eq!(
+                vec![
+                    Value::Int64(-1),
+                    Value::Decimal(Decimal::ONE),
+                    Value::Float64(2.5),
+                    Value::UInt8(3)
+                ],
+                values.into_iter().map(|v| v.0).collect::<Vec<Value>>()
+            );
+        }
+    }
+
+    mod ord_value {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+        use super::*;
+
+        fn hash_of(v: &OrdValue) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        fn date_time(offset_hours: i32) -> Value {
+            Value::DateTime(
+                NaiveDate::from_ymd_opt(2022, 12, 31)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(offset_hours * 3600).unwrap())
+                    .unwrap(),
+            )
+        }
+
+        // one value of every variant, built twice, so we get equal but not identical values
+        #[cfg(not(feature = "uuid"))]
+        const NUM_VARIANTS: usize = 26;
+        #[cfg(feature = "uuid")]
+        const NUM_VARIANTS: usize = 27;
+
+        fn one_of_each_variant() -> Vec<Value> {
+            #[allow(unused_mut)]
+            let mut values = vec![
+                Value::None,
+                Value::Char('a'),
+                Value::String(String::from("a")),
+                Value::Int8(1),
+                Value::Int16(1),
+                Value::Int32(1),
+                Value::Int64(1),
+                Value::Int128(1),
+                Value::UInt8(1),
+                Value::UInt16(1),
+                Value::UInt32(1),
+                Value::UInt64(1),
+                Value::UInt128(1),
+                Value::Float32(1.0),
+                Value::Float64(1.0),
+                Value::Bool(true),
+                Value::Decimal(Decimal::ONE),
+                Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap()),
+                Value::NaiveDateTime(
+                    NaiveDate::from_ymd_opt(2022, 12, 31)
+                        .unwrap()
+                        .and_hms_opt(12, 0, 0)
+                        .unwrap(),
+                ),
+                date_time(0),
+                Value::Null(ValueType::Int8),
+                Value::NaiveTime(NaiveTime::from_hms_opt(8, 30, 0).unwrap()),
+                Value::Duration(Duration::try_minutes(90).unwrap()),
+                Value::List(vec![Value::Int8(1), Value::None]),
+                Value::Map(BTreeMap::from([(String::from("a"), Value::Int8(1))])),
+                Value::Bytes(vec![0xca, 0xfe]),
+            ];
+            #[cfg(feature = "uuid")]
+            values.push(Value::Uuid(uuid::Uuid::from_u128(1)));
+            values
+        }
+
+        #[test]
+        pub fn hash_eq_consistency_all_variants() {
+            let values = one_of_each_variant();
+            let same_values = one_of_each_variant();
+            assert_eq!(NUM_VARIANTS, values.len());
+
+            for (l, r) in values.into_iter().zip(same_values) {
+                let (l, r) = (OrdValue(l), OrdValue(r));
+                assert_eq!(l, r);
+                assert_eq!(Ordering::Equal, l.cmp(&r));
+                assert_eq!(hash_of(&l), hash_of(&r));
+            }
+        }
+
+        #[test]
+        pub fn all_variants_are_distinct_keys() {
+            let hash_set: HashSet<OrdValue> =
+                one_of_each_variant().into_iter().map(OrdValue).collect();
+            let btree_set: BTreeSet<OrdValue> =
+                one_of_each_variant().into_iter().map(OrdValue).collect();
+            assert_eq!(NUM_VARIANTS, hash_set.len());
+            assert_eq!(NUM_VARIANTS, btree_set.len());
+
+            // ordered by the position of the variant, i.e. the order of definition
+            assert_eq!(
+                one_of_each_variant(),
+                btree_set.into_iter().map(|v| v.0).collect::<Vec<Value>>()
+            );
+        }
+
+        #[test]
+        pub fn floats() {
+            let nan = OrdValue(Value::Float64(f64::NAN));
+            assert_eq!(nan, nan.clone());
+            assert_eq!(hash_of(&nan), hash_of(&nan.clone()));
+
+            let pos_zero = OrdValue(Value::Float32(0.0));
+            let neg_zero = OrdValue(Value::Float32(-0.0));
+            assert_ne!(pos_zero, neg_zero);
+            assert!(neg_zero < pos_zero);
+
+            let sorted: Vec<Value> = vec![
+                Value::Float64(f64::NAN),
+                Value::Float64(f64::INFINITY),
+                Value::Float64(1.0),
+                Value::Float64(-f64::NAN),
+                Value::Float64(f64::NEG_INFINITY),
+            ]
+            .into_iter()
+            .map(OrdValue)
+            .collect::<BTreeSet<OrdValue>>()
+            .into_iter()
+            .map(|v| v.0)
+            .collect();
+            assert_eq!(Value::Float64(f64::NEG_INFINITY), sorted[1]);
+            assert_eq!(Value::Float64(1.0), sorted[2]);
+            assert_eq!(Value::Float64(f64::INFINITY), sorted[3]);
+            assert!(matches!(sorted[0], Value::Float64(v) if v.is_nan() && v.is_sign_negative()));
+            assert!(matches!(sorted[4], Value::Float64(v) if v.is_nan() && v.is_sign_positive()));
+        }
+
+        #[test]
+        pub fn decimal_scale_is_ignored() {
+            let l = OrdValue(Value::Decimal(Decimal::new(10, 1)));
+            let r = OrdValue(Value::Decimal(Decimal::new(100, 2)));
+            assert_eq!(l, r);
+            assert_eq!(hash_of(&l), hash_of(&r));
+        }
+
+        #[test]
+        pub fn date_time_same_instant_different_offset() {
+            let l = OrdValue(date_time(0));
+            let r = OrdValue(Value::DateTime(
+                NaiveDate::from_ymd_opt(2022, 12, 31)
+                    .unwrap()
+                    .and_hms_opt(14, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(2 * 3600).unwrap())
+                    .unwrap(),
+            ));
+            assert_eq!(l, r);
+            assert_eq!(hash_of(&l), hash_of(&r));
+        }
+
+        #[test]
+        pub fn lists() {
+            let l = OrdValue(Value::List(vec![Value::Float64(f64::NAN)]));
+            assert_eq!(l, l.clone());
+            assert_eq!(hash_of(&l), hash_of(&l.clone()));
+
+            let short = OrdValue(Value::List(vec![Value::Int8(1)]));
+            let long = OrdValue(Value::List(vec![Value::Int8(1), Value::Int8(0)]));
+            let bigger = OrdValue(Value::List(vec![Value::Int8(2)]));
+            assert!(short < long);
+            assert!(long < bigger);
+            assert_ne!(hash_of(&short), hash_of(&long));
+        }
+
+        #[test]
+        pub fn different_variants_are_different_keys() {
+            assert_ne!(OrdValue(Value::Int8(1)), OrdValue(Value::Int16(1)));
+            assert!(OrdValue(Value::None) < OrdValue(Value::Char('a')));
+            assert!(OrdValue(Value::Int8(100)) < OrdValue(Value::Int16(1)));
+        }
+    }
+}
@@ -0,0 +1,522 @@
+use std::str::FromStr;
+
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::errors_result::Result;
+use crate::value::Value;
+use crate::value_type::ValueType;
+
+/// What to do when a number doesn't fit into the numeric target type of a conversion, see: `Value::try_convert_to_with`.
+/// Values that fit are converted the same way with every policy, i.e. like `Value::try_convert_to` does it.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionPolicy {
+    /// Fail, i.e. `Value::try_convert_to`
+    #[default]
+    Strict,
+    /// Clamp to the min / max of the target type, e.g. `Int32(300)` becomes `UInt8(255)` and `Float64(-3.7)` becomes
+    /// `UInt8(0)`. Fractions are truncated (towards zero) first. NaN still fails.
+    Saturate,
+    /// Round floats and decimals to ints, e.g. `Float64(3.7)` becomes `Int8(4)` (with `MidpointAwayFromZero`).
+    /// Values that are out of range after rounding still fail.
+    Round(RoundingStrategy),
+    /// Drop the fraction of floats and decimals for ints, e.g. `Float64(3.7)` becomes `Int8(3)`.
+    /// Values that are out of range after truncating still fail.
+    Truncate,
+}
+
+macro_rules! int_bound {
+    ($target_type:expr, $negative:expr, $($variant:ident => $primitive:ty),+) => {
+        match $target_type {
+            $(ValueType::$variant => Some(Value::$variant(if $negative {
+                <$primitive>::MIN
+            } else {
+                <$primitive>::MAX
+            })),)+
+            _ => None,
+        }
+    };
+}
+
+fn int_bound(target_type: &ValueType, negative: bool) -> Option<Value> {
+    int_bound!(target_type, negative,
+        Int8 => i8, Int16 => i16, Int32 => i32, Int64 => i64, Int128 => i128,
+        UInt8 => u8, UInt16 => u16, UInt32 => u32, UInt64 => u64, UInt128 => u128)
+}
+
+fn is_negative(v: &Value) -> bool {
+    match v {
+        Value::Int8(x) => *x < 0,
+        Value::Int16(x) => *x < 0,
+        Value::Int32(x) => *x < 0,
+        Value::Int64(x) => *x < 0,
+        Value::Int128(x) => *x < 0,
+        Value::Float32(x) => *x < 0.0,
+        Value::Float64(x) => *x < 0.0,
+        Value::Decimal(x) => x.is_sign_negative(),
+        _ => false,
+    }
+}
+
+fn as_f64(v: &Value) -> Option<f64> {
+    match v {
+        Value::Int8(x) => Some(f64::from(*x)),
+        Value::Int16(x) => Some(f64::from(*x)),
+        Value::Int32(x) => Some(f64::from(*x)),
+        Value::Int64(x) => Some(*x as f64),
+        Value::Int128(x) => Some(*x as f64),
+        Value::UInt8(x) => Some(f64::from(*x)),
+        Value::UInt16(x) => Some(f64::from(*x)),
+        Value::UInt32(x) => Some(f64::from(*x)),
+        Value::UInt64(x) => Some(*x as f64),
+        Value::UInt128(x) => Some(*x as f64),
+        Value::Float32(x) => Some(f64::from(*x)),
+        Value::Float64(x) => Some(*x),
+        Value::Decimal(x) => x.to_f64(),
+        _ => None,
+    }
+}
+
+// Numbers in strings, as decimal if possible, as float otherwise (e.g. "1e300" or "inf")
+fn parse_number(s: &str) -> Option<Value> {
+    Decimal::from_str_exact(s)
+        .or_else(|_| Decimal::from_scientific(s))
+        .map(Value::Decimal)
+        .ok()
+        .or_else(|| f64::from_str(s).ok().map(Value::Float64))
+}
+
+// Floats and decimals without fraction. Floats too big for a decimal don't have a fraction anyway.
+fn to_integral(v: &Value, policy: &ConversionPolicy) -> Option<Value> {
+    let round = |d: Decimal| {
+        let rounded = match policy {
+            ConversionPolicy::Round(strategy) => d.round_dp_with_strategy(0, *strategy),
+            _ => d.trunc(),
+        };
+        // no "-0", e.g. -0.5 truncated, which isn't an unsigned int
+        if rounded.is_zero() {
+            Decimal::ZERO
+        } else {
+            rounded
+        }
+    };
+    match v {
+        Value::Decimal(d) => Some(Value::Decimal(round(*d))),
+        Value::Float32(_) | Value::Float64(_) => {
+            let f = as_f64(v)?;
+            if f.is_nan() {
+                None
+            } else if let Some(d) = Decimal::from_f64(f) {
+                Some(Value::Decimal(round(d)))
+            } else {
+                Some(Value::Float64(f))
+            }
+        }
+        _ => Some(v.clone()),
+    }
+}
+
+fn to_int(v: &Value, target_type: &ValueType, policy: &ConversionPolicy) -> Option<Value> {
+    let integral = to_integral(v, policy)?;
+    match integral.try_convert_to(target_type) {
+        Ok(converted) => Some(converted),
+        Err(_) if *policy == ConversionPolicy::Saturate => {
+            int_bound(target_type, is_negative(&integral))
+        }
+        Err(_) => None,
+    }
+}
+
+fn to_float(v: &Value, target_type: &ValueType, policy: &ConversionPolicy) -> Option<Value> {
+    let f = as_f64(v)?;
+    match target_type {
+        ValueType::Float64 => Some(Value::Float64(f)),
+        _ => {
+            let narrowed = f as f32;
+            if narrowed.is_infinite() && f.is_finite() {
+                // out of range
+                match policy {
+                    ConversionPolicy::Saturate => Some(Value::Float32(f32::MAX.copysign(narrowed))),
+                    _ => None,
+                }
+            } else {
+                Some(Value::Float32(narrowed))
+            }
+        }
+    }
+}
+
+fn to_decimal(v: &Value, policy: &ConversionPolicy) -> Option<Value> {
+    match policy {
+        ConversionPolicy::Saturate => {
+            let f = as_f64(v)?;
+            if f.is_nan() {
+                None
+            } else if f.is_sign_negative() {
+                Some(Value::Decimal(Decimal::MIN))
+            } else {
+                Some(Value::Decimal(Decimal::MAX))
+            }
+        }
+        _ => None,
+    }
+}
+
+impl Value {
+    /// Like `try_convert_to`, but numbers that don't fit into the (numeric) target type are handled as the policy says.
+    /// Numbers in strings, e.g. "3.7", are handled as well.
+    /// NOTE: All non-strict policies accept the nearest float for float targets, e.g. `Float64(0.1)` to `Float32`.
+    ///       `Saturate` also clamps to `f32::MIN` / `f32::MAX` and `Decimal::MIN` / `Decimal::MAX`.
+    /// On failure, the error is the one of `try_convert_to`.
+    pub fn try_convert_to_with(
+        &self,
+        target_type: &ValueType,
+        policy: &ConversionPolicy,
+    ) -> Result<Value> {
+        let strict = self.try_convert_to(target_type);
+        if strict.is_ok()
+            || *policy == ConversionPolicy::Strict
+            || !target_type.is_some_numeric_type()
+        {
+            return strict;
+        }
+        let source = match self {
+            Value::String(s) => parse_number(s),
+            _ if ValueType::try_from(self).map_or(false, |t| t.is_some_numeric_type()) => {
+                Some(self.clone())
+            }
+            _ => None,
+        };
+        let converted = source.and_then(|source| match target_type {
+            ValueType::Float32 | ValueType::Float64 => to_float(&source, target_type, policy),
+            ValueType::Decimal => to_decimal(&source, policy),
+            _ => to_int(&source, target_type, policy),
+        });
+        match converted {
+            Some(converted) => Ok(converted),
+            None => strict,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(v: Value, target_type: ValueType, policy: ConversionPolicy) -> Result<Value> {
+        v.try_convert_to_with(&target_type, &policy)
+    }
+
+    mod strict {
+        use super::*;
+
+        #[test]
+        pub fn same_as_try_convert_to() {
+            for (v, t) in [
+                (Value::Float64(3.7), ValueType::Int8),
+                (Value::Int32(300), ValueType::UInt8),
+                (Value::Int32(3), ValueType::UInt8),
+                (Value::String(String::from("abc")), ValueType::Int8),
+            ] {
+                assert_eq!(
+                    v.try_convert_to(&t),
+                    convert(v.clone(), t, ConversionPolicy::Strict)
+                );
+            }
+        }
+    }
+
+    mod saturate {
+        use super::*;
+
+        #[test]
+        pub fn ints() {
+            assert_eq!(
+                Ok(Value::UInt8(255)),
+                convert(
+                    Value::Int32(300),
+                    ValueType::UInt8,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::UInt8(0)),
+                convert(
+                    Value::Int32(-300),
+                    ValueType::UInt8,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::Int8(-128)),
+                convert(
+                    Value::Int64(-300),
+                    ValueType::Int8,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::Int128(i128::MAX)),
+                convert(
+                    Value::UInt128(u128::MAX),
+                    ValueType::Int128,
+                    ConversionPolicy::Saturate
+                )
+            );
+            // fits, so nothing to saturate
+            assert_eq!(
+                Ok(Value::Int8(3)),
+                convert(
+                    Value::UInt64(3),
+                    ValueType::Int8,
+                    ConversionPolicy::Saturate
+                )
+            );
+        }
+
+        #[test]
+        pub fn floats_and_decimals_to_ints() {
+            assert_eq!(
+                Ok(Value::Int8(3)),
+                convert(
+                    Value::Float64(3.7),
+                    ValueType::Int8,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::UInt8(0)),
+                convert(
+                    Value::Float64(-3.7),
+                    ValueType::UInt8,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::Int16(i16::MAX)),
+                convert(
+                    Value::Float32(1e10),
+                    ValueType::Int16,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::Int64(i64::MIN)),
+                convert(
+                    Value::Float64(f64::NEG_INFINITY),
+                    ValueType::Int64,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::UInt128(u128::MAX)),
+                convert(
+                    Value::Float64(1e300),
+                    ValueType::UInt128,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::UInt8(255)),
+                convert(
+                    Value::Decimal(Decimal::new(2999, 1)),
+                    ValueType::UInt8,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert!(convert(
+                Value::Float64(f64::NAN),
+                ValueType::Int8,
+                ConversionPolicy::Saturate
+            )
+            .is_err());
+        }
+
+        #[test]
+        pub fn floats_and_decimals() {
+            assert_eq!(
+                Ok(Value::Float32(f32::MAX)),
+                convert(
+                    Value::Float64(1e300),
+                    ValueType::Float32,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::Float32(f32::MIN)),
+                convert(
+                    Value::Float64(-1e300),
+                    ValueType::Float32,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::MAX)),
+                convert(
+                    Value::Float64(1e30),
+                    ValueType::Decimal,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::MIN)),
+                convert(
+                    Value::Float64(-1e30),
+                    ValueType::Decimal,
+                    ConversionPolicy::Saturate
+                )
+            );
+        }
+
+        #[test]
+        pub fn strings() {
+            assert_eq!(
+                Ok(Value::UInt8(255)),
+                convert(
+                    Value::String(String::from("300")),
+                    ValueType::UInt8,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert_eq!(
+                Ok(Value::Int8(3)),
+                convert(
+                    Value::String(String::from("3.7")),
+                    ValueType::Int8,
+                    ConversionPolicy::Saturate
+                )
+            );
+            assert!(convert(
+                Value::String(String::from("abc")),
+                ValueType::Int8,
+                ConversionPolicy::Saturate
+            )
+            .is_err());
+        }
+    }
+
+    mod round {
+        use super::*;
+
+        #[test]
+        pub fn floats_and_decimals_to_ints() {
+            let away = ConversionPolicy::Round(RoundingStrategy::MidpointAwayFromZero);
+            let even = ConversionPolicy::Round(RoundingStrategy::MidpointNearestEven);
+            let down = ConversionPolicy::Round(RoundingStrategy::ToNegativeInfinity);
+            assert_eq!(
+                Ok(Value::Int8(4)),
+                convert(Value::Float64(3.7), ValueType::Int8, away)
+            );
+            assert_eq!(
+                Ok(Value::Int8(3)),
+                convert(Value::Float64(2.5), ValueType::Int8, away)
+            );
+            assert_eq!(
+                Ok(Value::Int8(2)),
+                convert(Value::Float64(2.5), ValueType::Int8, even)
+            );
+            assert_eq!(
+                Ok(Value::Int8(-4)),
+                convert(Value::Float32(-3.2), ValueType::Int8, down)
+            );
+            assert_eq!(
+                Ok(Value::UInt16(4)),
+                convert(Value::Decimal(Decimal::new(35, 1)), ValueType::UInt16, away)
+            );
+        }
+
+        #[test]
+        pub fn out_of_range() {
+            let away = ConversionPolicy::Round(RoundingStrategy::MidpointAwayFromZero);
+            assert_eq!(
+                Value::Float64(255.5).try_convert_to(&ValueType::UInt8),
+                convert(Value::Float64(255.5), ValueType::UInt8, away)
+            );
+            assert!(convert(Value::Int32(300), ValueType::UInt8, away).is_err());
+        }
+
+        #[test]
+        pub fn lossy_floats() {
+            let away = ConversionPolicy::Round(RoundingStrategy::MidpointAwayFromZero);
+            assert_eq!(
+                Ok(Value::Float32(0.1)),
+                convert(Value::Float64(0.1), ValueType::Float32, away)
+            );
+            assert!(convert(Value::Float64(1e300), ValueType::Float32, away).is_err());
+        }
+    }
+
+    mod truncate {
+        use super::*;
+
+        #[test]
+        pub fn floats_and_decimals_to_ints() {
+            assert_eq!(
+                Ok(Value::Int8(3)),
+                convert(
+                    Value::Float64(3.7),
+                    ValueType::Int8,
+                    ConversionPolicy::Truncate
+                )
+            );
+            assert_eq!(
+                Ok(Value::Int8(-3)),
+                convert(
+                    Value::Float64(-3.7),
+                    ValueType::Int8,
+                    ConversionPolicy::Truncate
+                )
+            );
+            assert_eq!(
+                Ok(Value::Int64(12)),
+                convert(
+                    Value::Decimal(Decimal::new(12999, 3)),
+                    ValueType::Int64,
+                    ConversionPolicy::Truncate
+                )
+            );
+        }
+
+        #[test]
+        pub fn out_of_range() {
+            assert!(convert(
+                Value::Float64(-0.5),
+                ValueType::UInt8,
+                ConversionPolicy::Truncate
+            )
+            .is_ok());
+            assert!(convert(
+                Value::Float64(300.5),
+                ValueType::UInt8,
+                ConversionPolicy::Truncate
+            )
+            .is_err());
+            assert!(convert(
+                Value::Int32(300),
+                ValueType::UInt8,
+                ConversionPolicy::Truncate
+            )
+            .is_err());
+        }
+
+        #[test]
+        pub fn non_numeric_targets_unaffected() {
+            assert_eq!(
+                Value::Float64(3.7).try_convert_to(&ValueType::Bool),
+                convert(
+                    Value::Float64(3.7),
+                    ValueType::Bool,
+                    ConversionPolicy::Truncate
+                )
+            );
+            assert_eq!(
+                Ok(Value::Null(ValueType::Int8)),
+                convert(
+                    Value::Null(ValueType::Float64),
+                    ValueType::Int8,
+                    ConversionPolicy::Truncate
+                )
+            );
+        }
+    }
+}
@@ -6,17 +6,20 @@
 //     clippy::cast_sign_loss
 // )]
 
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat};
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 
 use crate::errors_result::{ConversionError, Result, VenumError};
-use crate::value::Value;
+use crate::value::{escape_list_element, BytesEncoding, Value, DEFAULT_LIST_DELIMITER};
+use crate::value_duration;
 use crate::value_type::ValueType;
 
 const DEFAULT_RADIX_10: u32 = 10;
 const DATE_FORMAT: &str = "%Y-%m-%d";
 const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3f"; // %.3f = .%f but left-aligned but fixed to a length of 3
+const TIME_FORMAT: &str = "%H:%M:%S%.3f";
 
 fn mk_not_rep_err(s: &Value, tt: ValueType) -> VenumError {
     VenumError::Conversion(ConversionError::NotRepresentableAs {
@@ -29,8 +32,11 @@ impl Value {
     // TODO: docu
 
     pub fn try_convert_to_char(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::Char;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => Ok(self.clone()),
@@ -155,13 +161,48 @@ impl Value {
             ValueType::Float64 => Err(mk_not_rep_err(self, target_type)),
             ValueType::Bool => Err(mk_not_rep_err(self, target_type)),
             ValueType::Decimal => Err(mk_not_rep_err(self, target_type)),
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
+    /// Same as `try_convert_to_string_with_bytes_encoding` with hex encoding for bytes.
     pub fn try_convert_to_string(&self) -> Result<Value> {
+        self.try_convert_to_string_with_bytes_encoding(BytesEncoding::default())
+    }
+
+    pub fn try_convert_to_string_with_bytes_encoding(
+        &self,
+        bytes_encoding: BytesEncoding,
+    ) -> Result<Value> {
+        // Lists are handled upfront, as they may contain only `Value::None`s, which don't have a type.
+        // Every element is converted on its own, escaped and then joined with the default delimiter, e.g. "a|b|c".
+        // See: `Value::parse_list_from_str`, which is the way back.
+        if let Value::List(elements) = self {
+            let strings = elements
+                .iter()
+                .map(|e| match e {
+                    Value::None | Value::Null(_) => Ok(String::new()),
+                    _ => e
+                        .try_convert_to_string_with_bytes_encoding(bytes_encoding)
+                        .and_then(String::try_from)
+                        .map(|s| escape_list_element(&s, DEFAULT_LIST_DELIMITER)),
+                })
+                .collect::<Result<Vec<String>>>()?;
+            return Ok(Value::String(strings.join(DEFAULT_LIST_DELIMITER)));
+        }
+
+        if self.is_null() {
+            return Ok(Value::Null(ValueType::String));
+        }
         let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
@@ -240,12 +281,39 @@ impl Value {
                     self_val.to_rfc3339_opts(SecondsFormat::Millis, false),
                 ))
             }
+            ValueType::NaiveTime => {
+                let self_val: NaiveTime = self.try_into()?;
+                Ok(Value::String(self_val.format(TIME_FORMAT).to_string()))
+            }
+            ValueType::Duration => {
+                let self_val: Duration = self.try_into()?;
+                Ok(Value::String(value_duration::format_iso8601(&self_val)))
+            }
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => {
+                let self_val: uuid::Uuid = self.try_into()?;
+                Ok(Value::String(self_val.hyphenated().to_string()))
+            }
+            ValueType::List(_) => unreachable!("lists are handled above"),
+            ValueType::Map => Err(mk_not_rep_err(self, ValueType::String)),
+            ValueType::Bytes => {
+                let self_val: Vec<u8> = self.try_into()?;
+                match bytes_encoding {
+                    BytesEncoding::Hex => Ok(Value::String(
+                        self_val.iter().map(|b| format!("{:02x}", b)).collect(),
+                    )),
+                    BytesEncoding::Base64 => Ok(Value::String(BASE64.encode(self_val))),
+                }
+            }
         }
     }
 
     pub fn try_convert_to_int8(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::Int8;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => {
@@ -377,15 +445,25 @@ impl Value {
                     Err(mk_not_rep_err(self, target_type))
                 }
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_int16(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::Int16;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => {
@@ -513,15 +591,25 @@ impl Value {
                     Err(mk_not_rep_err(self, target_type))
                 }
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_int32(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::Int32;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => {
@@ -645,26 +733,33 @@ impl Value {
                     Err(mk_not_rep_err(self, target_type))
                 }
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_int64(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::Int64;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => {
                 let self_val: char = self.try_into()?;
                 match self_val.to_digit(DEFAULT_RADIX_10) {
-                    Some(self_val_as_digit_u32) => match self_val_as_digit_u32.try_into() {
-                        Ok(self_val_as_target_primitive) => {
-                            Ok(Value::Int64(self_val_as_target_primitive)) // success!
-                        }
-                        Err(_) => Err(mk_not_rep_err(self, target_type)),
-                    },
+                    Some(self_val_as_digit_u32) => {
+                        Ok(Value::Int64(self_val_as_digit_u32.into())) // success!
+                    }
                     None => Err(mk_not_rep_err(self, target_type)),
                 }
             }
@@ -777,26 +872,33 @@ impl Value {
                     Err(mk_not_rep_err(self, target_type))
                 }
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_int128(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::Int128;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => {
                 let self_val: char = self.try_into()?;
                 match self_val.to_digit(DEFAULT_RADIX_10) {
-                    Some(self_val_as_digit_u32) => match self_val_as_digit_u32.try_into() {
-                        Ok(self_val_as_target_primitive) => {
-                            Ok(Value::Int128(self_val_as_target_primitive)) // success!
-                        }
-                        Err(_) => Err(mk_not_rep_err(self, target_type)),
-                    },
+                    Some(self_val_as_digit_u32) => {
+                        Ok(Value::Int128(self_val_as_digit_u32.into())) // success!
+                    }
                     None => Err(mk_not_rep_err(self, target_type)),
                 }
             }
@@ -905,15 +1007,25 @@ impl Value {
                     Err(mk_not_rep_err(self, target_type))
                 }
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_uint8(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::UInt8;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => {
@@ -1049,15 +1161,25 @@ impl Value {
                     Err(mk_not_rep_err(self, target_type))
                 }
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_uint16(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::UInt16;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => {
@@ -1191,15 +1313,25 @@ impl Value {
                     Err(mk_not_rep_err(self, target_type))
                 }
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_uint32(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::UInt32;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => {
@@ -1330,26 +1462,33 @@ impl Value {
                     Err(mk_not_rep_err(self, target_type))
                 }
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_uint64(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::UInt64;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => {
                 let self_val: char = self.try_into()?;
                 match self_val.to_digit(DEFAULT_RADIX_10) {
-                    Some(self_val_as_digit_u32) => match self_val_as_digit_u32.try_into() {
-                        Ok(self_val_as_target_primitive) => {
-                            Ok(Value::UInt64(self_val_as_target_primitive)) // success!
-                        }
-                        Err(_) => Err(mk_not_rep_err(self, target_type)),
-                    },
+                    Some(self_val_as_digit_u32) => {
+                        Ok(Value::UInt64(self_val_as_digit_u32.into())) // success!
+                    }
                     None => Err(mk_not_rep_err(self, target_type)),
                 }
             }
@@ -1472,27 +1611,33 @@ impl Value {
                     Err(mk_not_rep_err(self, target_type))
                 }
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_uint128(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::UInt128;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => {
                 let self_val: char = self.try_into()?;
                 match self_val.to_digit(DEFAULT_RADIX_10) {
-                    Some(self_val_as_digit_u32) => match self_val_as_digit_u32.try_into() {
-                        Ok(self_val_as_target_primitive) => {
-                            Ok(Value::UInt128(self_val_as_target_primitive))
-                            // success!
-                        }
-                        Err(_) => Err(mk_not_rep_err(self, target_type)),
-                    },
+                    Some(self_val_as_digit_u32) => {
+                        Ok(Value::UInt128(self_val_as_digit_u32.into())) // success!
+                    }
                     None => Err(mk_not_rep_err(self, target_type)),
                 }
             }
@@ -1608,15 +1753,25 @@ impl Value {
                     Err(mk_not_rep_err(self, target_type))
                 }
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_float32(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::Float32;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => Err(mk_not_rep_err(self, target_type)),
@@ -1734,15 +1889,25 @@ impl Value {
                     .ok_or_else(|| mk_not_rep_err(self, target_type))?;
                 Ok(Value::Float32(self_val_as_target_primitive))
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_float64(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::Float64;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::Char => Err(mk_not_rep_err(self, target_type)),
@@ -1845,15 +2010,25 @@ impl Value {
                     .ok_or_else(|| mk_not_rep_err(self, target_type))?;
                 Ok(Value::Float64(self_val_as_target_primitive))
             }
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_bool(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::Bool;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::String => {
@@ -1873,8 +2048,11 @@ impl Value {
     }
 
     pub fn try_convert_to_decimal(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::Decimal;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             // TODO: debatable if we should convert, e.g. '1' to 1.0
@@ -1963,15 +2141,25 @@ impl Value {
             }
             ValueType::Bool => Err(mk_not_rep_err(self, target_type)),
             ValueType::Decimal => Ok(self.clone()),
-            ValueType::NaiveDate | ValueType::NaiveDateTime | ValueType::DateTime => {
-                Err(mk_not_rep_err(self, target_type))
-            }
+            ValueType::NaiveDate
+            | ValueType::NaiveDateTime
+            | ValueType::DateTime
+            | ValueType::NaiveTime
+            | ValueType::Duration
+            | ValueType::List(_)
+            | ValueType::Map
+            | ValueType::Bytes => Err(mk_not_rep_err(self, target_type)),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Err(mk_not_rep_err(self, target_type)),
         }
     }
 
     pub fn try_convert_to_naive_date(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::NaiveDate;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::String => {
@@ -1989,8 +2177,11 @@ impl Value {
     }
 
     pub fn try_convert_to_naive_date_time(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::NaiveDateTime;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::String => {
@@ -2008,8 +2199,11 @@ impl Value {
     }
 
     pub fn try_convert_to_date_time(&self) -> Result<Value> {
-        let self_type = ValueType::try_from(self)?; // TODO: wrap error
         let target_type = ValueType::DateTime;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
 
         match self_type {
             ValueType::String => {
@@ -2026,6 +2220,163 @@ impl Value {
         }
     }
 
+    /// Converts every element of a list to `element_type`. `Value::None` elements stay as they are,
+    /// typed nulls take the new element type.
+    /// Strings are parsed as delimited lists, e.g. "1|2|3" (see: `Value::parse_list_from_str`).
+    pub fn try_convert_to_list(&self, element_type: &ValueType) -> Result<Value> {
+        let target_type = ValueType::List(Box::new(element_type.clone()));
+
+        match self {
+            Value::Null(_) => Ok(Value::Null(target_type)),
+            Value::List(elements) => {
+                let converted = elements
+                    .iter()
+                    .map(|e| match e {
+                        Value::None => Ok(Value::None),
+                        _ => e.try_convert_to(element_type),
+                    })
+                    .collect::<Result<Vec<Value>>>()
+                    .map_err(|_err| mk_not_rep_err(self, target_type))?;
+                Ok(Value::List(converted))
+            }
+            Value::String(self_val) => {
+                if self_val.is_empty() {
+                    Err(mk_not_rep_err(self, target_type))
+                } else {
+                    Value::parse_list_from_str(self_val, element_type, DEFAULT_LIST_DELIMITER)
+                        .map_err(|_err| mk_not_rep_err(self, target_type))
+                }
+            }
+            _ => Err(mk_not_rep_err(self, target_type)),
+        }
+    }
+
+    /// Date times are converted to their time of day (in their own offset, for DateTime).
+    pub fn try_convert_to_naive_time(&self) -> Result<Value> {
+        let target_type = ValueType::NaiveTime;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
+
+        match self_type {
+            ValueType::String => {
+                let self_val: String = self.try_into()?;
+                if self_val.is_empty() {
+                    Err(mk_not_rep_err(self, target_type))
+                } else {
+                    Value::from_str_and_type(&self_val, &target_type)
+                        .map_err(|_err| mk_not_rep_err(self, target_type))
+                }
+            }
+            ValueType::NaiveDateTime => {
+                let self_val: NaiveDateTime = self.try_into()?;
+                Ok(Value::NaiveTime(self_val.time()))
+            }
+            ValueType::DateTime => {
+                let self_val: DateTime<FixedOffset> = self.try_into()?;
+                Ok(Value::NaiveTime(self_val.time()))
+            }
+            ValueType::NaiveTime => Ok(self.clone()),
+            _ => Err(mk_not_rep_err(self, target_type)),
+        }
+    }
+
+    pub fn try_convert_to_duration(&self) -> Result<Value> {
+        let target_type = ValueType::Duration;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
+
+        match self_type {
+            ValueType::String => {
+                let self_val: String = self.try_into()?;
+                if self_val.is_empty() {
+                    Err(mk_not_rep_err(self, target_type))
+                } else {
+                    Value::from_str_and_type(&self_val, &target_type)
+                        .map_err(|_err| mk_not_rep_err(self, target_type))
+                }
+            }
+            ValueType::Duration => Ok(self.clone()),
+            _ => Err(mk_not_rep_err(self, target_type)),
+        }
+    }
+
+    /// Same as `try_convert_to_bytes_with_bytes_encoding` with hex encoding for strings.
+    pub fn try_convert_to_bytes(&self) -> Result<Value> {
+        self.try_convert_to_bytes_with_bytes_encoding(BytesEncoding::default())
+    }
+
+    pub fn try_convert_to_bytes_with_bytes_encoding(
+        &self,
+        bytes_encoding: BytesEncoding,
+    ) -> Result<Value> {
+        let target_type = ValueType::Bytes;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
+
+        match self_type {
+            ValueType::String => {
+                let self_val: String = self.try_into()?;
+                if self_val.is_empty() {
+                    Err(mk_not_rep_err(self, target_type))
+                } else {
+                    Value::parse_bytes_from_str(&self_val, bytes_encoding)
+                        .map_err(|_err| mk_not_rep_err(self, target_type))
+                }
+            }
+            ValueType::Bytes => Ok(self.clone()),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => {
+                let self_val: uuid::Uuid = self.try_into()?;
+                Ok(Value::Bytes(self_val.as_bytes().to_vec()))
+            }
+            _ => Err(mk_not_rep_err(self, target_type)),
+        }
+    }
+
+    /// Strings are parsed (see: `Value::parse_uuid_from_str`), bytes must be exactly 16 bytes long.
+    #[cfg(feature = "uuid")]
+    pub fn try_convert_to_uuid(&self) -> Result<Value> {
+        let target_type = ValueType::Uuid;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        let self_type = ValueType::try_from(self)?; // TODO: wrap error
+
+        match self_type {
+            ValueType::String => {
+                let self_val: String = self.try_into()?;
+                if self_val.is_empty() {
+                    Err(mk_not_rep_err(self, target_type))
+                } else {
+                    Value::parse_uuid_from_str(&self_val)
+                        .map_err(|_err| mk_not_rep_err(self, target_type))
+                }
+            }
+            ValueType::Bytes => {
+                let self_val: Vec<u8> = self.try_into()?;
+                let self_val_as_target = uuid::Uuid::from_slice(&self_val)
+                    .map_err(|_err| mk_not_rep_err(self, target_type))?;
+                Ok(Value::Uuid(self_val_as_target))
+            }
+            ValueType::Uuid => Ok(self.clone()),
+            _ => Err(mk_not_rep_err(self, target_type)),
+        }
+    }
+
+    pub fn try_convert_to_map(&self) -> Result<Value> {
+        match self {
+            Value::Null(_) => Ok(Value::Null(ValueType::Map)),
+            Value::Map(_) => Ok(self.clone()),
+            _ => Err(mk_not_rep_err(self, ValueType::Map)),
+        }
+    }
+
     pub fn try_convert_to(&self, target_type: &ValueType) -> Result<Value> {
         match target_type {
             ValueType::Char => Self::try_convert_to_char(self),
@@ -2047,6 +2398,13 @@ impl Value {
             ValueType::NaiveDate => Self::try_convert_to_naive_date(self),
             ValueType::NaiveDateTime => Self::try_convert_to_naive_date_time(self),
             ValueType::DateTime => Self::try_convert_to_date_time(self),
+            ValueType::NaiveTime => Self::try_convert_to_naive_time(self),
+            ValueType::Duration => Self::try_convert_to_duration(self),
+            ValueType::List(element_type) => Self::try_convert_to_list(self, element_type),
+            ValueType::Map => Self::try_convert_to_map(self),
+            ValueType::Bytes => Self::try_convert_to_bytes(self),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Self::try_convert_to_uuid(self),
         }
     }
 }
@@ -5742,7 +6100,7 @@ mod tests {
         #[test]
         fn from_decimal() {
             assert_eq!(
-                Value::Float32(123.456789),
+                Value::Float32(123.456_79),
                 Value::Decimal(Decimal::new(123456789, 6))
                     .try_convert_to_float32()
                     .unwrap()
@@ -7036,4 +7394,436 @@ mod tests {
             );
         }
     }
+
+    mod try_convert_to_naive_time {
+        use super::*;
+
+        #[test]
+        fn from_string() {
+            assert_eq!(
+                Value::NaiveTime(NaiveTime::from_hms_opt(8, 30, 0).unwrap()),
+                Value::String(String::from("08:30"))
+                    .try_convert_to_naive_time()
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_string_err() {
+            Value::String(String::from("8h30"))
+                .try_convert_to_naive_time()
+                .unwrap();
+        }
+
+        #[test]
+        fn from_naive_date_time() {
+            assert_eq!(
+                Value::NaiveTime(NaiveTime::from_hms_milli_opt(10, 0, 0, 100).unwrap()),
+                Value::NaiveDateTime(
+                    NaiveDate::from_ymd_opt(2022, 12, 31)
+                        .unwrap()
+                        .and_hms_milli_opt(10, 0, 0, 100)
+                        .unwrap()
+                )
+                .try_convert_to_naive_time()
+                .unwrap()
+            );
+        }
+
+        #[test]
+        fn from_date_time() {
+            // the time of day in the offset of the date time, not in UTC!
+            assert_eq!(
+                Value::NaiveTime(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+                Value::DateTime(
+                    NaiveDate::from_ymd_opt(2022, 12, 31)
+                        .unwrap()
+                        .and_hms_opt(10, 0, 0)
+                        .unwrap()
+                        .and_local_timezone(FixedOffset::east_opt(2 * 3600).unwrap())
+                        .unwrap(),
+                )
+                .try_convert_to(&ValueType::NaiveTime)
+                .unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_naive_date_err() {
+            Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap())
+                .try_convert_to_naive_time()
+                .unwrap();
+        }
+
+        #[test]
+        fn to_string() {
+            assert_eq!(
+                Value::String(String::from("08:30:00.000")),
+                Value::NaiveTime(NaiveTime::from_hms_opt(8, 30, 0).unwrap())
+                    .try_convert_to_string()
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn to_naive_date_time_err() {
+            Value::NaiveTime(NaiveTime::from_hms_opt(8, 30, 0).unwrap())
+                .try_convert_to_naive_date_time()
+                .unwrap();
+        }
+    }
+
+    mod try_convert_to_duration {
+        use super::*;
+
+        #[test]
+        fn from_string() {
+            assert_eq!(
+                Value::Duration(Duration::try_minutes(90).unwrap()),
+                Value::String(String::from("PT1H30M"))
+                    .try_convert_to_duration()
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_string_err() {
+            Value::String(String::from("soon"))
+                .try_convert_to_duration()
+                .unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_int64_err() {
+            Value::Int64(90).try_convert_to_duration().unwrap();
+        }
+
+        #[test]
+        fn to_string() {
+            assert_eq!(
+                Value::String(String::from("-PT26H0.5S")),
+                Value::Duration(-Duration::try_milliseconds(26 * 3600 * 1000 + 500).unwrap())
+                    .try_convert_to_string()
+                    .unwrap()
+            );
+        }
+    }
+
+    mod try_convert_to_bytes {
+        use super::*;
+
+        #[test]
+        fn from_string() {
+            assert_eq!(
+                Value::Bytes(vec![0xca, 0xfe]),
+                Value::String(String::from("0xcafe"))
+                    .try_convert_to_bytes()
+                    .unwrap()
+            );
+            assert_eq!(
+                Value::Bytes(vec![0xca, 0xfe]),
+                Value::String(String::from("cafe"))
+                    .try_convert_to(&ValueType::Bytes)
+                    .unwrap()
+            );
+            assert_eq!(
+                Value::Bytes(vec![0xca, 0xfe]),
+                Value::String(String::from("yv4="))
+                    .try_convert_to_bytes_with_bytes_encoding(BytesEncoding::Base64)
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_string_err() {
+            Value::String(String::from("yv4="))
+                .try_convert_to_bytes()
+                .unwrap();
+        }
+
+        #[test]
+        fn round_trip() {
+            for bytes_encoding in [BytesEncoding::Hex, BytesEncoding::Base64] {
+                let bytes = Value::Bytes(vec![0, 0, 0]);
+                assert_eq!(
+                    Ok(bytes.clone()),
+                    bytes
+                        .try_convert_to_string_with_bytes_encoding(bytes_encoding)
+                        .and_then(|s| s.try_convert_to_bytes_with_bytes_encoding(bytes_encoding)),
+                    "{bytes_encoding:?}"
+                );
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_uint8_err() {
+            Value::UInt8(1).try_convert_to_bytes().unwrap();
+        }
+
+        #[test]
+        fn to_string() {
+            let bytes = Value::Bytes(vec![0x00, 0xca, 0xfe]);
+            assert_eq!(
+                Value::String(String::from("00cafe")),
+                bytes.try_convert_to_string().unwrap()
+            );
+            assert_eq!(
+                Value::String(String::from("AMr+")),
+                bytes
+                    .try_convert_to_string_with_bytes_encoding(BytesEncoding::Base64)
+                    .unwrap()
+            );
+            assert_eq!(
+                Value::String(String::from("AMr+|")),
+                Value::List(vec![bytes, Value::Bytes(vec![])])
+                    .try_convert_to_string_with_bytes_encoding(BytesEncoding::Base64)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[cfg(feature = "uuid")]
+    mod try_convert_to_uuid {
+        use super::*;
+
+        const UUID: uuid::Uuid = uuid::Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+        #[test]
+        fn from_string() {
+            assert_eq!(
+                Value::Uuid(UUID),
+                Value::String(String::from("{67e55044-10b1-426f-9247-bb680e5fe0c8}"))
+                    .try_convert_to_uuid()
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_string_err() {
+            Value::String(String::from("67e55044"))
+                .try_convert_to_uuid()
+                .unwrap();
+        }
+
+        #[test]
+        fn from_bytes() {
+            assert_eq!(
+                Value::Uuid(UUID),
+                Value::Bytes(UUID.as_bytes().to_vec())
+                    .try_convert_to(&ValueType::Uuid)
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_bytes_err() {
+            Value::Bytes(vec![0xca, 0xfe])
+                .try_convert_to_uuid()
+                .unwrap();
+        }
+
+        #[test]
+        fn to_string_and_bytes() {
+            assert_eq!(
+                Value::String(String::from("67e55044-10b1-426f-9247-bb680e5fe0c8")),
+                Value::Uuid(UUID).try_convert_to_string().unwrap()
+            );
+            assert_eq!(
+                Value::Bytes(UUID.as_bytes().to_vec()),
+                Value::Uuid(UUID).try_convert_to_bytes().unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn to_uint128_err() {
+            Value::Uuid(UUID).try_convert_to_uint128().unwrap();
+        }
+    }
+
+    mod try_convert_to_map {
+        use std::collections::BTreeMap;
+
+        use super::*;
+
+        #[test]
+        fn from_map() {
+            let map = Value::Map(BTreeMap::from([(String::from("a"), Value::None)]));
+            assert_eq!(map, map.try_convert_to(&ValueType::Map).unwrap());
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_string_err() {
+            Value::String(String::from("a"))
+                .try_convert_to_map()
+                .unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn to_string_err() {
+            Value::Map(BTreeMap::new()).try_convert_to_string().unwrap();
+        }
+    }
+
+    mod try_convert_to_list {
+        use super::*;
+
+        #[test]
+        fn from_list() {
+            assert_eq!(
+                Value::List(vec![Value::Int64(1), Value::None, Value::Int64(3)]),
+                Value::List(vec![Value::Int8(1), Value::None, Value::UInt32(3)])
+                    .try_convert_to_list(&ValueType::Int64)
+                    .unwrap()
+            );
+            assert_eq!(
+                Value::List(vec![]),
+                Value::List(vec![])
+                    .try_convert_to(&ValueType::List(Box::new(ValueType::Bool)))
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_list_err() {
+            Value::List(vec![Value::Int16(1), Value::Int16(300)])
+                .try_convert_to_list(&ValueType::Int8)
+                .unwrap();
+        }
+
+        #[test]
+        fn from_string() {
+            assert_eq!(
+                Value::List(vec![Value::Float32(1.5), Value::Float32(-2.0)]),
+                Value::String(String::from("1.5|-2"))
+                    .try_convert_to_list(&ValueType::Float32)
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_string_err() {
+            Value::String(String::from("1|a"))
+                .try_convert_to_list(&ValueType::Int32)
+                .unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn from_int_err() {
+            Value::Int8(1)
+                .try_convert_to_list(&ValueType::Int8)
+                .unwrap();
+        }
+
+        #[test]
+        fn to_string() {
+            assert_eq!(
+                Value::String(String::from("1||true")),
+                Value::List(vec![Value::Int8(1), Value::None, Value::Bool(true)])
+                    .try_convert_to_string()
+                    .unwrap()
+            );
+            assert_eq!(
+                Value::String(String::from("")),
+                Value::List(vec![Value::None])
+                    .try_convert_to_string()
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Conversion(NotRepresentableAs")]
+        fn to_scalar_err() {
+            Value::List(vec![Value::Int8(1)])
+                .try_convert_to_int8()
+                .unwrap();
+        }
+    }
+
+    mod try_convert_null {
+        use super::*;
+
+        #[test]
+        fn keeps_null_with_target_type() {
+            let null = Value::Null(ValueType::String);
+            assert_eq!(Ok(ValueType::String), ValueType::try_from(&null));
+            assert_eq!(
+                Value::Null(ValueType::Int32),
+                null.try_convert_to_int32().unwrap()
+            );
+            assert_eq!(
+                Value::Null(ValueType::NaiveDate),
+                null.try_convert_to(&ValueType::NaiveDate).unwrap()
+            );
+            assert_eq!(
+                Value::Null(ValueType::String),
+                Value::Null(ValueType::Bool)
+                    .try_convert_to_string()
+                    .unwrap()
+            );
+            assert_eq!(
+                Value::Null(ValueType::Map),
+                null.try_convert_to_map().unwrap()
+            );
+            assert_eq!(
+                Value::Null(ValueType::List(Box::new(ValueType::Int8))),
+                null.try_convert_to_list(&ValueType::Int8).unwrap()
+            );
+        }
+
+        #[test]
+        fn in_list() {
+            assert_eq!(
+                Value::List(vec![
+                    Value::Int64(1),
+                    Value::Null(ValueType::Int64),
+                    Value::None
+                ]),
+                Value::List(vec![
+                    Value::Int8(1),
+                    Value::Null(ValueType::Int8),
+                    Value::None
+                ])
+                .try_convert_to_list(&ValueType::Int64)
+                .unwrap()
+            );
+            assert_eq!(
+                Value::String(String::from("1|")),
+                Value::List(vec![Value::Int8(1), Value::Null(ValueType::Int8)])
+                    .try_convert_to_string()
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn list_to_string_round_trip() {
+            let list_type = ValueType::List(Box::new(ValueType::String));
+            let list = Value::List(vec![
+                Value::String(String::from("a|b")),
+                Value::String(String::from(r"C:\temp\")),
+                Value::String(String::from(r"\|")),
+                Value::String(String::from("c")),
+            ]);
+            let s = list.try_convert_to_string().unwrap();
+            assert_eq!(Value::String(String::from(r"a\|b|C:\\temp\\|\\\||c")), s);
+            assert_eq!(
+                Ok(list),
+                Value::from_str_and_type(&String::try_from(s).unwrap(), &list_type)
+            );
+        }
+    }
 }
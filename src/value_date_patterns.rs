@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+
+use chrono::NaiveTime;
+
+use crate::errors_result::{ParseError, Result, VenumError};
+use crate::value::Value;
+use crate::value_type::ValueType;
+
+/// One way to write a date (time), see: `Value::parse_date_from_str_with_patterns`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DatePattern {
+    /// e.g. "%d.%m.%Y"
+    Chrono(String),
+    /// e.g. "1996-12-19T16:39:57-08:00". Only for `ValueType::DateTime`.
+    Rfc3339,
+    /// e.g. "Tue, 1 Jul 2003 10:52:37 +0200". Only for `ValueType::DateTime`.
+    Rfc2822,
+}
+
+impl From<&str> for DatePattern {
+    fn from(chrono_pattern: &str) -> Self {
+        DatePattern::Chrono(String::from(chrono_pattern))
+    }
+}
+
+impl DatePattern {
+    fn parse(&self, v: &str, target_value_type: &ValueType) -> Option<Result<Value>> {
+        match (self, target_value_type) {
+            (DatePattern::Chrono(p), ValueType::NaiveDate) => {
+                Some(Value::parse_naive_date_from_str(v, p))
+            }
+            (DatePattern::Chrono(p), ValueType::NaiveDateTime) => {
+                Some(Value::parse_naive_date_time_from_str(v, p))
+            }
+            (DatePattern::Chrono(p), ValueType::DateTime) => {
+                Some(Value::parse_date_time_from_str(v, p))
+            }
+            (DatePattern::Chrono(p), ValueType::NaiveTime) => {
+                Some(Value::parse_naive_time_from_str(v, p))
+            }
+            (DatePattern::Rfc3339, ValueType::DateTime) => {
+                Some(Value::parse_date_time_from_str_rfc3339(v))
+            }
+            (DatePattern::Rfc2822, ValueType::DateTime) => {
+                Some(Value::parse_date_time_from_str_rfc2822(v))
+            }
+            _ => None, // doesn't apply to this type
+        }
+    }
+}
+
+/// Ordered lists of date patterns per date (time) type, i.e. for `NaiveDate`, `NaiveDateTime`, `DateTime`
+/// and `NaiveTime`. The default is what `Value::from_str_and_type` tries without a chrono pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatePatterns {
+    patterns: HashMap<ValueType, Vec<DatePattern>>,
+}
+
+impl Default for DatePatterns {
+    fn default() -> Self {
+        DatePatterns::empty()
+            .with_patterns(ValueType::NaiveDate, &[DatePattern::from("%Y-%m-%d")])
+            .with_patterns(
+                ValueType::NaiveDateTime,
+                &[
+                    DatePattern::from("%Y-%m-%dT%H:%M:%S"),
+                    DatePattern::from("%Y-%m-%dT%H:%M:%S%.3f"),
+                ],
+            )
+            .with_patterns(
+                ValueType::DateTime,
+                &[DatePattern::Rfc3339, DatePattern::Rfc2822],
+            )
+            .with_patterns(
+                ValueType::NaiveTime,
+                &[DatePattern::from("%H:%M:%S%.f"), DatePattern::from("%H:%M")],
+            )
+    }
+}
+
+impl DatePatterns {
+    /// No patterns at all, i.e. nothing parses
+    pub fn empty() -> Self {
+        DatePatterns {
+            patterns: HashMap::new(),
+        }
+    }
+
+    /// Replaces the patterns of the type
+    pub fn with_patterns(mut self, value_type: ValueType, patterns: &[DatePattern]) -> Self {
+        self.patterns.insert(value_type, patterns.to_vec());
+        self
+    }
+
+    pub fn patterns(&self, value_type: &ValueType) -> &[DatePattern] {
+        self.patterns
+            .get(value_type)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// See: `Value::parse_date_from_str_with_patterns`
+    /// NOTE: `NaiveDateTime` falls back to the `NaiveDate` patterns, with dates becoming midnight,
+    ///       e.g. for columns with dates and date times (see: `TypeInferrer`).
+    pub fn parse(&self, v: &str, target_value_type: &ValueType) -> Result<(Value, &DatePattern)> {
+        let parsed = Value::parse_date_from_str_with_patterns(
+            v,
+            target_value_type,
+            self.patterns(target_value_type),
+        );
+        if parsed.is_err() && *target_value_type == ValueType::NaiveDateTime {
+            if let Ok((Value::NaiveDate(d), pattern)) = Value::parse_date_from_str_with_patterns(
+                v,
+                &ValueType::NaiveDate,
+                self.patterns(&ValueType::NaiveDate),
+            ) {
+                return Ok((Value::NaiveDateTime(d.and_time(NaiveTime::MIN)), pattern));
+            }
+        }
+        parsed
+    }
+}
+
+impl Value {
+    /// Tries the patterns in order and returns the value of the first one that matches, together with the pattern.
+    /// Works for `NaiveDate`, `NaiveDateTime`, `DateTime` and `NaiveTime`. Patterns that don't apply to the type
+    /// (i.e. RFC 3339 / RFC 2822 for anything but `DateTime`) are skipped.
+    /// NOTE: Unlike the `parse_*_from_str` functions, an empty string is an error here, not a `Value::None`.
+    pub fn parse_date_from_str_with_patterns<'p>(
+        v: &str,
+        target_value_type: &ValueType,
+        patterns: &'p [DatePattern],
+    ) -> Result<(Value, &'p DatePattern)> {
+        let mk_err = |details: String| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("Value::{target_value_type}"),
+                details: Some(details),
+            })
+        };
+        if v.is_empty() {
+            return Err(mk_err(String::from("Empty string")));
+        }
+        if !target_value_type.is_some_date_type() && *target_value_type != ValueType::NaiveTime {
+            return Err(mk_err(String::from("Not a date (time) type")));
+        }
+        let mut last_err = None;
+        for pattern in patterns {
+            match pattern.parse(v, target_value_type) {
+                Some(Ok(value)) => return Ok((value, pattern)),
+                Some(Err(err)) => last_err = Some(err),
+                None => {}
+            }
+        }
+        Err(mk_err(format!(
+            "No date pattern matched. Tried: {patterns:?}. Last error: {last_err:?}"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, NaiveDate, NaiveTime};
+
+    use super::*;
+
+    fn mixed() -> Vec<DatePattern> {
+        vec![
+            DatePattern::from("%d.%m.%Y"),
+            DatePattern::from("%m/%d/%Y"),
+            DatePattern::from("%Y-%m-%d"),
+        ]
+    }
+
+    #[test]
+    pub fn parse_with_patterns() {
+        let patterns = mixed();
+        let expected = Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap());
+        assert_eq!(
+            Ok((expected.clone(), &patterns[0])),
+            Value::parse_date_from_str_with_patterns(
+                "31.12.2022",
+                &ValueType::NaiveDate,
+                &patterns
+            )
+        );
+        assert_eq!(
+            Ok((expected.clone(), &patterns[1])),
+            Value::parse_date_from_str_with_patterns(
+                "12/31/2022",
+                &ValueType::NaiveDate,
+                &patterns
+            )
+        );
+        assert_eq!(
+            Ok((expected, &patterns[2])),
+            Value::parse_date_from_str_with_patterns(
+                "2022-12-31",
+                &ValueType::NaiveDate,
+                &patterns
+            )
+        );
+    }
+
+    #[test]
+    pub fn parse_with_patterns_first_wins() {
+        let patterns = vec![DatePattern::from("%d/%m/%Y"), DatePattern::from("%m/%d/%Y")];
+        assert_eq!(
+            Ok((
+                Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 2, 1).unwrap()),
+                &patterns[0]
+            )),
+            Value::parse_date_from_str_with_patterns(
+                "01/02/2022",
+                &ValueType::NaiveDate,
+                &patterns
+            )
+        );
+    }
+
+    #[test]
+    pub fn parse_with_patterns_rfc() {
+        let patterns = vec![DatePattern::Rfc3339, DatePattern::Rfc2822];
+        let expected = Value::DateTime(
+            NaiveDate::from_ymd_opt(2003, 7, 1)
+                .unwrap()
+                .and_hms_opt(10, 52, 37)
+                .unwrap()
+                .and_local_timezone(FixedOffset::east_opt(7200).unwrap())
+                .unwrap(),
+        );
+        assert_eq!(
+            Ok((expected.clone(), &DatePattern::Rfc2822)),
+            Value::parse_date_from_str_with_patterns(
+                "Tue, 1 Jul 2003 10:52:37 +0200",
+                &ValueType::DateTime,
+                &patterns
+            )
+        );
+        assert_eq!(
+            Ok((expected, &DatePattern::Rfc3339)),
+            Value::parse_date_from_str_with_patterns(
+                "2003-07-01T10:52:37+02:00",
+                &ValueType::DateTime,
+                &patterns
+            )
+        );
+        // RFCs don't apply to naive types
+        assert!(Value::parse_date_from_str_with_patterns(
+            "2003-07-01T10:52:37+02:00",
+            &ValueType::NaiveDateTime,
+            &patterns
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn parse_with_patterns_err() {
+        assert_eq!(
+            Err(VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from("2022-12-31"),
+                target_type: String::from("Value::NaiveDate"),
+                details: Some(String::from(
+                    r#"No date pattern matched. Tried: [Chrono("%d.%m.%Y")]. Last error: Some(Parsing(ValueFromStringFailed { src_value: "2022-12-31", target_type: "Value::NaiveDate", details: Some("Chrono pattern: %d.%m.%Y. Original error: input contains invalid characters") }))"#
+                )),
+            })),
+            Value::parse_date_from_str_with_patterns(
+                "2022-12-31",
+                &ValueType::NaiveDate,
+                &[DatePattern::from("%d.%m.%Y")]
+            )
+        );
+        assert!(
+            Value::parse_date_from_str_with_patterns("", &ValueType::NaiveDate, &mixed()).is_err()
+        );
+        assert!(
+            Value::parse_date_from_str_with_patterns("2022-12-31", &ValueType::NaiveDate, &[])
+                .is_err()
+        );
+        assert!(Value::parse_date_from_str_with_patterns(
+            "2022-12-31",
+            &ValueType::String,
+            &mixed()
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn date_patterns_default() {
+        let patterns = DatePatterns::default();
+        assert_eq!(
+            Ok((
+                Value::NaiveTime(NaiveTime::from_hms_opt(8, 30, 0).unwrap()),
+                &DatePattern::from("%H:%M")
+            )),
+            patterns.parse("08:30", &ValueType::NaiveTime)
+        );
+        assert_eq!(
+            Some(&DatePattern::Rfc2822),
+            patterns
+                .parse("Tue, 1 Jul 2003 10:52:37 +0200", &ValueType::DateTime)
+                .ok()
+                .map(|(_, p)| p)
+        );
+        assert!(DatePatterns::empty()
+            .parse("2022-12-31", &ValueType::NaiveDate)
+            .is_err());
+    }
+
+    #[test]
+    pub fn date_patterns_per_type() {
+        let patterns = DatePatterns::default().with_patterns(ValueType::NaiveDate, &mixed());
+        assert_eq!(mixed().as_slice(), patterns.patterns(&ValueType::NaiveDate));
+        assert!(patterns.parse("31.12.2022", &ValueType::NaiveDate).is_ok());
+        assert!(patterns
+            .parse("31.12.2022 12:00", &ValueType::NaiveDateTime)
+            .is_err());
+        assert!(patterns.patterns(&ValueType::Int8).is_empty());
+    }
+
+    #[test]
+    pub fn date_patterns_naive_date_time_falls_back_to_dates() {
+        let patterns = DatePatterns::empty().with_patterns(ValueType::NaiveDate, &mixed());
+        assert_eq!(
+            Ok((
+                Value::NaiveDateTime(
+                    NaiveDate::from_ymd_opt(2022, 12, 31)
+                        .unwrap()
+                        .and_hms_opt(0, 0, 0)
+                        .unwrap()
+                ),
+                &DatePattern::from("%d.%m.%Y")
+            )),
+            patterns.parse("31.12.2022", &ValueType::NaiveDateTime)
+        );
+        assert!(patterns.parse("31.12.2022", &ValueType::DateTime).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::errors_result::{ConversionError, Result, VenumError};
+use crate::value::Value;
+use crate::value_type::ValueType;
+
+/// How values become a `Value::Decimal`, see: `Value::try_convert_to_decimal_with`.
+/// The default is what `try_convert_to_decimal` does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalOptions {
+    /// Number of decimal places of the result. More places are rounded, fewer are padded with zeros,
+    /// e.g. 1.5 becomes 1.50 with scale 2.
+    pub scale: Option<u32>,
+    /// Used when rounding to the scale. Default: banker's rounding, i.e. `MidpointNearestEven`.
+    pub rounding: RoundingStrategy,
+    /// Fail instead of rounding, if rounding to the scale would change the value
+    pub strict: bool,
+    /// Floats keep all their bits, e.g. 0.1 becomes 0.1000000000000000055511151231, see: `Value::decimal_from_f64_retain`
+    pub retain: bool,
+}
+
+impl Default for DecimalOptions {
+    fn default() -> Self {
+        DecimalOptions {
+            scale: None,
+            rounding: RoundingStrategy::MidpointNearestEven,
+            strict: false,
+            retain: false,
+        }
+    }
+}
+
+impl DecimalOptions {
+    pub fn new() -> Self {
+        DecimalOptions::default()
+    }
+
+    pub fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    pub fn with_rounding(mut self, rounding: RoundingStrategy) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    pub fn retain(mut self) -> Self {
+        self.retain = true;
+        self
+    }
+
+    /// Rounds (or pads) the decimal to the scale. `None`, if strict and rounding would change the value.
+    pub fn apply(&self, d: Decimal) -> Option<Decimal> {
+        let scale = match self.scale {
+            Some(scale) => scale,
+            None => return Some(d),
+        };
+        let mut rounded = d.round_dp_with_strategy(scale, self.rounding);
+        if self.strict && rounded != d {
+            return None;
+        }
+        // pad, e.g. 1.5 to 1.50 (rescale doesn't change the value here, as it's already rounded)
+        rounded.rescale(scale);
+        Some(rounded)
+    }
+}
+
+impl Value {
+    /// Like `try_convert_to_decimal`, but with control over scale, rounding and the conversion of floats.
+    pub fn try_convert_to_decimal_with(&self, options: &DecimalOptions) -> Result<Value> {
+        let mk_err = || {
+            VenumError::Conversion(ConversionError::NotRepresentableAs {
+                src: self.clone(),
+                target_type: ValueType::Decimal,
+            })
+        };
+        let converted = match self {
+            Value::Float32(f) if options.retain => Decimal::from_f32_retain(*f),
+            Value::Float64(f) if options.retain => Decimal::from_f64_retain(*f),
+            Value::Float32(f) => Decimal::from_f32(*f),
+            Value::Float64(f) => Decimal::from_f64(*f),
+            _ => match self.try_convert_to_decimal()? {
+                Value::Decimal(d) => Some(d),
+                null => return Ok(null),
+            },
+        };
+        converted
+            .and_then(|d| options.apply(d))
+            .map(Value::Decimal)
+            .ok_or_else(mk_err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    pub fn default_is_try_convert_to_decimal() {
+        for v in [
+            Value::Float64(0.1),
+            Value::Float32(1.5),
+            Value::String(String::from("1.23456")),
+            Value::String(String::from("abc")),
+            Value::Int64(-7),
+            Value::Bool(true),
+            Value::Null(ValueType::Float64),
+        ] {
+            assert_eq!(
+                v.try_convert_to_decimal(),
+                v.try_convert_to_decimal_with(&DecimalOptions::default())
+            );
+        }
+    }
+
+    #[test]
+    pub fn scale_and_rounding() {
+        let options = DecimalOptions::new().with_scale(2);
+        assert_eq!(
+            Ok(Value::Decimal(dec("2.34"))),
+            Value::Float64(2.345).try_convert_to_decimal_with(&options)
+        );
+        assert_eq!(
+            Ok(Value::Decimal(dec("2.36"))),
+            Value::String(String::from("2.355")).try_convert_to_decimal_with(&options)
+        );
+        assert_eq!(
+            Ok(Value::Decimal(dec("2.35"))),
+            Value::String(String::from("2.345")).try_convert_to_decimal_with(
+                &options.with_rounding(RoundingStrategy::MidpointAwayFromZero)
+            )
+        );
+        assert_eq!(
+            Ok(Value::Decimal(dec("-2.35"))),
+            Value::String(String::from("-2.341")).try_convert_to_decimal_with(
+                &options.with_rounding(RoundingStrategy::ToNegativeInfinity)
+            )
+        );
+        assert_eq!(
+            Ok(Value::Decimal(dec("2.34"))),
+            Value::String(String::from("2.349"))
+                .try_convert_to_decimal_with(&options.with_rounding(RoundingStrategy::ToZero))
+        );
+    }
+
+    #[test]
+    pub fn scale_pads() {
+        let converted = Value::Int32(3)
+            .try_convert_to_decimal_with(&DecimalOptions::new().with_scale(2))
+            .unwrap();
+        assert_eq!(Value::Decimal(dec("3")), converted);
+        assert!(matches!(converted, Value::Decimal(d) if d.to_string() == "3.00"));
+    }
+
+    #[test]
+    pub fn strict() {
+        let options = DecimalOptions::new().with_scale(2).strict();
+        assert_eq!(
+            Ok(Value::Decimal(dec("1.50"))),
+            Value::Float64(1.5).try_convert_to_decimal_with(&options)
+        );
+        assert_eq!(
+            Err(VenumError::Conversion(
+                ConversionError::NotRepresentableAs {
+                    src: Value::String(String::from("1.505")),
+                    target_type: ValueType::Decimal,
+                }
+            )),
+            Value::String(String::from("1.505")).try_convert_to_decimal_with(&options)
+        );
+    }
+
+    #[test]
+    pub fn retain() {
+        let options = DecimalOptions::new().retain();
+        assert_eq!(
+            Ok(Value::Decimal(dec("0.1000000000000000055511151231"))),
+            Value::Float64(0.1).try_convert_to_decimal_with(&options)
+        );
+        assert_eq!(
+            Ok(Value::Decimal(dec("0.10"))),
+            Value::Float64(0.1).try_convert_to_decimal_with(&options.with_scale(2))
+        );
+        assert!(Value::Float64(0.1)
+            .try_convert_to_decimal_with(&options.with_scale(2).strict())
+            .is_err());
+        assert!(Value::Float64(f64::NAN)
+            .try_convert_to_decimal_with(&options)
+            .is_err());
+    }
+}
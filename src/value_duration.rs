@@ -0,0 +1,293 @@
+use chrono::Duration;
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+const SECS_PER_MINUTE: i128 = 60;
+const SECS_PER_HOUR: i128 = 60 * SECS_PER_MINUTE;
+const SECS_PER_DAY: i128 = 24 * SECS_PER_HOUR;
+const SECS_PER_WEEK: i128 = 7 * SECS_PER_DAY;
+
+fn duration_from_nanos(nanos: i128) -> Option<Duration> {
+    let secs = i64::try_from(nanos.div_euclid(NANOS_PER_SEC)).ok()?;
+    let nanos = u32::try_from(nanos.rem_euclid(NANOS_PER_SEC)).ok()?;
+    Duration::new(secs, nanos)
+}
+
+fn duration_as_nanos(d: &Duration) -> i128 {
+    // subsec_nanos has the same sign as num_seconds
+    i128::from(d.num_seconds()) * NANOS_PER_SEC + i128::from(d.subsec_nanos())
+}
+
+// e.g. "1", "1.5" or "1,5". At most 9 fractional digits, i.e. nanoseconds.
+fn number_as_nanos(number: &str, unit_secs: i128) -> Option<i128> {
+    let (int_part, frac_part) = match number.find(['.', ',']) {
+        Some(idx) => (&number[..idx], &number[idx + 1..]),
+        None => (number, ""),
+    };
+    if int_part.is_empty()
+        || frac_part.len() > 9
+        || !int_part.chars().all(|c| c.is_ascii_digit())
+        || !frac_part.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let int_nanos = int_part
+        .parse::<i128>()
+        .ok()?
+        .checked_mul(unit_secs)?
+        .checked_mul(NANOS_PER_SEC)?;
+    let frac_nanos = if frac_part.is_empty() {
+        0
+    } else {
+        // "5" -> 500_000_000
+        format!("{:0<9}", frac_part).parse::<i128>().ok()? * unit_secs
+    };
+    int_nanos.checked_add(frac_nanos)
+}
+
+// Parses a sequence of <number><unit>, where the units must appear in the given order, each one at most once.
+// Returns the number of components found.
+fn parse_components(mut s: &str, units: &[(char, i128)], nanos: &mut i128) -> Option<usize> {
+    let mut count = 0;
+    let mut next_unit = 0;
+    while !s.is_empty() {
+        let end = s.find(|c: char| c.is_ascii_alphabetic())?;
+        let unit = s[end..].chars().next()?;
+        let pos = next_unit + units[next_unit..].iter().position(|(u, _)| *u == unit)?;
+        *nanos = nanos.checked_add(number_as_nanos(&s[..end], units[pos].1)?)?;
+        next_unit = pos + 1;
+        s = &s[end + 1..];
+        count += 1;
+    }
+    Some(count)
+}
+
+/// ISO-8601 durations like "PT1H30M", "P1DT12H", "P2W", "PT0.5S" or "-PT10M".
+/// NOTE: Years and months are rejected, as they don't have a fixed length. A day is always 24 hours.
+pub(crate) fn parse_iso8601(v: &str) -> Option<Duration> {
+    let (negative, rest) = match v.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, v.strip_prefix('+').unwrap_or(v)),
+    };
+    let rest = rest.strip_prefix('P')?;
+    let (date_part, time_part) = match rest.split_once('T') {
+        Some((date_part, time_part)) => (date_part, Some(time_part)),
+        None => (rest, None),
+    };
+
+    let mut nanos: i128 = 0;
+    let mut components = parse_components(
+        date_part,
+        &[('W', SECS_PER_WEEK), ('D', SECS_PER_DAY)],
+        &mut nanos,
+    )?;
+    if let Some(time_part) = time_part {
+        let time_components = parse_components(
+            time_part,
+            &[('H', SECS_PER_HOUR), ('M', SECS_PER_MINUTE), ('S', 1)],
+            &mut nanos,
+        )?;
+        if time_components == 0 {
+            return None; // "PT" or "P1DT"
+        }
+        components += time_components;
+    }
+    if components == 0 {
+        return None; // "P"
+    }
+    duration_from_nanos(if negative { -nanos } else { nanos })
+}
+
+/// Durations like "01:30:00", "-00:00:01.5" or "100:00:00", i.e. hours can be more than 24.
+pub(crate) fn parse_hms(v: &str) -> Option<Duration> {
+    let (negative, rest) = match v.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, v),
+    };
+    let mut parts = rest.split(':');
+    let (hours, minutes, seconds) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() || minutes.len() != 2 || seconds.split(['.', ',']).next()?.len() != 2
+    {
+        return None;
+    }
+    let minutes_nanos = number_as_nanos(minutes, SECS_PER_MINUTE)?;
+    let seconds_nanos = number_as_nanos(seconds, 1)?;
+    if minutes_nanos >= SECS_PER_HOUR * NANOS_PER_SEC
+        || seconds_nanos >= SECS_PER_MINUTE * NANOS_PER_SEC
+    {
+        return None;
+    }
+    let nanos = number_as_nanos(hours, SECS_PER_HOUR)?
+        .checked_add(minutes_nanos)?
+        .checked_add(seconds_nanos)?;
+    duration_from_nanos(if negative { -nanos } else { nanos })
+}
+
+/// Whole seconds, e.g. "90" or "-5".
+pub(crate) fn parse_secs(v: &str) -> Option<Duration> {
+    Duration::try_seconds(v.parse::<i64>().ok()?)
+}
+
+/// Whole milliseconds, e.g. "1500" or "-5".
+pub(crate) fn parse_millis(v: &str) -> Option<Duration> {
+    Duration::try_milliseconds(v.parse::<i64>().ok()?)
+}
+
+/// The ISO-8601 representation, using hours as the biggest unit, e.g. "PT26H0.5S" or "-PT1M".
+/// A zero duration is "PT0S".
+pub(crate) fn format_iso8601(d: &Duration) -> String {
+    let nanos = duration_as_nanos(d);
+    if nanos == 0 {
+        return String::from("PT0S");
+    }
+    let abs_nanos = nanos.abs();
+    let hours = abs_nanos / (SECS_PER_HOUR * NANOS_PER_SEC);
+    let minutes = abs_nanos % (SECS_PER_HOUR * NANOS_PER_SEC) / (SECS_PER_MINUTE * NANOS_PER_SEC);
+    let seconds = abs_nanos % (SECS_PER_MINUTE * NANOS_PER_SEC) / NANOS_PER_SEC;
+    let frac_nanos = abs_nanos % NANOS_PER_SEC;
+
+    let mut res = String::from(if nanos < 0 { "-PT" } else { "PT" });
+    if hours > 0 {
+        res.push_str(&format!("{hours}H"));
+    }
+    if minutes > 0 {
+        res.push_str(&format!("{minutes}M"));
+    }
+    if frac_nanos > 0 {
+        let frac = format!("{:09}", frac_nanos);
+        res.push_str(&format!("{seconds}.{}S", frac.trim_end_matches('0')));
+    } else if seconds > 0 {
+        res.push_str(&format!("{seconds}S"));
+    }
+    res
+}
+
+/// (De-)Serializes a chrono Duration as ISO-8601 string, since chrono doesn't implement serde for it.
+#[cfg(feature = "serde")]
+pub(crate) mod serde_iso8601 {
+    use chrono::Duration;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&super::format_iso8601(d))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        super::parse_iso8601(&s)
+            .ok_or_else(|| D::Error::custom(format!("not an ISO-8601 duration: {s}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod iso8601 {
+        use super::*;
+
+        #[test]
+        pub fn parse() {
+            assert_eq!(
+                Some(Duration::try_minutes(90).unwrap()),
+                parse_iso8601("PT1H30M")
+            );
+            assert_eq!(
+                Some(Duration::try_hours(36).unwrap()),
+                parse_iso8601("P1DT12H")
+            );
+            assert_eq!(Some(Duration::try_weeks(2).unwrap()), parse_iso8601("P2W"));
+            assert_eq!(
+                Some(Duration::try_milliseconds(500).unwrap()),
+                parse_iso8601("PT0.5S")
+            );
+            assert_eq!(
+                Some(Duration::try_milliseconds(1500).unwrap()),
+                parse_iso8601("PT1,5S")
+            );
+            assert_eq!(
+                Some(Duration::try_minutes(-10).unwrap()),
+                parse_iso8601("-PT10M")
+            );
+            assert_eq!(Some(Duration::zero()), parse_iso8601("PT0S"));
+        }
+
+        #[test]
+        pub fn parse_err() {
+            assert_eq!(None, parse_iso8601(""));
+            assert_eq!(None, parse_iso8601("P"));
+            assert_eq!(None, parse_iso8601("PT"));
+            assert_eq!(None, parse_iso8601("P1DT"));
+            assert_eq!(None, parse_iso8601("1H"));
+            assert_eq!(None, parse_iso8601("P1Y")); // years and months have no fixed length
+            assert_eq!(None, parse_iso8601("P1M"));
+            assert_eq!(None, parse_iso8601("PT1M1H")); // wrong order
+            assert_eq!(None, parse_iso8601("PT1H1H")); // twice
+            assert_eq!(None, parse_iso8601("PTH"));
+            assert_eq!(None, parse_iso8601("PT-1H"));
+            assert_eq!(None, parse_iso8601("PT1.0000000001S")); // more precise than nanos
+            assert_eq!(None, parse_iso8601("PT99999999999999999999H")); // out of range
+        }
+
+        #[test]
+        pub fn format() {
+            assert_eq!(
+                "PT1H30M",
+                format_iso8601(&Duration::try_minutes(90).unwrap())
+            );
+            assert_eq!("PT26H", format_iso8601(&Duration::try_hours(26).unwrap()));
+            assert_eq!(
+                "PT1.5S",
+                format_iso8601(&Duration::try_milliseconds(1500).unwrap())
+            );
+            assert_eq!(
+                "-PT0.000000001S",
+                format_iso8601(&Duration::nanoseconds(-1))
+            );
+            assert_eq!("PT0S", format_iso8601(&Duration::zero()));
+        }
+
+        #[test]
+        pub fn round_trip() {
+            for d in [
+                Duration::zero(),
+                Duration::nanoseconds(1),
+                Duration::try_milliseconds(-1500).unwrap(),
+                Duration::try_days(400).unwrap(),
+                Duration::MAX,
+                Duration::MIN,
+            ] {
+                assert_eq!(Some(d), parse_iso8601(&format_iso8601(&d)));
+            }
+        }
+    }
+
+    mod hms {
+        use super::*;
+
+        #[test]
+        pub fn parse() {
+            assert_eq!(
+                Some(Duration::try_minutes(90).unwrap()),
+                parse_hms("01:30:00")
+            );
+            assert_eq!(
+                Some(Duration::try_hours(100).unwrap()),
+                parse_hms("100:00:00")
+            );
+            assert_eq!(
+                Some(Duration::try_milliseconds(-1500).unwrap()),
+                parse_hms("-00:00:01.5")
+            );
+        }
+
+        #[test]
+        pub fn parse_err() {
+            assert_eq!(None, parse_hms("01:30"));
+            assert_eq!(None, parse_hms("01:60:00"));
+            assert_eq!(None, parse_hms("01:00:60"));
+            assert_eq!(None, parse_hms("01:0:00"));
+            assert_eq!(None, parse_hms("01:00:00:00"));
+            assert_eq!(None, parse_hms("PT1H"));
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+
+use crate::errors_result::{ConversionError, ParseError, Result, VenumError};
+use crate::value::Value;
+use crate::value_type::ValueType;
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// The unit of a Unix timestamp, i.e. of the time since 1970-01-01T00:00:00 UTC
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EpochUnit {
+    #[default]
+    Seconds,
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl EpochUnit {
+    pub fn nanos_per_unit(&self) -> i128 {
+        match self {
+            EpochUnit::Seconds => NANOS_PER_SEC,
+            EpochUnit::Millis => 1_000_000,
+            EpochUnit::Micros => 1_000,
+            EpochUnit::Nanos => 1,
+        }
+    }
+}
+
+// Nanoseconds since the epoch. Fractions of a nanosecond are rounded.
+fn epoch_nanos(v: &Value, unit: &EpochUnit) -> Option<i128> {
+    let factor = unit.nanos_per_unit();
+    match v {
+        Value::Float32(_) | Value::Float64(_) => {
+            let f: f64 = match v {
+                Value::Float32(f) => f64::from(*f),
+                Value::Float64(f) => *f,
+                _ => unreachable!(),
+            };
+            let nanos = (f * factor as f64).round();
+            // i64 nanos are roughly +/- 292 years, i128 is plenty for everything chrono can represent
+            if nanos.is_finite() && nanos.abs() < 1e36 {
+                Some(nanos as i128)
+            } else {
+                None
+            }
+        }
+        Value::Decimal(d) => d
+            .checked_mul(Decimal::from_i128(factor)?)?
+            .round()
+            .to_i128(),
+        Value::String(s) => epoch_nanos(&Value::Decimal(Decimal::from_str_exact(s).ok()?), unit),
+        _ if ValueType::try_from(v).map_or(false, |t| t.is_some_int_type()) => {
+            match v.try_convert_to(&ValueType::Int128).ok()? {
+                Value::Int128(i) => i.checked_mul(factor),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn date_time_from_epoch_nanos(nanos: i128) -> Option<DateTime<Utc>> {
+    let secs = i64::try_from(nanos.div_euclid(NANOS_PER_SEC)).ok()?;
+    let subsec_nanos = u32::try_from(nanos.rem_euclid(NANOS_PER_SEC)).ok()?;
+    DateTime::from_timestamp(secs, subsec_nanos)
+}
+
+fn date_time_to_epoch_nanos<Tz: chrono::TimeZone>(dt: &DateTime<Tz>) -> i128 {
+    i128::from(dt.timestamp()) * NANOS_PER_SEC + i128::from(dt.timestamp_subsec_nanos())
+}
+
+impl Value {
+    /// Ints, floats, decimals (and numeric strings) as Unix timestamp in the given unit, e.g. `Int64(1_700_000_000)`
+    /// with `EpochUnit::Seconds` is 2023-11-14T22:13:20. Fractions are kept down to nanoseconds.
+    pub fn try_convert_to_naive_date_time_from_epoch(&self, unit: &EpochUnit) -> Result<Value> {
+        let target_type = ValueType::NaiveDateTime;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        epoch_nanos(self, unit)
+            .and_then(date_time_from_epoch_nanos)
+            .map(|dt| Value::NaiveDateTime(dt.naive_utc()))
+            .ok_or_else(|| {
+                VenumError::Conversion(ConversionError::NotRepresentableAs {
+                    src: self.clone(),
+                    target_type,
+                })
+            })
+    }
+
+    /// Like `try_convert_to_naive_date_time_from_epoch`, in UTC (i.e. offset +00:00)
+    pub fn try_convert_to_date_time_from_epoch(&self, unit: &EpochUnit) -> Result<Value> {
+        let target_type = ValueType::DateTime;
+        if self.is_null() {
+            return Ok(Value::Null(target_type));
+        }
+        epoch_nanos(self, unit)
+            .and_then(date_time_from_epoch_nanos)
+            .map(|dt| Value::DateTime(dt.with_timezone(&FixedOffset::east_opt(0).unwrap())))
+            .ok_or_else(|| {
+                VenumError::Conversion(ConversionError::NotRepresentableAs {
+                    src: self.clone(),
+                    target_type,
+                })
+            })
+    }
+
+    /// `NaiveDateTime` (taken as UTC) and `DateTime` to a Unix timestamp in the given unit.
+    /// Ints are floored to the unit (e.g. 1.9 seconds before the epoch is -2), floats and decimals keep the fraction.
+    /// Works for all int, float and decimal target types, as long as the timestamp fits.
+    pub fn try_convert_to_epoch(&self, target_type: &ValueType, unit: &EpochUnit) -> Result<Value> {
+        if self.is_null() {
+            return Ok(Value::Null(target_type.clone()));
+        }
+        let mk_err = || {
+            VenumError::Conversion(ConversionError::NotRepresentableAs {
+                src: self.clone(),
+                target_type: target_type.clone(),
+            })
+        };
+        let nanos = match self {
+            Value::NaiveDateTime(ndt) => date_time_to_epoch_nanos(&ndt.and_utc()),
+            Value::DateTime(dt) => date_time_to_epoch_nanos(dt),
+            _ => return Err(mk_err()),
+        };
+        let factor = unit.nanos_per_unit();
+        let epoch = match target_type {
+            ValueType::Float32 | ValueType::Float64 => Value::Float64(nanos as f64 / factor as f64),
+            ValueType::Decimal => Decimal::from_i128(nanos)
+                .and_then(|d| d.checked_div(Decimal::from_i128(factor)?))
+                .map(|d| Value::Decimal(d.normalize()))
+                .ok_or_else(mk_err)?,
+            _ if target_type.is_some_int_type() => Value::Int128(nanos.div_euclid(factor)),
+            _ => return Err(mk_err()),
+        };
+        epoch.try_convert_to(target_type).map_err(|_| mk_err())
+    }
+
+    /// Parses a numeric string, e.g. "1700000000" or "1700000000.5", as Unix timestamp
+    /// (for `NaiveDateTime` and `DateTime`), see: `try_convert_to_naive_date_time_from_epoch`
+    pub fn parse_date_time_from_str_epoch(
+        v: &str,
+        target_value_type: &ValueType,
+        unit: &EpochUnit,
+    ) -> Result<Value> {
+        let src = Value::String(String::from(v));
+        let parsed = match target_value_type {
+            ValueType::NaiveDateTime => src.try_convert_to_naive_date_time_from_epoch(unit),
+            ValueType::DateTime => src.try_convert_to_date_time_from_epoch(unit),
+            _ => Err(VenumError::Generic {
+                msg: String::from("Not a date time type"),
+            }),
+        };
+        parsed.map_err(|err| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: format!("Value::{target_value_type}"),
+                details: Some(format!("Epoch unit: {unit:?}. Original error: {err:?}")),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use super::*;
+
+    fn ndt(h: u32, m: u32, s: u32, nanos: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 11, 14)
+            .unwrap()
+            .and_hms_nano_opt(h, m, s, nanos)
+            .unwrap()
+    }
+
+    mod from_epoch {
+        use super::*;
+
+        #[test]
+        pub fn units() {
+            let expected = Ok(Value::NaiveDateTime(ndt(22, 13, 20, 0)));
+            assert_eq!(
+                expected,
+                Value::Int64(1_700_000_000)
+                    .try_convert_to_naive_date_time_from_epoch(&EpochUnit::Seconds)
+            );
+            assert_eq!(
+                expected,
+                Value::UInt64(1_700_000_000_000)
+                    .try_convert_to_naive_date_time_from_epoch(&EpochUnit::Millis)
+            );
+            assert_eq!(
+                expected,
+                Value::Int64(1_700_000_000_000_000)
+                    .try_convert_to_naive_date_time_from_epoch(&EpochUnit::Micros)
+            );
+            assert_eq!(
+                expected,
+                Value::Int64(1_700_000_000_000_000_000)
+                    .try_convert_to_naive_date_time_from_epoch(&EpochUnit::Nanos)
+            );
+        }
+
+        #[test]
+        pub fn fractions() {
+            assert_eq!(
+                Ok(Value::NaiveDateTime(ndt(22, 13, 20, 500_000_000))),
+                Value::Float64(1_700_000_000.5)
+                    .try_convert_to_naive_date_time_from_epoch(&EpochUnit::Seconds)
+            );
+            assert_eq!(
+                Ok(Value::NaiveDateTime(ndt(22, 13, 20, 123_456_789))),
+                Value::Decimal(Decimal::new(1_700_000_000_123_456_789, 9))
+                    .try_convert_to_naive_date_time_from_epoch(&EpochUnit::Seconds)
+            );
+            assert_eq!(
+                Ok(Value::NaiveDateTime(ndt(22, 13, 20, 1_500))),
+                Value::Decimal(Decimal::new(17_000_000_000_000_015, 1))
+                    .try_convert_to_naive_date_time_from_epoch(&EpochUnit::Micros)
+            );
+        }
+
+        #[test]
+        pub fn before_epoch() {
+            assert_eq!(
+                Ok(Value::NaiveDateTime(
+                    NaiveDate::from_ymd_opt(1969, 12, 31)
+                        .unwrap()
+                        .and_hms_milli_opt(23, 59, 58, 500)
+                        .unwrap()
+                )),
+                Value::Int32(-1500).try_convert_to_naive_date_time_from_epoch(&EpochUnit::Millis)
+            );
+        }
+
+        #[test]
+        pub fn date_time() {
+            assert_eq!(
+                Ok(Value::DateTime(
+                    ndt(22, 13, 20, 0)
+                        .and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                        .unwrap()
+                )),
+                Value::Int64(1_700_000_000)
+                    .try_convert_to_date_time_from_epoch(&EpochUnit::Seconds)
+            );
+        }
+
+        #[test]
+        pub fn err() {
+            assert_eq!(
+                Err(VenumError::Conversion(
+                    ConversionError::NotRepresentableAs {
+                        src: Value::Float64(f64::INFINITY),
+                        target_type: ValueType::NaiveDateTime,
+                    }
+                )),
+                Value::Float64(f64::INFINITY)
+                    .try_convert_to_naive_date_time_from_epoch(&EpochUnit::Seconds)
+            );
+            assert!(Value::Float64(f64::NAN)
+                .try_convert_to_naive_date_time_from_epoch(&EpochUnit::Seconds)
+                .is_err());
+            assert!(Value::Int64(i64::MAX)
+                .try_convert_to_date_time_from_epoch(&EpochUnit::Seconds)
+                .is_err());
+            assert!(Value::Bool(true)
+                .try_convert_to_date_time_from_epoch(&EpochUnit::Seconds)
+                .is_err());
+            assert_eq!(
+                Ok(Value::Null(ValueType::DateTime)),
+                Value::Null(ValueType::Int64)
+                    .try_convert_to_date_time_from_epoch(&EpochUnit::Seconds)
+            );
+        }
+    }
+
+    mod to_epoch {
+        use super::*;
+
+        #[test]
+        pub fn units() {
+            let v = Value::NaiveDateTime(ndt(22, 13, 20, 123_456_789));
+            assert_eq!(
+                Ok(Value::Int64(1_700_000_000)),
+                v.try_convert_to_epoch(&ValueType::Int64, &EpochUnit::Seconds)
+            );
+            assert_eq!(
+                Ok(Value::UInt64(1_700_000_000_123)),
+                v.try_convert_to_epoch(&ValueType::UInt64, &EpochUnit::Millis)
+            );
+            assert_eq!(
+                Ok(Value::Int64(1_700_000_000_123_456)),
+                v.try_convert_to_epoch(&ValueType::Int64, &EpochUnit::Micros)
+            );
+            assert_eq!(
+                Ok(Value::Int64(1_700_000_000_123_456_789)),
+                v.try_convert_to_epoch(&ValueType::Int64, &EpochUnit::Nanos)
+            );
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::new(1_700_000_000_123_456_789, 9))),
+                v.try_convert_to_epoch(&ValueType::Decimal, &EpochUnit::Seconds)
+            );
+            assert_eq!(
+                Ok(Value::Float64(1_700_000_000_123.456_8)),
+                v.try_convert_to_epoch(&ValueType::Float64, &EpochUnit::Millis)
+            );
+        }
+
+        #[test]
+        pub fn date_time_with_offset() {
+            let v = Value::DateTime(
+                ndt(23, 13, 20, 0)
+                    .and_local_timezone(FixedOffset::east_opt(3600).unwrap())
+                    .unwrap(),
+            );
+            assert_eq!(
+                Ok(Value::Int64(1_700_000_000)),
+                v.try_convert_to_epoch(&ValueType::Int64, &EpochUnit::Seconds)
+            );
+        }
+
+        #[test]
+        pub fn round_trip_before_epoch() {
+            let v = Value::Int64(-1500);
+            let ndt = v
+                .try_convert_to_naive_date_time_from_epoch(&EpochUnit::Millis)
+                .unwrap();
+            assert_eq!(
+                Ok(v),
+                ndt.try_convert_to_epoch(&ValueType::Int64, &EpochUnit::Millis)
+            );
+            // floored
+            assert_eq!(
+                Ok(Value::Int64(-2)),
+                ndt.try_convert_to_epoch(&ValueType::Int64, &EpochUnit::Seconds)
+            );
+        }
+
+        #[test]
+        pub fn err() {
+            let v = Value::NaiveDateTime(ndt(22, 13, 20, 0));
+            assert_eq!(
+                Err(VenumError::Conversion(
+                    ConversionError::NotRepresentableAs {
+                        src: v.clone(),
+                        target_type: ValueType::Int32,
+                    }
+                )),
+                v.try_convert_to_epoch(&ValueType::Int32, &EpochUnit::Millis)
+            );
+            assert!(v
+                .try_convert_to_epoch(&ValueType::String, &EpochUnit::Seconds)
+                .is_err());
+            assert!(Value::Int64(1)
+                .try_convert_to_epoch(&ValueType::Int64, &EpochUnit::Seconds)
+                .is_err());
+        }
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        pub fn parse_date_time_from_str_epoch() {
+            assert_eq!(
+                Ok(Value::NaiveDateTime(ndt(22, 13, 20, 0))),
+                Value::parse_date_time_from_str_epoch(
+                    "1700000000000",
+                    &ValueType::NaiveDateTime,
+                    &EpochUnit::Millis
+                )
+            );
+            assert_eq!(
+                Err(VenumError::Parsing(ParseError::ValueFromStringFailed {
+                    src_value: String::from("abc"),
+                    target_type: String::from("Value::NaiveDateTime"),
+                    details: Some(String::from(
+                        r#"Epoch unit: Seconds. Original error: Conversion(NotRepresentableAs { src: String("abc"), target_type: NaiveDateTime })"#
+                    )),
+                })),
+                Value::parse_date_time_from_str_epoch(
+                    "abc",
+                    &ValueType::NaiveDateTime,
+                    &EpochUnit::Seconds
+                )
+            );
+            assert!(Value::parse_date_time_from_str_epoch(
+                "1700000000",
+                &ValueType::NaiveDate,
+                &EpochUnit::Seconds
+            )
+            .is_err());
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use std::collections::BTreeMap;
+
+use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{DeserializeOwned, IntoDeserializer, Visitor};
+use serde::Deserializer;
+
+use crate::errors_result::{Result, VenumError};
+use crate::value::Value;
+use crate::value_serde::as_string;
+use crate::value_type::ValueType;
+
+/// Builds a `T` from a value, e.g. a `#[derive(Deserialize)]` struct from a `Value::Map`.
+/// See: `impl Deserializer for Value`
+pub fn from_value<T: DeserializeOwned>(v: Value) -> Result<T> {
+    T::deserialize(v)
+}
+
+/// Builds a `T` from a list of values, e.g. a struct from a record, where the values are in the order of the fields.
+pub fn from_list<T: DeserializeOwned>(v: Vec<Value>) -> Result<T> {
+    T::deserialize(Value::List(v))
+}
+
+/// Builds a `T` from a map of values, e.g. a struct from a record, where the keys are the field names.
+pub fn from_map<T: DeserializeOwned>(v: BTreeMap<String, Value>) -> Result<T> {
+    T::deserialize(Value::Map(v))
+}
+
+// Numbers, bools and chars are coerced through `try_convert_to`, i.e. a `Value::String("1")` or a `Value::Float64(1.0)`
+// can become an i8, but a `Value::Int32(300)` can't. Nulls are handed to the visitor as unit, which fails for
+// everything but Option and ().
+macro_rules! deserialize_converted {
+    ($fn_name:ident, $visit_fn:ident, $value_type:ident, $type:ty) => {
+        fn $fn_name<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+            if self.is_none() {
+                return visitor.visit_unit();
+            }
+            let converted: $type = self.try_convert_to(&ValueType::$value_type)?.try_into()?;
+            visitor.$visit_fn(converted)
+        }
+    };
+}
+
+/// Lets us use a `Value` as input for serde, i.e. `T::deserialize(value)`:
+/// - the numeric (and bool, char) deserialize functions coerce via `try_convert_to`
+/// - lists are sequences (bytes, too), maps are maps. Structs can be built from both.
+/// - `Value::None` and typed nulls are unit, or None for Option
+/// - decimals, dates, times, durations and uuids are strings (with full precision), bytes are bytes
+/// - enums are either strings (unit variants) or maps with one entry, i.e. `{"Variant": value}`
+impl<'de> Deserializer<'de> for Value {
+    type Error = VenumError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self {
+            Value::None | Value::Null(_) => visitor.visit_unit(),
+            Value::Char(v) => visitor.visit_char(v),
+            Value::String(v) => visitor.visit_string(v),
+            Value::Int8(v) => visitor.visit_i8(v),
+            Value::Int16(v) => visitor.visit_i16(v),
+            Value::Int32(v) => visitor.visit_i32(v),
+            Value::Int64(v) => visitor.visit_i64(v),
+            Value::Int128(v) => visitor.visit_i128(v),
+            Value::UInt8(v) => visitor.visit_u8(v),
+            Value::UInt16(v) => visitor.visit_u16(v),
+            Value::UInt32(v) => visitor.visit_u32(v),
+            Value::UInt64(v) => visitor.visit_u64(v),
+            Value::UInt128(v) => visitor.visit_u128(v),
+            Value::Float32(v) => visitor.visit_f32(v),
+            Value::Float64(v) => visitor.visit_f64(v),
+            Value::Bool(v) => visitor.visit_bool(v),
+            Value::Bytes(v) => visitor.visit_byte_buf(v),
+            Value::List(v) => visitor.visit_seq(SeqDeserializer::new(v.into_iter())),
+            Value::Map(v) => visitor.visit_map(MapDeserializer::new(v.into_iter())),
+            _ => match as_string(&self) {
+                Some(s) => visitor.visit_string(s),
+                None => unreachable!("all other variants are handled above"),
+            },
+        }
+    }
+
+    deserialize_converted!(deserialize_bool, visit_bool, Bool, bool);
+    deserialize_converted!(deserialize_i8, visit_i8, Int8, i8);
+    deserialize_converted!(deserialize_i16, visit_i16, Int16, i16);
+    deserialize_converted!(deserialize_i32, visit_i32, Int32, i32);
+    deserialize_converted!(deserialize_i64, visit_i64, Int64, i64);
+    deserialize_converted!(deserialize_i128, visit_i128, Int128, i128);
+    deserialize_converted!(deserialize_u8, visit_u8, UInt8, u8);
+    deserialize_converted!(deserialize_u16, visit_u16, UInt16, u16);
+    deserialize_converted!(deserialize_u32, visit_u32, UInt32, u32);
+    deserialize_converted!(deserialize_u64, visit_u64, UInt64, u64);
+    deserialize_converted!(deserialize_u128, visit_u128, UInt128, u128);
+    deserialize_converted!(deserialize_f32, visit_f32, Float32, f32);
+    deserialize_converted!(deserialize_f64, visit_f64, Float64, f64);
+    deserialize_converted!(deserialize_char, visit_char, Char, char);
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self {
+            Value::String(v) => visitor.visit_string(v),
+            Value::Char(_)
+            | Value::Int8(_)
+            | Value::Int16(_)
+            | Value::Int32(_)
+            | Value::Int64(_)
+            | Value::Int128(_)
+            | Value::UInt8(_)
+            | Value::UInt16(_)
+            | Value::UInt32(_)
+            | Value::UInt64(_)
+            | Value::UInt128(_)
+            | Value::Float32(_)
+            | Value::Float64(_)
+            | Value::Bool(_) => visitor.visit_string(self.try_convert_to_string()?.try_into()?),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self {
+            Value::String(v) => visitor.visit_byte_buf(v.into_bytes()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.is_none() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self {
+            // i.e. for Vec<u8>, which is deserialized as a sequence
+            Value::Bytes(v) => visitor.visit_seq(SeqDeserializer::new(v.into_iter())),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self {
+            Value::String(v) => visitor.visit_enum(v.into_deserializer()),
+            Value::Map(v) if v.len() == 1 => visitor.visit_enum(MapAccessDeserializer::new(
+                MapDeserializer::new(v.into_iter()),
+            )),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+}
+
+impl<'de> IntoDeserializer<'de, VenumError> for Value {
+    type Deserializer = Value;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    enum Kind {
+        Small,
+        Big(u32),
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Record {
+        id: u64,
+        name: String,
+        score: f64,
+        active: bool,
+        date: NaiveDate,
+        comment: Option<String>,
+        tags: Vec<String>,
+        kind: Kind,
+    }
+
+    fn record_values() -> Vec<Value> {
+        vec![
+            Value::Int32(1),
+            Value::String(String::from("a")),
+            Value::Int8(5),
+            Value::Bool(true),
+            Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap()),
+            Value::Null(ValueType::String),
+            Value::List(vec![Value::String(String::from("x"))]),
+            Value::String(String::from("Small")),
+        ]
+    }
+
+    fn record() -> Record {
+        Record {
+            id: 1,
+            name: String::from("a"),
+            score: 5.0,
+            active: true,
+            date: NaiveDate::from_ymd_opt(2022, 12, 31).unwrap(),
+            comment: None,
+            tags: vec![String::from("x")],
+            kind: Kind::Small,
+        }
+    }
+
+    #[test]
+    pub fn struct_from_list() {
+        assert_eq!(Ok(record()), from_list::<Record>(record_values()));
+    }
+
+    #[test]
+    pub fn struct_from_map() {
+        let fields = [
+            "id", "name", "score", "active", "date", "comment", "tags", "kind",
+        ];
+        let map = fields
+            .iter()
+            .map(|f| String::from(*f))
+            .zip(record_values())
+            .collect::<BTreeMap<String, Value>>();
+        assert_eq!(Ok(record()), from_map::<Record>(map));
+    }
+
+    #[test]
+    pub fn coercion() {
+        assert_eq!(Ok(1u8), from_value::<u8>(Value::String(String::from("1"))));
+        assert_eq!(Ok(1i32), from_value::<i32>(Value::Float64(1.0)));
+        assert_eq!(Ok(u128::MAX), from_value::<u128>(Value::UInt128(u128::MAX)));
+        assert_eq!(Ok(String::from("5")), from_value::<String>(Value::Int8(5)));
+        assert_eq!(
+            Ok(String::from("1.10")),
+            from_value::<String>(Value::Decimal(Decimal::new(110, 2)))
+        );
+        assert_eq!(
+            Ok(vec![0xca, 0xfe]),
+            from_value::<Vec<u8>>(Value::Bytes(vec![0xca, 0xfe]))
+        );
+        assert_eq!(
+            Ok(Kind::Big(5)),
+            from_value::<Kind>(Value::Map(BTreeMap::from([(
+                String::from("Big"),
+                Value::Int8(5)
+            )])))
+        );
+        assert_eq!(Ok(Some(1i8)), from_value::<Option<i8>>(Value::Int64(1)));
+        assert_eq!(Ok(None), from_value::<Option<i8>>(Value::None));
+    }
+
+    #[test]
+    pub fn errors() {
+        assert!(matches!(
+            from_value::<u8>(Value::Int32(300)),
+            Err(VenumError::Conversion(_))
+        ));
+        assert!(matches!(
+            from_value::<u8>(Value::Null(ValueType::UInt8)),
+            Err(VenumError::Generic { .. })
+        ));
+        assert!(matches!(
+            from_list::<Record>(vec![Value::Int32(1)]),
+            Err(VenumError::Generic { .. })
+        ));
+    }
+}
@@ -1,7 +1,8 @@
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 use rust_decimal::Decimal;
 
 use crate::value::Value;
+use std::collections::{BTreeMap, HashMap};
 use std::convert::From;
 
 macro_rules! impl_from_type_for_value {
@@ -32,6 +33,60 @@ impl_from_type_for_value!(Decimal, Decimal);
 impl_from_type_for_value!(NaiveDate, NaiveDate);
 impl_from_type_for_value!(NaiveDateTime, NaiveDateTime);
 impl_from_type_for_value!(DateTime, DateTime<FixedOffset>);
+impl_from_type_for_value!(NaiveTime, NaiveTime);
+impl_from_type_for_value!(Duration, Duration);
+impl_from_type_for_value!(List, Vec<Value>);
+// NOTE: Vec<u8> is Value::Bytes, not a Value::List of UInt8s (there's no impl_from_vec_of_type_for_value!(u8))
+impl_from_type_for_value!(Bytes, Vec<u8>);
+#[cfg(feature = "uuid")]
+impl_from_type_for_value!(Uuid, uuid::Uuid);
+
+// Every element becomes a Value on its own, i.e. vec![1i8, 2i8] becomes List([Int8(1), Int8(2)])
+macro_rules! impl_from_vec_of_type_for_value {
+    ($from_type:ty) => {
+        impl From<Vec<$from_type>> for Value {
+            fn from(item: Vec<$from_type>) -> Self {
+                Value::List(item.into_iter().map(Value::from).collect())
+            }
+        }
+    };
+}
+impl_from_vec_of_type_for_value!(char);
+impl_from_vec_of_type_for_value!(String);
+impl_from_vec_of_type_for_value!(i8);
+impl_from_vec_of_type_for_value!(i16);
+impl_from_vec_of_type_for_value!(i32);
+impl_from_vec_of_type_for_value!(i64);
+impl_from_vec_of_type_for_value!(i128);
+impl_from_vec_of_type_for_value!(u16);
+impl_from_vec_of_type_for_value!(u32);
+impl_from_vec_of_type_for_value!(u64);
+impl_from_vec_of_type_for_value!(u128);
+impl_from_vec_of_type_for_value!(f32);
+impl_from_vec_of_type_for_value!(f64);
+impl_from_vec_of_type_for_value!(bool);
+impl_from_vec_of_type_for_value!(Decimal);
+impl_from_vec_of_type_for_value!(NaiveDate);
+impl_from_vec_of_type_for_value!(NaiveDateTime);
+impl_from_vec_of_type_for_value!(DateTime<FixedOffset>);
+impl_from_vec_of_type_for_value!(NaiveTime);
+impl_from_vec_of_type_for_value!(Duration);
+#[cfg(feature = "uuid")]
+impl_from_vec_of_type_for_value!(uuid::Uuid);
+
+// Every value becomes a Value on its own, i.e. {"a": 1i8} becomes Map({"a": Int8(1)})
+impl<T: Into<Value>> From<BTreeMap<String, T>> for Value {
+    fn from(item: BTreeMap<String, T>) -> Self {
+        Value::Map(item.into_iter().map(|(k, v)| (k, v.into())).collect())
+    }
+}
+
+// NOTE: The entries end up ordered by key, as Value::Map is a BTreeMap
+impl<T: Into<Value>> From<HashMap<String, T>> for Value {
+    fn from(item: HashMap<String, T>) -> Self {
+        Value::Map(item.into_iter().map(|(k, v)| (k, v.into())).collect())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -100,4 +155,70 @@ mod tests {
 
         assert_eq!(Value::DateTime(d), Value::from(d));
     }
+
+    #[test]
+    pub fn impl_from_bytes_for_value() {
+        assert_eq!(
+            Value::Bytes(vec![0xca, 0xfe]),
+            Value::from(vec![0xcau8, 0xfe])
+        );
+        // not a list of UInt8s (that needs the values)
+        assert_ne!(
+            Value::List(vec![Value::UInt8(0xca), Value::UInt8(0xfe)]),
+            Value::from(vec![0xcau8, 0xfe])
+        );
+        assert_eq!(
+            Value::List(vec![Value::UInt8(0xca), Value::UInt8(0xfe)]),
+            Value::from(vec![Value::from(0xcau8), Value::from(0xfeu8)])
+        );
+    }
+
+    #[test]
+    pub fn impl_from_vec_of_type_for_value() {
+        assert_eq!(Value::List(vec![]), Value::from(Vec::<i8>::new()));
+        assert_eq!(
+            Value::List(vec![Value::Int8(1), Value::Int8(2)]),
+            Value::from(vec![1i8, 2i8])
+        );
+        assert_eq!(
+            Value::List(vec![
+                Value::String(String::from("a")),
+                Value::String(String::from("b"))
+            ]),
+            Value::from(vec![String::from("a"), String::from("b")])
+        );
+        assert_eq!(
+            Value::List(vec![Value::Bool(true), Value::None]),
+            Value::from(vec![Value::Bool(true), Value::None])
+        );
+    }
+
+    #[test]
+    pub fn impl_from_map_of_type_for_value() {
+        let exp = Value::Map(BTreeMap::from([
+            (String::from("a"), Value::Int32(1)),
+            (String::from("b"), Value::Int32(2)),
+        ]));
+        assert_eq!(
+            exp,
+            Value::from(BTreeMap::from([
+                (String::from("b"), 2i32),
+                (String::from("a"), 1i32)
+            ]))
+        );
+        assert_eq!(
+            exp,
+            Value::from(HashMap::from([
+                (String::from("b"), 2i32),
+                (String::from("a"), 1i32)
+            ]))
+        );
+        assert_eq!(
+            Value::Map(BTreeMap::from([(
+                String::from("a"),
+                Value::List(vec![Value::Bool(true)])
+            )])),
+            Value::from(BTreeMap::from([(String::from("a"), vec![true])]))
+        );
+    }
 }
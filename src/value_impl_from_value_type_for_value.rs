@@ -23,6 +23,13 @@ impl From<&ValueType> for Value {
             ValueType::NaiveDate => Value::naive_date_default(),
             ValueType::NaiveDateTime => Value::naive_date_time_default(),
             ValueType::DateTime => Value::date_time_default(),
+            ValueType::NaiveTime => Value::naive_time_default(),
+            ValueType::Duration => Value::duration_default(),
+            ValueType::List(_) => Value::list_default(),
+            ValueType::Map => Value::map_default(),
+            ValueType::Bytes => Value::bytes_default(),
+            #[cfg(feature = "uuid")]
+            ValueType::Uuid => Value::uuid_default(),
         }
     }
 }
@@ -61,6 +68,16 @@ mod tests {
             ValueType::NaiveDateTime.into()
         );
         assert_eq!(Value::date_time_default(), ValueType::DateTime.into());
+        assert_eq!(Value::naive_time_default(), ValueType::NaiveTime.into());
+        assert_eq!(Value::duration_default(), ValueType::Duration.into());
+        assert_eq!(
+            Value::list_default(),
+            ValueType::List(Box::new(ValueType::Int8)).into()
+        );
+        assert_eq!(Value::map_default(), ValueType::Map.into());
+        assert_eq!(Value::bytes_default(), ValueType::Bytes.into());
+        #[cfg(feature = "uuid")]
+        assert_eq!(Value::uuid_default(), ValueType::Uuid.into());
     }
 
     #[test]
@@ -0,0 +1,378 @@
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+use crate::errors_result::Result;
+use crate::value::Value;
+use crate::value_arithmetic::{arith, ArithmeticOp, OverflowMode};
+
+// All operators promote both operands to a common numeric type first (see: `ValueType::numeric_supertype`)
+// and return an error instead of panicking, on overflow, division by zero or non-numeric operands.
+// NOTE: Division by zero is an error for floats as well, instead of becoming 'inf' or 'NaN'.
+macro_rules! impl_op_for_value {
+    ($op_trait:ident, $fn_name:ident, $op:expr) => {
+        impl $op_trait for Value {
+            type Output = Result<Value>;
+            fn $fn_name(self, rhs: Value) -> Result<Value> {
+                arith($op, OverflowMode::Checked, &self, &rhs)
+            }
+        }
+
+        impl $op_trait<&Value> for &Value {
+            type Output = Result<Value>;
+            fn $fn_name(self, rhs: &Value) -> Result<Value> {
+                arith($op, OverflowMode::Checked, self, rhs)
+            }
+        }
+    };
+}
+impl_op_for_value!(Add, add, ArithmeticOp::Add);
+impl_op_for_value!(Sub, sub, ArithmeticOp::Sub);
+impl_op_for_value!(Mul, mul, ArithmeticOp::Mul);
+impl_op_for_value!(Div, div, ArithmeticOp::Div);
+impl_op_for_value!(Rem, rem, ArithmeticOp::Rem);
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, FixedOffset, NaiveDate, NaiveTime};
+    use rust_decimal::Decimal;
+
+    use crate::errors_result::{ArithmeticError, VenumError};
+    use crate::value_type::ValueType;
+
+    use super::*;
+
+    #[test]
+    pub fn same_types() {
+        assert_eq!(Ok(Value::Int8(3)), Value::Int8(1) + Value::Int8(2));
+        assert_eq!(Ok(Value::UInt64(1)), Value::UInt64(3) - Value::UInt64(2));
+        assert_eq!(Ok(Value::Int32(-6)), Value::Int32(2) * Value::Int32(-3));
+        assert_eq!(Ok(Value::Int16(3)), Value::Int16(7) / Value::Int16(2));
+        assert_eq!(Ok(Value::UInt128(1)), Value::UInt128(7) % Value::UInt128(2));
+        assert_eq!(
+            Ok(Value::Float64(3.5)),
+            Value::Float64(7.0) / Value::Float64(2.0)
+        );
+        assert_eq!(
+            Ok(Value::Decimal(Decimal::new(35, 1))),
+            Value::Decimal(Decimal::new(7, 0)) / Value::Decimal(Decimal::new(2, 0))
+        );
+    }
+
+    #[test]
+    pub fn by_ref() {
+        let a = Value::Int64(40);
+        let b = Value::Int64(2);
+        assert_eq!(Ok(Value::Int64(42)), &a + &b);
+        assert_eq!(Ok(Value::Int64(38)), &a - &b);
+        assert_eq!(Ok(Value::Int64(80)), &a * &b);
+        assert_eq!(Ok(Value::Int64(20)), &a / &b);
+        assert_eq!(Ok(Value::Int64(0)), &a % &b);
+    }
+
+    #[test]
+    pub fn promotion() {
+        assert_eq!(Ok(Value::Int64(3)), Value::Int8(1) + Value::Int64(2));
+        assert_eq!(Ok(Value::Int16(-1)), Value::UInt8(1) - Value::Int8(2));
+        assert_eq!(
+            Ok(Value::Int128(i64::MAX as i128 + 1)),
+            Value::Int64(i64::MAX) + Value::UInt64(1)
+        );
+        assert_eq!(
+            Ok(Value::Float32(3.5)),
+            Value::Int16(3) + Value::Float32(0.5)
+        );
+        assert_eq!(
+            Ok(Value::Float64(3.5)),
+            Value::Int32(3) + Value::Float32(0.5)
+        );
+        assert_eq!(
+            Ok(Value::Decimal(Decimal::new(35, 1))),
+            Value::Float64(0.5) + Value::Decimal(Decimal::new(3, 0))
+        );
+        assert_eq!(
+            Ok(Value::Decimal(Decimal::new(6, 0))),
+            Value::UInt64(3) * Value::Decimal(Decimal::new(2, 0))
+        );
+    }
+
+    #[test]
+    pub fn overflow() {
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::Overflow {
+                op: String::from("+"),
+                lhs: Value::Int8(i8::MAX),
+                rhs: Value::Int8(1),
+                target_type: ValueType::Int8
+            })),
+            Value::Int8(i8::MAX) + Value::Int8(1)
+        );
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::Overflow {
+                op: String::from("-"),
+                lhs: Value::UInt8(0),
+                rhs: Value::UInt16(1),
+                target_type: ValueType::UInt16
+            })),
+            Value::UInt8(0) - Value::UInt16(1)
+        );
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::Overflow {
+                op: String::from("*"),
+                lhs: Value::Float64(f64::MAX),
+                rhs: Value::Float64(2.0),
+                target_type: ValueType::Float64
+            })),
+            Value::Float64(f64::MAX) * Value::Float64(2.0)
+        );
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::Overflow {
+                op: String::from("/"),
+                lhs: Value::Int32(i32::MIN),
+                rhs: Value::Int32(-1),
+                target_type: ValueType::Int32
+            })),
+            Value::Int32(i32::MIN) / Value::Int32(-1)
+        );
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::Overflow {
+                op: String::from("+"),
+                lhs: Value::Decimal(Decimal::MAX),
+                rhs: Value::Decimal(Decimal::MAX),
+                target_type: ValueType::Decimal
+            })),
+            Value::Decimal(Decimal::MAX) + Value::Decimal(Decimal::MAX)
+        );
+    }
+
+    #[test]
+    pub fn non_finite_operands_are_no_overflow() {
+        assert_eq!(
+            Ok(Value::Float64(f64::INFINITY)),
+            Value::Float64(f64::INFINITY) + Value::Float64(1.0)
+        );
+    }
+
+    #[test]
+    pub fn division_by_zero() {
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::DivisionByZero {
+                op: String::from("/"),
+                lhs: Value::Int8(1),
+                rhs: Value::Int8(0),
+            })),
+            Value::Int8(1) / Value::Int8(0)
+        );
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::DivisionByZero {
+                op: String::from("%"),
+                lhs: Value::UInt32(1),
+                rhs: Value::Int64(0),
+            })),
+            Value::UInt32(1) % Value::Int64(0)
+        );
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::DivisionByZero {
+                op: String::from("/"),
+                lhs: Value::Float32(1.0),
+                rhs: Value::Float32(0.0),
+            })),
+            Value::Float32(1.0) / Value::Float32(0.0)
+        );
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::DivisionByZero {
+                op: String::from("/"),
+                lhs: Value::Decimal(Decimal::ONE),
+                rhs: Value::Decimal(Decimal::ZERO),
+            })),
+            Value::Decimal(Decimal::ONE) / Value::Decimal(Decimal::ZERO)
+        );
+    }
+
+    #[test]
+    pub fn unsupported_operands() {
+        assert_eq!(
+            Err(VenumError::Arithmetic(
+                ArithmeticError::UnsupportedOperands {
+                    op: String::from("+"),
+                    lhs: Value::Bool(true),
+                    rhs: Value::Int8(1),
+                }
+            )),
+            Value::Bool(true) + Value::Int8(1)
+        );
+        let d = Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap());
+        assert_eq!(
+            Err(VenumError::Arithmetic(
+                ArithmeticError::UnsupportedOperands {
+                    op: String::from("*"),
+                    lhs: d.clone(),
+                    rhs: d.clone(),
+                }
+            )),
+            &d * &d
+        );
+        assert_eq!(
+            Err(VenumError::Arithmetic(
+                ArithmeticError::UnsupportedOperands {
+                    op: String::from("-"),
+                    lhs: Value::None,
+                    rhs: Value::Int8(1),
+                }
+            )),
+            Value::None - Value::Int8(1)
+        );
+        assert_eq!(
+            Err(VenumError::Arithmetic(
+                ArithmeticError::UnsupportedOperands {
+                    op: String::from("+"),
+                    lhs: Value::String(String::from("1")),
+                    rhs: Value::String(String::from("1")),
+                }
+            )),
+            Value::String(String::from("1")) + Value::String(String::from("1"))
+        );
+    }
+
+    #[test]
+    pub fn temporal() {
+        let d1 = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+        let d2 = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+        let dt1 = d1.and_hms_opt(12, 0, 0).unwrap();
+        let dt2 = d2.and_hms_opt(13, 30, 0).unwrap();
+        let one_hour = Duration::try_hours(1).unwrap();
+
+        assert_eq!(
+            Ok(Value::Duration(Duration::try_days(2).unwrap())),
+            Value::NaiveDate(d2) - Value::NaiveDate(d1)
+        );
+        assert_eq!(
+            Ok(Value::Duration(
+                Duration::try_minutes(-(49 * 60 + 30)).unwrap()
+            )),
+            Value::NaiveDateTime(dt1) - Value::NaiveDateTime(dt2)
+        );
+        assert_eq!(
+            Ok(Value::Duration(one_hour)),
+            Value::DateTime(
+                dt1.and_local_timezone(FixedOffset::east_opt(0).unwrap())
+                    .unwrap()
+            ) - Value::DateTime(
+                dt1.and_local_timezone(FixedOffset::east_opt(3600).unwrap())
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            Ok(Value::Duration(Duration::try_minutes(90).unwrap())),
+            Value::NaiveTime(NaiveTime::from_hms_opt(10, 0, 0).unwrap())
+                - Value::NaiveTime(NaiveTime::from_hms_opt(8, 30, 0).unwrap())
+        );
+        assert_eq!(
+            Ok(Value::NaiveDateTime(d1.and_hms_opt(13, 0, 0).unwrap())),
+            Value::NaiveDateTime(dt1) + Value::Duration(one_hour)
+        );
+        assert_eq!(
+            Ok(Value::NaiveDateTime(d1.and_hms_opt(11, 0, 0).unwrap())),
+            Value::NaiveDateTime(dt1) - Value::Duration(one_hour)
+        );
+        assert_eq!(
+            Ok(Value::NaiveDate(d2)),
+            Value::Duration(Duration::try_hours(50).unwrap()) + Value::NaiveDate(d1)
+        );
+        assert_eq!(
+            Ok(Value::Duration(Duration::zero())),
+            Value::Duration(one_hour) - Value::Duration(one_hour)
+        );
+    }
+
+    #[test]
+    pub fn temporal_overflow() {
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::Overflow {
+                op: String::from("+"),
+                lhs: Value::NaiveDate(NaiveDate::MAX),
+                rhs: Value::Duration(Duration::try_days(1).unwrap()),
+                target_type: ValueType::NaiveDate
+            })),
+            Value::NaiveDate(NaiveDate::MAX) + Value::Duration(Duration::try_days(1).unwrap())
+        );
+    }
+
+    #[test]
+    pub fn temporal_unsupported_operands() {
+        let d = Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap());
+        let dur = Value::Duration(Duration::try_days(1).unwrap());
+        assert_eq!(
+            Err(VenumError::Arithmetic(
+                ArithmeticError::UnsupportedOperands {
+                    op: String::from("+"),
+                    lhs: d.clone(),
+                    rhs: d.clone(),
+                }
+            )),
+            &d + &d
+        );
+        assert_eq!(
+            Err(VenumError::Arithmetic(
+                ArithmeticError::UnsupportedOperands {
+                    op: String::from("-"),
+                    lhs: dur.clone(),
+                    rhs: d.clone(),
+                }
+            )),
+            &dur - &d
+        );
+        assert_eq!(
+            Err(VenumError::Arithmetic(
+                ArithmeticError::UnsupportedOperands {
+                    op: String::from("+"),
+                    lhs: dur.clone(),
+                    rhs: dur.clone(),
+                }
+            )),
+            dur.wrapping_add(&dur)
+        );
+    }
+
+    #[test]
+    pub fn promotion_not_representable() {
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::Overflow {
+                op: String::from("+"),
+                lhs: Value::UInt128(u128::MAX),
+                rhs: Value::Int8(1),
+                target_type: ValueType::Int128
+            })),
+            Value::UInt128(u128::MAX) + Value::Int8(1)
+        );
+    }
+
+    #[test]
+    pub fn promotion_to_float_is_exact() {
+        assert_eq!(
+            Ok(Value::Float64(3.5)),
+            Value::Int64(3) + Value::Float64(0.5)
+        );
+        assert_eq!(
+            Ok(Value::Float32(-128.5)),
+            Value::Int8(i8::MIN) + Value::Float32(-0.5)
+        );
+        // i64::MAX would be rounded to 2^63
+        assert_eq!(
+            Err(VenumError::Arithmetic(ArithmeticError::Overflow {
+                op: String::from("+"),
+                lhs: Value::Int64(i64::MAX),
+                rhs: Value::Float64(1.0),
+                target_type: ValueType::Float64
+            })),
+            Value::Int64(i64::MAX) + Value::Float64(1.0)
+        );
+        // 2^53 + 1 isn't a Float64
+        assert!(matches!(
+            Value::Int64(9_007_199_254_740_993) + Value::Float64(0.0),
+            Err(VenumError::Arithmetic(ArithmeticError::Overflow { .. }))
+        ));
+        assert!(matches!(
+            Value::UInt128(u128::MAX) - Value::Float64(0.0),
+            Err(VenumError::Arithmetic(ArithmeticError::Overflow { .. }))
+        ));
+    }
+}
@@ -1,9 +1,11 @@
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 use rust_decimal::Decimal;
 
 use crate::errors_result::{ConversionError, Result, VenumError};
 use crate::value::Value;
+use std::collections::{BTreeMap, HashMap};
 use std::convert::TryFrom;
+use std::fmt::Debug;
 
 const VAL_ENUM_NAME: &str = "Value::";
 
@@ -44,13 +46,20 @@ impl_try_from_value_for_type!(Decimal, Decimal);
 impl_try_from_value_for_type!(NaiveDate, NaiveDate);
 impl_try_from_value_for_type!(NaiveDateTime, NaiveDateTime);
 impl_try_from_value_for_type!(DateTime, DateTime<FixedOffset>);
+impl_try_from_value_for_type!(NaiveTime, NaiveTime);
+impl_try_from_value_for_type!(Duration, Duration);
+impl_try_from_value_for_type!(List, Vec<Value>);
+// NOTE: Vec<u8> comes from Value::Bytes only, not from a Value::List of UInt8s
+impl_try_from_value_for_type!(Bytes, Vec<u8>);
+#[cfg(feature = "uuid")]
+impl_try_from_value_for_type!(Uuid, uuid::Uuid);
 
 impl TryFrom<Value> for String {
     type Error = VenumError;
     fn try_from(item: Value) -> Result<Self> {
         match item {
             Value::String(v) => Ok(v),
-            Value::None => Ok(String::new()),
+            Value::None | Value::Null(_) => Ok(String::new()),
             _ => Err(VenumError::Conversion(ConversionError::WrongType {
                 src_value: format!("{:?}", item),               // i.e. Bool(true)
                 src_type: format!("{}{}", VAL_ENUM_NAME, item), // i.e. Value::Bool, where 'Bool' is generated by strum through the display trait
@@ -61,6 +70,93 @@ impl TryFrom<Value> for String {
     }
 }
 
+// Same as TryFrom<&Value> for Vec<T>, which does the element-wise conversion.
+macro_rules! impl_try_from_value_for_vec_of_type {
+    ($for_type:ty) => {
+        impl TryFrom<Value> for Vec<$for_type> {
+            type Error = VenumError;
+            fn try_from(item: Value) -> Result<Self> {
+                Vec::<$for_type>::try_from(&item)
+            }
+        }
+    };
+}
+impl_try_from_value_for_vec_of_type!(char);
+impl_try_from_value_for_vec_of_type!(String);
+impl_try_from_value_for_vec_of_type!(i8);
+impl_try_from_value_for_vec_of_type!(i16);
+impl_try_from_value_for_vec_of_type!(i32);
+impl_try_from_value_for_vec_of_type!(i64);
+impl_try_from_value_for_vec_of_type!(i128);
+impl_try_from_value_for_vec_of_type!(u16);
+impl_try_from_value_for_vec_of_type!(u32);
+impl_try_from_value_for_vec_of_type!(u64);
+impl_try_from_value_for_vec_of_type!(u128);
+impl_try_from_value_for_vec_of_type!(f32);
+impl_try_from_value_for_vec_of_type!(f64);
+impl_try_from_value_for_vec_of_type!(bool);
+impl_try_from_value_for_vec_of_type!(Decimal);
+impl_try_from_value_for_vec_of_type!(NaiveDate);
+impl_try_from_value_for_vec_of_type!(NaiveDateTime);
+impl_try_from_value_for_vec_of_type!(DateTime<FixedOffset>);
+impl_try_from_value_for_vec_of_type!(NaiveTime);
+impl_try_from_value_for_vec_of_type!(Duration);
+#[cfg(feature = "uuid")]
+impl_try_from_value_for_vec_of_type!(uuid::Uuid);
+
+// Every value is converted on its own, the first one that fails fails the whole conversion.
+fn try_from_map_entries<T, M>(item: Value, target_type: &str) -> Result<M>
+where
+    T: TryFrom<Value>,
+    T::Error: Debug,
+    M: FromIterator<(String, T)>,
+{
+    let src_value = format!("{:?}", item); // i.e. Map({"a": Int8(1)})
+    let src_type = format!("{}{}", VAL_ENUM_NAME, item); // i.e. Value::Map, where 'Map' is generated by strum through the display trait
+    match item {
+        Value::Map(m) => m
+            .into_iter()
+            .map(|(k, v)| T::try_from(v).map(|v| (k, v)))
+            .collect::<std::result::Result<M, T::Error>>()
+            .map_err(|err| {
+                VenumError::Conversion(ConversionError::WrongType {
+                    src_value,
+                    src_type,
+                    target_type: String::from(target_type),
+                    details: Some(format!("{:?}", err)),
+                })
+            }),
+        _ => Err(VenumError::Conversion(ConversionError::WrongType {
+            src_value,
+            src_type,
+            target_type: String::from(target_type),
+            details: None,
+        })),
+    }
+}
+
+impl<T> TryFrom<Value> for BTreeMap<String, T>
+where
+    T: TryFrom<Value>,
+    T::Error: Debug,
+{
+    type Error = VenumError;
+    fn try_from(item: Value) -> Result<Self> {
+        try_from_map_entries(item, "BTreeMap<String, T>")
+    }
+}
+
+impl<T> TryFrom<Value> for HashMap<String, T>
+where
+    T: TryFrom<Value>,
+    T::Error: Debug,
+{
+    type Error = VenumError;
+    fn try_from(item: Value) -> Result<Self> {
+        try_from_map_entries(item, "HashMap<String, T>")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,7 +190,7 @@ mod tests {
         assert_eq!(0.0f32, f32::try_from(Value::Float32(0.0f32)).unwrap());
         assert_eq!(0.0f64, f64::try_from(Value::Float64(0.0f64)).unwrap());
 
-        assert_eq!(true, bool::try_from(Value::Bool(true)).unwrap());
+        assert!(bool::try_from(Value::Bool(true)).unwrap());
 
         assert_eq!(
             Decimal::new(00, 1),
@@ -139,4 +235,99 @@ mod tests {
     pub fn string_to_bool_err() {
         bool::try_from(Value::Int8(0i8)).unwrap();
     }
+
+    #[test]
+    pub fn impl_try_from_value_for_bytes() {
+        assert_eq!(
+            vec![0xcau8, 0xfe],
+            Vec::<u8>::try_from(Value::Bytes(vec![0xca, 0xfe])).unwrap()
+        );
+        assert_eq!(
+            vec![0xcau8, 0xfe],
+            Vec::<u8>::try_from(&Value::Bytes(vec![0xca, 0xfe])).unwrap()
+        );
+        // a list of UInt8s isn't bytes
+        let list = Value::List(vec![Value::UInt8(0xca), Value::UInt8(0xfe)]);
+        assert!(Vec::<u8>::try_from(&list).is_err());
+        assert!(Vec::<u8>::try_from(list).is_err());
+    }
+
+    #[test]
+    pub fn impl_try_from_value_for_vec_of_type() {
+        assert_eq!(
+            vec![1i8, 2i8],
+            Vec::<i8>::try_from(Value::List(vec![Value::Int8(1), Value::Int8(2)])).unwrap()
+        );
+        assert_eq!(
+            vec![String::from("a"), String::new()],
+            Vec::<String>::try_from(Value::List(vec![
+                Value::String(String::from("a")),
+                Value::None
+            ]))
+            .unwrap()
+        );
+        assert_eq!(
+            vec![Value::Int8(1), Value::None],
+            Vec::<Value>::try_from(Value::List(vec![Value::Int8(1), Value::None])).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Conversion(WrongType { src_value: \"List([Int8(1), Int16(2)])\", src_type: \"Value::List\", target_type: \"Vec<i8>\""
+    )]
+    pub fn list_element_to_vec_of_type_err() {
+        Vec::<i8>::try_from(Value::List(vec![Value::Int8(1), Value::Int16(2)])).unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Conversion(WrongType { src_value: \"Int8(1)\", src_type: \"Value::Int8\", target_type: \"Vec<i8>\", details: None })"
+    )]
+    pub fn non_list_to_vec_of_type_err() {
+        Vec::<i8>::try_from(Value::Int8(1)).unwrap();
+    }
+
+    #[test]
+    pub fn impl_try_from_value_for_map_of_type() {
+        let map = Value::Map(BTreeMap::from([
+            (String::from("a"), Value::Int32(1)),
+            (String::from("b"), Value::Int32(2)),
+        ]));
+        assert_eq!(
+            BTreeMap::from([(String::from("a"), 1i32), (String::from("b"), 2i32)]),
+            BTreeMap::<String, i32>::try_from(map.clone()).unwrap()
+        );
+        assert_eq!(
+            HashMap::from([(String::from("a"), 1i32), (String::from("b"), 2i32)]),
+            HashMap::<String, i32>::try_from(map.clone()).unwrap()
+        );
+        assert_eq!(
+            BTreeMap::from([
+                (String::from("a"), Value::Int32(1)),
+                (String::from("b"), Value::Int32(2))
+            ]),
+            BTreeMap::<String, Value>::try_from(map).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Conversion(WrongType { src_value: \"Map({\\\"a\\\": Int32(1)})\", src_type: \"Value::Map\", target_type: \"BTreeMap<String, T>\""
+    )]
+    pub fn map_value_to_map_of_type_err() {
+        BTreeMap::<String, bool>::try_from(Value::Map(BTreeMap::from([(
+            String::from("a"),
+            Value::Int32(1),
+        )])))
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Conversion(WrongType { src_value: \"Int8(1)\", src_type: \"Value::Int8\", target_type: \"HashMap<String, T>\", details: None })"
+    )]
+    pub fn non_map_to_map_of_type_err() {
+        HashMap::<String, i8>::try_from(Value::Int8(1)).unwrap();
+    }
 }
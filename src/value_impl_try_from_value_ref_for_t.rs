@@ -1,4 +1,4 @@
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 use rust_decimal::Decimal;
 use std::convert::TryFrom;
 
@@ -39,6 +39,8 @@ impl_try_from_value_ref_for_copy_type!(UInt128, u128);
 impl_try_from_value_ref_for_copy_type!(Float32, f32);
 impl_try_from_value_ref_for_copy_type!(Float64, f64);
 impl_try_from_value_ref_for_copy_type!(Bool, bool);
+#[cfg(feature = "uuid")]
+impl_try_from_value_ref_for_copy_type!(Uuid, uuid::Uuid);
 
 macro_rules! impl_try_from_value_ref_for_clone_type {
     ($enum_type:ident, $for_type:ty) => {
@@ -63,13 +65,18 @@ impl_try_from_value_ref_for_clone_type!(Decimal, Decimal);
 impl_try_from_value_ref_for_clone_type!(NaiveDate, NaiveDate);
 impl_try_from_value_ref_for_clone_type!(NaiveDateTime, NaiveDateTime);
 impl_try_from_value_ref_for_clone_type!(DateTime, DateTime<FixedOffset>);
+impl_try_from_value_ref_for_clone_type!(NaiveTime, NaiveTime);
+impl_try_from_value_ref_for_clone_type!(Duration, Duration);
+impl_try_from_value_ref_for_clone_type!(List, Vec<Value>);
+// NOTE: Vec<u8> comes from Value::Bytes only, not from a Value::List of UInt8s
+impl_try_from_value_ref_for_clone_type!(Bytes, Vec<u8>);
 
 impl TryFrom<&Value> for String {
     type Error = VenumError;
     fn try_from(item: &Value) -> Result<Self> {
         match item {
             Value::String(v) => Ok(v.clone()),
-            Value::None => Ok(String::new()),
+            Value::None | Value::Null(_) => Ok(String::new()),
             _ => Err(VenumError::Conversion(ConversionError::WrongType {
                 src_value: format!("{:?}", item),               // i.e. Bool(true)
                 src_type: format!("{}{}", VAL_ENUM_NAME, item), // i.e. Value::Bool, where 'Bool' is generated by strum through the display trait
@@ -80,6 +87,59 @@ impl TryFrom<&Value> for String {
     }
 }
 
+// Every element is converted on its own, the first one that fails fails the whole conversion.
+macro_rules! impl_try_from_value_ref_for_vec_of_type {
+    ($for_type:ty) => {
+        impl TryFrom<&Value> for Vec<$for_type> {
+            type Error = VenumError;
+            fn try_from(item: &Value) -> Result<Self> {
+                match item {
+                    Value::List(v) => v
+                        .iter()
+                        .map(<$for_type>::try_from)
+                        .collect::<Result<Vec<$for_type>>>()
+                        .map_err(|err| {
+                            VenumError::Conversion(ConversionError::WrongType {
+                                src_value: format!("{:?}", item),
+                                src_type: format!("{}{}", VAL_ENUM_NAME, item),
+                                target_type: format!("Vec<{}>", stringify!($for_type)),
+                                details: Some(format!("{:?}", err)),
+                            })
+                        }),
+                    _ => Err(VenumError::Conversion(ConversionError::WrongType {
+                        src_value: format!("{:?}", item), // i.e. Bool(true)
+                        src_type: format!("{}{}", VAL_ENUM_NAME, item), // i.e. Value::Bool, where 'Bool' is generated by strum through the display trait
+                        target_type: format!("Vec<{}>", stringify!($for_type)),
+                        details: None,
+                    })),
+                }
+            }
+        }
+    };
+}
+impl_try_from_value_ref_for_vec_of_type!(char);
+impl_try_from_value_ref_for_vec_of_type!(String);
+impl_try_from_value_ref_for_vec_of_type!(i8);
+impl_try_from_value_ref_for_vec_of_type!(i16);
+impl_try_from_value_ref_for_vec_of_type!(i32);
+impl_try_from_value_ref_for_vec_of_type!(i64);
+impl_try_from_value_ref_for_vec_of_type!(i128);
+impl_try_from_value_ref_for_vec_of_type!(u16);
+impl_try_from_value_ref_for_vec_of_type!(u32);
+impl_try_from_value_ref_for_vec_of_type!(u64);
+impl_try_from_value_ref_for_vec_of_type!(u128);
+impl_try_from_value_ref_for_vec_of_type!(f32);
+impl_try_from_value_ref_for_vec_of_type!(f64);
+impl_try_from_value_ref_for_vec_of_type!(bool);
+impl_try_from_value_ref_for_vec_of_type!(Decimal);
+impl_try_from_value_ref_for_vec_of_type!(NaiveDate);
+impl_try_from_value_ref_for_vec_of_type!(NaiveDateTime);
+impl_try_from_value_ref_for_vec_of_type!(DateTime<FixedOffset>);
+impl_try_from_value_ref_for_vec_of_type!(NaiveTime);
+impl_try_from_value_ref_for_vec_of_type!(Duration);
+#[cfg(feature = "uuid")]
+impl_try_from_value_ref_for_vec_of_type!(uuid::Uuid);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,7 +163,7 @@ mod tests {
         assert_eq!(0.0f32, f32::try_from(&Value::Float32(0.0f32)).unwrap());
         assert_eq!(0.0f64, f64::try_from(&Value::Float64(0.0f64)).unwrap());
 
-        assert_eq!(true, bool::try_from(&Value::Bool(true)).unwrap());
+        assert!(bool::try_from(&Value::Bool(true)).unwrap());
     }
 
     #[test]
@@ -0,0 +1,777 @@
+use crate::errors_result::{ParseError, Result, VenumError};
+use crate::value::Value;
+use crate::value_bool_format::BoolFormat;
+use crate::value_date_patterns::{DatePattern, DatePatterns};
+use crate::value_schema::{Column, Schema};
+use crate::value_type::ValueType;
+
+/// How `Value::infer_from_str` guesses the type of a string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InferOptions {
+    /// `None`: the smallest signed int the number fits into, i.e. `Int8` up to `Int128` (or `UInt128` beyond that).
+    /// `Some(t)`: `t`, e.g. `Int64`, as long as the number fits into it, otherwise the smallest one (see above).
+    pub int_type: Option<ValueType>,
+    /// Numbers with a fraction become `Decimal` instead of `Float64`, if they are representable as such.
+    pub prefer_decimal: bool,
+    pub infer_bools: bool,
+    /// Which strings are bools, if `infer_bools` is set. NOTE: Bools are tried before ints, i.e. with "1"/"0" as
+    /// bools, there won't be any `Int8` 1 or 0.
+    pub bool_format: BoolFormat,
+    pub infer_dates: bool,
+    /// The patterns tried for `NaiveDate`, `NaiveDateTime` and `DateTime` (in this order), if `infer_dates` is set.
+    /// NOTE: `NaiveTime`s are not inferred.
+    pub date_patterns: DatePatterns,
+    /// Ignore leading and trailing whitespace
+    pub trim: bool,
+}
+
+impl Default for InferOptions {
+    fn default() -> Self {
+        InferOptions {
+            int_type: None,
+            prefer_decimal: false,
+            infer_bools: true,
+            bool_format: BoolFormat::default(),
+            infer_dates: true,
+            date_patterns: DatePatterns::default(),
+            trim: false,
+        }
+    }
+}
+
+// The smallest first
+const INT_TYPES: [ValueType; 6] = [
+    ValueType::Int8,
+    ValueType::Int16,
+    ValueType::Int32,
+    ValueType::Int64,
+    ValueType::Int128,
+    ValueType::UInt128,
+];
+
+fn infer_int(v: &str, options: &InferOptions) -> Option<Value> {
+    // Quick check, so we don't try all int types for every string
+    let digits = v.strip_prefix(['-', '+']).unwrap_or(v);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    options
+        .int_type
+        .iter()
+        .chain(INT_TYPES.iter())
+        .find_map(|t| Value::from_str_and_type(v, t).ok())
+}
+
+fn infer_fractional(v: &str, options: &InferOptions) -> Option<Value> {
+    if options.prefer_decimal {
+        if let Ok(d) = Value::parse_decimal_from_str(v) {
+            return Some(d);
+        }
+    }
+    // Only finite floats, so we don't end up with "NaN" or "inf" as floats
+    Value::parse_float64_from_str(v).ok()
+}
+
+// Tried in this order
+const DATE_TYPES: [ValueType; 3] = [
+    ValueType::NaiveDate,
+    ValueType::NaiveDateTime,
+    ValueType::DateTime,
+];
+
+// Returns the pattern that matched as well
+fn infer_date<'p>(v: &str, date_patterns: &'p DatePatterns) -> Option<(Value, &'p DatePattern)> {
+    DATE_TYPES
+        .iter()
+        .find_map(|t| date_patterns.parse(v, t).ok())
+}
+
+fn infer_with_date_pattern<'o>(
+    v: &str,
+    options: &'o InferOptions,
+) -> (Value, ValueType, Option<&'o DatePattern>) {
+    let trimmed = if options.trim { v.trim() } else { v };
+    let mut date_pattern = None;
+    let inferred = if trimmed.is_empty() {
+        None
+    } else {
+        None.or_else(|| {
+            if options.infer_bools {
+                Value::parse_bool_from_str_with_format(trimmed, &options.bool_format).ok()
+            } else {
+                None
+            }
+        })
+        .or_else(|| infer_int(trimmed, options))
+        .or_else(|| infer_fractional(trimmed, options))
+        .or_else(|| {
+            if options.infer_dates {
+                infer_date(trimmed, &options.date_patterns).map(|(d, pattern)| {
+                    date_pattern = Some(pattern);
+                    d
+                })
+            } else {
+                None
+            }
+        })
+    };
+    match inferred {
+        Some(value) => {
+            let value_type = ValueType::try_from(&value).expect("inferred values are typed");
+            (value, value_type, date_pattern)
+        }
+        None => (Value::String(String::from(v)), ValueType::String, None),
+    }
+}
+
+// The type that can hold values of both types. Falls back to String, which can hold everything.
+fn widen(current: &ValueType, observed: &ValueType) -> ValueType {
+    if current == observed {
+        return current.clone();
+    }
+    match (current, observed) {
+        // A UInt128 is only inferred for numbers > i128::MAX, so Int128 won't do and Decimal is too small.
+        (ValueType::UInt128, t) | (t, ValueType::UInt128) if t.is_some_signed_int_type() => {
+            ValueType::String
+        }
+        // Same for Int128, which is only inferred for numbers beyond i64, e.g. with 33 digits.
+        // Float64 can hold all of them (not exactly though), like it does without `prefer_decimal`.
+        (ValueType::UInt128 | ValueType::Int128, ValueType::Decimal)
+        | (ValueType::Decimal, ValueType::UInt128 | ValueType::Int128) => ValueType::Float64,
+        // dates become midnight, see: `DatePatterns::parse`
+        (ValueType::NaiveDate, ValueType::NaiveDateTime)
+        | (ValueType::NaiveDateTime, ValueType::NaiveDate) => ValueType::NaiveDateTime,
+        _ => current
+            .numeric_supertype(observed)
+            .unwrap_or(ValueType::String),
+    }
+}
+
+impl Value {
+    /// Guesses the narrowest sensible type of a string, trying (in this order):
+    /// - bool, i.e. "true" or "false" (see: `InferOptions::bool_format`)
+    /// - ints (see: `InferOptions::int_type`)
+    /// - floats / decimals (see: `InferOptions::prefer_decimal`)
+    /// - ISO-8601 dates ("2022-12-31") and date times ("2022-12-31T12:00:00", "2022-12-31T12:00:00.123"),
+    ///   RFC 3339 / RFC 2822 date times with offset ("2022-12-31T12:00:00+01:00"), see: `InferOptions::date_patterns`
+    /// - String, as fallback
+    ///
+    /// Returns the value and its type, i.e. the type that "won".
+    /// NOTE: An empty string is a `Value::String` as well. There is no guessing of nulls here.
+    pub fn infer_from_str(v: &str, options: &InferOptions) -> (Value, ValueType) {
+        let (value, value_type, _) = infer_with_date_pattern(v, options);
+        (value, value_type)
+    }
+}
+
+/// Infers the type of a column from many samples, widening the type as it sees more of them,
+/// e.g. "1", "300", "1.5" go from `Int8` to `Int16` to `Float64` (or `Decimal`, see: `InferOptions::prefer_decimal`).
+/// Everything that doesn't fit together, e.g. a date and an int, or a bool and a float, ends up as `String`.
+/// Empty strings and none-markers (after trimming, see: `InferOptions::trim`) count as nulls and don't change the type.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TypeInferrer {
+    options: InferOptions,
+    none_markers: Vec<String>,
+    value_type: Option<ValueType>,
+    date_patterns: Vec<(ValueType, DatePattern)>,
+    samples: usize,
+    nulls: usize,
+}
+
+impl TypeInferrer {
+    pub fn new(options: InferOptions) -> Self {
+        TypeInferrer {
+            options,
+            ..TypeInferrer::default()
+        }
+    }
+
+    pub fn with_none_markers(mut self, none_markers: &[&str]) -> Self {
+        self.none_markers = none_markers.iter().map(|m| String::from(*m)).collect();
+        self
+    }
+
+    pub fn observe(&mut self, v: &str) {
+        self.samples += 1;
+        let v = if self.options.trim { v.trim() } else { v };
+        if v.is_empty() || self.none_markers.iter().any(|m| m == v) {
+            self.nulls += 1;
+            return;
+        }
+        let (_, observed, date_pattern) = infer_with_date_pattern(v, &self.options);
+        if let Some(pattern) = date_pattern {
+            let typed_pattern = (observed.clone(), pattern.clone());
+            if !self.date_patterns.contains(&typed_pattern) {
+                self.date_patterns.push(typed_pattern);
+            }
+        }
+        self.value_type = Some(match &self.value_type {
+            Some(current) => widen(current, &observed),
+            None => observed,
+        });
+    }
+
+    /// The (widened) type of all non-null samples so far. `None`, if there were only nulls (or nothing at all).
+    pub fn value_type(&self) -> Option<&ValueType> {
+        self.value_type.as_ref()
+    }
+
+    /// The date patterns that matched so far, in the order they were seen first.
+    /// NOTE: These are kept, even if the type was widened to `String` later on.
+    pub fn date_patterns(&self) -> Vec<DatePattern> {
+        self.date_patterns.iter().map(|(_, p)| p.clone()).collect()
+    }
+
+    pub fn sample_count(&self) -> usize {
+        self.samples
+    }
+
+    pub fn null_count(&self) -> usize {
+        self.nulls
+    }
+
+    /// Between 0.0 and 1.0. No samples at all are a ratio of 0.0.
+    pub fn null_ratio(&self) -> f64 {
+        if self.samples == 0 {
+            0.0
+        } else {
+            self.nulls as f64 / self.samples as f64
+        }
+    }
+
+    /// The proposed column. Only nulls (or nothing at all) make a `String` column.
+    /// The column is nullable, if there was at least one null. When all dates matched the same chrono pattern,
+    /// that's the chrono pattern of the column, otherwise the column gets all date patterns that matched.
+    pub fn to_column(&self, name: &str) -> Column {
+        let value_type = self.value_type.clone().unwrap_or(ValueType::String);
+        let mut column = Column::new(name, value_type.clone());
+        if self.nulls == 0 && self.samples > 0 {
+            column = column.not_null();
+        }
+        if value_type.is_some_date_type() {
+            match self.date_patterns.as_slice() {
+                [(_, DatePattern::Chrono(pattern))] => {
+                    column = column.with_chrono_pattern(pattern);
+                }
+                [] => {}
+                // keyed by the type they matched, e.g. dates and date times of a `NaiveDateTime` column
+                typed_patterns => {
+                    let mut date_patterns = DatePatterns::empty();
+                    for t in DATE_TYPES.iter() {
+                        let patterns: Vec<DatePattern> = typed_patterns
+                            .iter()
+                            .filter(|(pattern_type, _)| pattern_type == t)
+                            .map(|(_, p)| p.clone())
+                            .collect();
+                        if !patterns.is_empty() {
+                            date_patterns = date_patterns.with_patterns(t.clone(), &patterns);
+                        }
+                    }
+                    column.options.date_patterns = Some(date_patterns);
+                }
+            }
+        }
+        if value_type == ValueType::Bool && self.options.bool_format != BoolFormat::default() {
+            column = column.with_bool_format(self.options.bool_format.clone());
+        }
+        column.options.none_markers = self.none_markers.clone();
+        column.options.trim = self.options.trim;
+        column
+    }
+}
+
+/// A `TypeInferrer` per column of a CSV header, for proposing a `Schema`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaInferrer {
+    header: Vec<String>,
+    columns: Vec<TypeInferrer>,
+}
+
+impl SchemaInferrer {
+    pub fn new<S: AsRef<str>>(header: &[S], options: InferOptions) -> Self {
+        SchemaInferrer {
+            header: header.iter().map(|h| String::from(h.as_ref())).collect(),
+            columns: vec![TypeInferrer::new(options); header.len()],
+        }
+    }
+
+    pub fn with_none_markers(mut self, none_markers: &[&str]) -> Self {
+        self.columns = self
+            .columns
+            .into_iter()
+            .map(|c| c.with_none_markers(none_markers))
+            .collect();
+        self
+    }
+
+    /// Fails (without observing anything) if the record doesn't have as many cells as the header.
+    pub fn observe_record<S: AsRef<str>>(&mut self, record: &[S]) -> Result<()> {
+        if record.len() != self.columns.len() {
+            return Err(VenumError::Parsing(ParseError::RecordLengthMismatch {
+                expected: self.columns.len(),
+                actual: record.len(),
+            }));
+        }
+        self.columns
+            .iter_mut()
+            .zip(record)
+            .for_each(|(column, cell)| column.observe(cell.as_ref()));
+        Ok(())
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = (&str, &TypeInferrer)> {
+        self.header
+            .iter()
+            .map(String::as_str)
+            .zip(self.columns.iter())
+    }
+
+    pub fn to_schema(&self) -> Schema {
+        Schema::new(
+            self.columns()
+                .map(|(name, column)| column.to_column(name))
+                .collect(),
+        )
+    }
+}
+
+impl Schema {
+    /// Proposes a schema from a CSV header and some sample rows, see: `SchemaInferrer`
+    pub fn infer_from_records<H, I, R, S>(
+        header: &[H],
+        rows: I,
+        options: InferOptions,
+    ) -> Result<Schema>
+    where
+        H: AsRef<str>,
+        I: IntoIterator<Item = R>,
+        R: AsRef<[S]>,
+        S: AsRef<str>,
+    {
+        let mut inferrer = SchemaInferrer::new(header, options);
+        for row in rows {
+            inferrer.observe_record(row.as_ref())?;
+        }
+        Ok(inferrer.to_schema())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, NaiveDate};
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    fn infer(v: &str) -> (Value, ValueType) {
+        Value::infer_from_str(v, &InferOptions::default())
+    }
+
+    #[test]
+    pub fn bools() {
+        assert_eq!((Value::Bool(true), ValueType::Bool), infer("true"));
+        assert_eq!((Value::Bool(false), ValueType::Bool), infer("false"));
+        assert_eq!(
+            (Value::String(String::from("TRUE")), ValueType::String),
+            infer("TRUE")
+        );
+        let options = InferOptions {
+            infer_bools: false,
+            ..InferOptions::default()
+        };
+        assert_eq!(ValueType::String, Value::infer_from_str("true", &options).1);
+    }
+
+    #[test]
+    pub fn bools_with_format() {
+        let options = InferOptions {
+            bool_format: BoolFormat::common(),
+            ..InferOptions::default()
+        };
+        assert_eq!(
+            (Value::Bool(true), ValueType::Bool),
+            Value::infer_from_str("Yes", &options)
+        );
+        assert_eq!(
+            (Value::Bool(false), ValueType::Bool),
+            Value::infer_from_str("0", &options)
+        );
+        assert_eq!(ValueType::Int8, Value::infer_from_str("2", &options).1);
+
+        let mut inferrer = TypeInferrer::new(InferOptions {
+            bool_format: BoolFormat::new(&["Y"], &["N"]),
+            ..InferOptions::default()
+        });
+        inferrer.observe("Y");
+        inferrer.observe("N");
+        assert_eq!(
+            Column::new("active", ValueType::Bool)
+                .not_null()
+                .with_bool_format(BoolFormat::new(&["Y"], &["N"])),
+            inferrer.to_column("active")
+        );
+    }
+
+    #[test]
+    pub fn ints() {
+        assert_eq!((Value::Int8(-5), ValueType::Int8), infer("-5"));
+        assert_eq!((Value::Int8(5), ValueType::Int8), infer("+5"));
+        assert_eq!((Value::Int16(200), ValueType::Int16), infer("200"));
+        assert_eq!((Value::Int32(70000), ValueType::Int32), infer("70000"));
+        assert_eq!(ValueType::Int64, infer("3000000000").1);
+        assert_eq!(ValueType::Int128, infer("10000000000000000000").1);
+        assert_eq!(
+            (Value::UInt128(u128::MAX), ValueType::UInt128),
+            infer("340282366920938463463374607431768211455")
+        );
+    }
+
+    #[test]
+    pub fn ints_with_default_type() {
+        let options = InferOptions {
+            int_type: Some(ValueType::Int64),
+            ..InferOptions::default()
+        };
+        assert_eq!(
+            (Value::Int64(5), ValueType::Int64),
+            Value::infer_from_str("5", &options)
+        );
+        // doesn't fit, so we take the smallest one that does
+        assert_eq!(
+            ValueType::Int128,
+            Value::infer_from_str("10000000000000000000", &options).1
+        );
+    }
+
+    #[test]
+    pub fn fractionals() {
+        assert_eq!((Value::Float64(1.5), ValueType::Float64), infer("1.5"));
+        assert_eq!((Value::Float64(1e5), ValueType::Float64), infer("1e5"));
+        assert_eq!(ValueType::String, infer("NaN").1);
+        assert_eq!(ValueType::String, infer("inf").1);
+
+        let options = InferOptions {
+            prefer_decimal: true,
+            ..InferOptions::default()
+        };
+        assert_eq!(
+            (Value::Decimal(Decimal::new(150, 2)), ValueType::Decimal),
+            Value::infer_from_str("1.50", &options)
+        );
+        // not representable as Decimal without the scientific notation
+        assert_eq!(ValueType::Float64, Value::infer_from_str("1e5", &options).1);
+    }
+
+    #[test]
+    pub fn dates() {
+        assert_eq!(
+            (
+                Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap()),
+                ValueType::NaiveDate
+            ),
+            infer("2022-12-31")
+        );
+        assert_eq!(ValueType::NaiveDateTime, infer("2022-12-31T12:00:00").1);
+        assert_eq!(ValueType::NaiveDateTime, infer("2022-12-31T12:00:00.123").1);
+        assert_eq!(
+            (
+                Value::DateTime(
+                    NaiveDate::from_ymd_opt(2022, 12, 31)
+                        .unwrap()
+                        .and_hms_opt(12, 0, 0)
+                        .unwrap()
+                        .and_local_timezone(FixedOffset::east_opt(3600).unwrap())
+                        .unwrap()
+                ),
+                ValueType::DateTime
+            ),
+            infer("2022-12-31T12:00:00+01:00")
+        );
+        assert_eq!(ValueType::String, infer("31.12.2022").1);
+    }
+
+    #[test]
+    pub fn strings() {
+        assert_eq!((Value::String(String::new()), ValueType::String), infer(""));
+        assert_eq!(ValueType::String, infer("abc").1);
+        assert_eq!(ValueType::String, infer("-").1);
+        assert_eq!(ValueType::String, infer(" 5").1);
+
+        let options = InferOptions {
+            trim: true,
+            ..InferOptions::default()
+        };
+        assert_eq!(
+            (Value::Int8(5), ValueType::Int8),
+            Value::infer_from_str(" 5 ", &options)
+        );
+        assert_eq!(
+            (Value::String(String::from("  ")), ValueType::String),
+            Value::infer_from_str("  ", &options)
+        );
+    }
+
+    #[test]
+    pub fn type_inferrer_widens() {
+        let mut inferrer = TypeInferrer::default();
+        assert_eq!(None, inferrer.value_type());
+        inferrer.observe("1");
+        assert_eq!(Some(&ValueType::Int8), inferrer.value_type());
+        inferrer.observe("70000");
+        assert_eq!(Some(&ValueType::Int32), inferrer.value_type());
+        inferrer.observe("-3");
+        assert_eq!(Some(&ValueType::Int32), inferrer.value_type());
+        inferrer.observe("1.5");
+        assert_eq!(Some(&ValueType::Float64), inferrer.value_type());
+        inferrer.observe("abc");
+        assert_eq!(Some(&ValueType::String), inferrer.value_type());
+        inferrer.observe("2");
+        assert_eq!(Some(&ValueType::String), inferrer.value_type());
+
+        let mut inferrer = TypeInferrer::new(InferOptions {
+            prefer_decimal: true,
+            ..InferOptions::default()
+        });
+        inferrer.observe("70000");
+        inferrer.observe("1.5");
+        assert_eq!(Some(&ValueType::Decimal), inferrer.value_type());
+    }
+
+    #[test]
+    pub fn type_inferrer_incompatible() {
+        let mut inferrer = TypeInferrer::default();
+        inferrer.observe("true");
+        inferrer.observe("1");
+        assert_eq!(Some(&ValueType::String), inferrer.value_type());
+
+        let mut inferrer = TypeInferrer::default();
+        inferrer.observe("2022-12-31");
+        inferrer.observe("2022-12-31T12:00:00+01:00");
+        assert_eq!(Some(&ValueType::String), inferrer.value_type());
+
+        let mut inferrer = TypeInferrer::default();
+        inferrer.observe("-1");
+        inferrer.observe("340282366920938463463374607431768211455");
+        assert_eq!(Some(&ValueType::String), inferrer.value_type());
+    }
+
+    #[test]
+    pub fn type_inferrer_nulls() {
+        let mut inferrer = TypeInferrer::default().with_none_markers(&["n/a"]);
+        assert_eq!(0.0, inferrer.null_ratio());
+        inferrer.observe("");
+        inferrer.observe("n/a");
+        assert_eq!(None, inferrer.value_type());
+        inferrer.observe("1");
+        inferrer.observe("2");
+        assert_eq!(Some(&ValueType::Int8), inferrer.value_type());
+        assert_eq!(4, inferrer.sample_count());
+        assert_eq!(2, inferrer.null_count());
+        assert_eq!(0.5, inferrer.null_ratio());
+    }
+
+    #[test]
+    pub fn type_inferrer_date_patterns() {
+        let mut inferrer = TypeInferrer::default();
+        inferrer.observe("2022-12-31T12:00:00");
+        inferrer.observe("2022-12-31T12:00:00.123");
+        assert_eq!(Some(&ValueType::NaiveDateTime), inferrer.value_type());
+        let patterns = [
+            DatePattern::from("%Y-%m-%dT%H:%M:%S"),
+            DatePattern::from("%Y-%m-%dT%H:%M:%S%.3f"),
+        ];
+        assert_eq!(patterns.to_vec(), inferrer.date_patterns());
+        assert_eq!(
+            Column::new("ts", ValueType::NaiveDateTime)
+                .not_null()
+                .with_date_patterns(&patterns),
+            inferrer.to_column("ts")
+        );
+
+        let mut inferrer = TypeInferrer::default();
+        inferrer.observe("2022-12-31T12:00:00+01:00");
+        inferrer.observe("Tue, 1 Jul 2003 10:52:37 +0200");
+        assert_eq!(Some(&ValueType::DateTime), inferrer.value_type());
+        assert_eq!(
+            vec![DatePattern::Rfc3339, DatePattern::Rfc2822],
+            inferrer.date_patterns()
+        );
+    }
+
+    #[test]
+    pub fn type_inferrer_dates_and_date_times() {
+        let mut inferrer = TypeInferrer::default();
+        inferrer.observe("2022-12-31");
+        inferrer.observe("2022-12-31T12:00:00");
+        assert_eq!(Some(&ValueType::NaiveDateTime), inferrer.value_type());
+        let column = inferrer.to_column("ts");
+        assert_eq!(
+            Some(
+                DatePatterns::empty()
+                    .with_patterns(ValueType::NaiveDate, &[DatePattern::from("%Y-%m-%d")])
+                    .with_patterns(
+                        ValueType::NaiveDateTime,
+                        &[DatePattern::from("%Y-%m-%dT%H:%M:%S")]
+                    )
+            ),
+            column.options.date_patterns
+        );
+        let date = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+        assert_eq!(
+            Ok(Value::NaiveDateTime(date.and_hms_opt(0, 0, 0).unwrap())),
+            column.parse("2022-12-31")
+        );
+        assert_eq!(
+            Ok(Value::NaiveDateTime(date.and_hms_opt(12, 0, 0).unwrap())),
+            column.parse("2022-12-31T12:00:00")
+        );
+        // still no dates with offsets
+        inferrer.observe("2022-12-31T12:00:00+01:00");
+        assert_eq!(Some(&ValueType::String), inferrer.value_type());
+    }
+
+    #[test]
+    pub fn type_inferrer_custom_date_patterns() {
+        let date_patterns = DatePatterns::default().with_patterns(
+            ValueType::NaiveDate,
+            &[DatePattern::from("%d.%m.%Y"), DatePattern::from("%m/%d/%Y")],
+        );
+        let mut inferrer = TypeInferrer::new(InferOptions {
+            date_patterns,
+            ..InferOptions::default()
+        });
+        inferrer.observe("12/31/2022");
+        inferrer.observe("31.12.2022");
+        inferrer.observe("01/02/2023");
+        let column = inferrer.to_column("booked");
+        assert_eq!(
+            Column::new("booked", ValueType::NaiveDate)
+                .not_null()
+                .with_date_patterns(&[
+                    DatePattern::from("%m/%d/%Y"),
+                    DatePattern::from("%d.%m.%Y")
+                ]),
+            column
+        );
+        // ISO is no longer part of the patterns
+        inferrer.observe("2022-12-31");
+        assert_eq!(Some(&ValueType::String), inferrer.value_type());
+        assert!(column.parse("31.12.2022").is_ok());
+    }
+
+    #[test]
+    pub fn type_inferrer_to_column() {
+        let mut inferrer = TypeInferrer::default();
+        inferrer.observe("2022-12-31");
+        assert_eq!(
+            Column::new("booked", ValueType::NaiveDate)
+                .not_null()
+                .with_chrono_pattern("%Y-%m-%d"),
+            inferrer.to_column("booked")
+        );
+
+        let mut inferrer = TypeInferrer::default().with_none_markers(&["n/a"]);
+        inferrer.observe("n/a");
+        assert_eq!(
+            Column::new("empty", ValueType::String).with_none_markers(&["n/a"]),
+            inferrer.to_column("empty")
+        );
+        assert_eq!(
+            Column::new("nothing", ValueType::String),
+            TypeInferrer::default().to_column("nothing")
+        );
+    }
+
+    #[test]
+    pub fn infer_schema() {
+        let rows = vec![
+            vec!["1", "10.50", "2022-12-31", "a"],
+            vec!["2", "", "2023-01-01", "true"],
+            vec!["300", "7", "2023-01-02", "c"],
+        ];
+        let schema = Schema::infer_from_records(
+            &["id", "amount", "booked", "misc"],
+            &rows,
+            InferOptions {
+                prefer_decimal: true,
+                ..InferOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            Schema::new(vec![
+                Column::new("id", ValueType::Int16).not_null(),
+                Column::new("amount", ValueType::Decimal),
+                Column::new("booked", ValueType::NaiveDate)
+                    .not_null()
+                    .with_chrono_pattern("%Y-%m-%d"),
+                Column::new("misc", ValueType::String).not_null(),
+            ]),
+            schema
+        );
+        // the proposed schema can parse what it was inferred from
+        assert!(schema.parse_records(&rows).all(|r| r.is_ok()));
+    }
+
+    #[test]
+    pub fn infer_schema_trim() {
+        let rows = vec![vec![" 5 ", " n/a"], vec!["  ", "1 "]];
+        let options = InferOptions {
+            trim: true,
+            ..InferOptions::default()
+        };
+        let mut inferrer = SchemaInferrer::new(&["a", "b"], options).with_none_markers(&["n/a"]);
+        for row in &rows {
+            inferrer.observe_record(row).unwrap();
+        }
+        let schema = inferrer.to_schema();
+        let mut expected_a = Column::new("a", ValueType::Int8).with_none_markers(&["n/a"]);
+        expected_a.options.trim = true;
+        let mut expected_b = Column::new("b", ValueType::Int8).with_none_markers(&["n/a"]);
+        expected_b.options.trim = true;
+        assert_eq!(Schema::new(vec![expected_a, expected_b]), schema);
+        for row in &rows {
+            assert!(schema.parse_record(row).is_ok(), "{row:?}");
+        }
+    }
+
+    #[test]
+    pub fn infer_schema_big_ints_and_decimals() {
+        let rows = vec![
+            vec!["340282366920938463463374607431768211455", "1.5"],
+            vec!["1.5", "-170141183460469231731687303715884105"],
+        ];
+        let schema = Schema::infer_from_records(
+            &["u128", "i128"],
+            &rows,
+            InferOptions {
+                prefer_decimal: true,
+                ..InferOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            Schema::new(vec![
+                Column::new("u128", ValueType::Float64).not_null(),
+                Column::new("i128", ValueType::Float64).not_null(),
+            ]),
+            schema
+        );
+        for row in &rows {
+            assert!(schema.parse_record(row).is_ok(), "{row:?}");
+        }
+    }
+
+    #[test]
+    pub fn infer_schema_length_mismatch() {
+        let mut inferrer = SchemaInferrer::new(&["a", "b"], InferOptions::default());
+        assert_eq!(
+            Err(VenumError::Parsing(ParseError::RecordLengthMismatch {
+                expected: 2,
+                actual: 1,
+            })),
+            inferrer.observe_record(&["1"])
+        );
+        assert!(inferrer.columns().all(|(_, c)| c.sample_count() == 0));
+    }
+}
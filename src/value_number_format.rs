@@ -0,0 +1,377 @@
+use crate::errors_result::{ConversionError, ParseError, Result, VenumError};
+use crate::value::Value;
+use crate::value_parse_options::ParseOptions;
+use crate::value_type::ValueType;
+
+/// How numbers are written, e.g. "1.234.567,89" or "1 234,5" in European exports.
+/// Such strings are normalized to the plain representation, i.e. "1234567.89", before parsing them
+/// with the usual `parse_*_from_str` functions.
+/// NOTE: "NaN" and "inf" are not numbers in this sense, i.e. they are rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub decimal_separator: char,
+    /// Only allowed in the integer part and only between digits, e.g. "1.234.567" but not "1..234" or ".123"
+    pub grouping_separator: Option<char>,
+    /// Whitespace (including non-breaking spaces) around the number, between the sign and the number,
+    /// and as grouping separator, e.g. " 1 234,5 "
+    pub allow_whitespace: bool,
+    pub allow_leading_plus: bool,
+    /// e.g. "123-" for -123, like some accounting exports do it
+    pub allow_trailing_minus: bool,
+}
+
+/// The plain format, i.e. the one Rust (and `parse_*_from_str`) understands: "-1234.5"
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat {
+            decimal_separator: '.',
+            grouping_separator: None,
+            allow_whitespace: false,
+            allow_leading_plus: true,
+            allow_trailing_minus: false,
+        }
+    }
+}
+
+impl NumberFormat {
+    pub fn new(decimal_separator: char, grouping_separator: Option<char>) -> Self {
+        NumberFormat {
+            decimal_separator,
+            grouping_separator,
+            ..NumberFormat::default()
+        }
+    }
+
+    /// Decimal comma and dots for grouping, e.g. "1.234.567,89"
+    pub fn decimal_comma() -> Self {
+        NumberFormat::new(',', Some('.'))
+    }
+
+    pub fn with_whitespace(mut self) -> Self {
+        self.allow_whitespace = true;
+        self
+    }
+
+    pub fn without_leading_plus(mut self) -> Self {
+        self.allow_leading_plus = false;
+        self
+    }
+
+    pub fn with_trailing_minus(mut self) -> Self {
+        self.allow_trailing_minus = true;
+        self
+    }
+
+    fn is_grouping(&self, c: char) -> bool {
+        Some(c) == self.grouping_separator || (self.allow_whitespace && c.is_whitespace())
+    }
+
+    fn trim<'a>(&self, v: &'a str) -> &'a str {
+        if self.allow_whitespace {
+            v.trim()
+        } else {
+            v
+        }
+    }
+
+    /// Turns e.g. "1.234,5-" into "-1234.5". The error is the reason why that wasn't possible.
+    fn try_normalize(&self, v: &str) -> std::result::Result<String, &'static str> {
+        let mut rest = self.trim(v);
+        let mut negative = false;
+        if let Some(r) = rest.strip_prefix('-') {
+            negative = true;
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix('+') {
+            if !self.allow_leading_plus {
+                return Err("leading '+' is not allowed");
+            }
+            rest = r;
+        } else if self.allow_trailing_minus {
+            if let Some(r) = rest.strip_suffix('-') {
+                negative = true;
+                rest = r;
+            }
+        }
+        rest = self.trim(rest);
+
+        let (mantissa, exponent) = match rest.find(['e', 'E']) {
+            Some(pos) => (&rest[..pos], Some(&rest[pos + 1..])),
+            None => (rest, None),
+        };
+        let (integer_part, fraction_part) = match mantissa.split_once(self.decimal_separator) {
+            Some((i, f)) => (i, Some(f)),
+            None => (mantissa, None),
+        };
+
+        let mut normalized = String::with_capacity(v.len());
+        if negative {
+            normalized.push('-');
+        }
+        let integer_chars: Vec<char> = integer_part.chars().collect();
+        for (i, c) in integer_chars.iter().enumerate() {
+            if c.is_ascii_digit() {
+                normalized.push(*c);
+            } else if self.is_grouping(*c) {
+                let between_digits = i > 0
+                    && integer_chars[i - 1].is_ascii_digit()
+                    && matches!(integer_chars.get(i + 1), Some(c) if c.is_ascii_digit());
+                if !between_digits {
+                    return Err("grouping separator is not between digits");
+                }
+            } else {
+                return Err("unexpected character in integer part");
+            }
+        }
+        let mut has_digits = !integer_part.is_empty();
+        if let Some(fraction) = fraction_part {
+            if !fraction.chars().all(|c| c.is_ascii_digit()) {
+                return Err("unexpected character in fraction part");
+            }
+            has_digits |= !fraction.is_empty();
+            normalized.push('.');
+            normalized.push_str(fraction);
+        }
+        if !has_digits {
+            return Err("no digits");
+        }
+        if let Some(exponent) = exponent {
+            let digits = exponent.strip_prefix(['-', '+']).unwrap_or(exponent);
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                return Err("invalid exponent");
+            }
+            normalized.push('e');
+            normalized.push_str(exponent);
+        }
+        Ok(normalized)
+    }
+
+    /// The plain representation of a number written in this format, e.g. "-1234.5" for "1.234,5-".
+    pub fn normalize(&self, v: &str) -> Result<String> {
+        self.normalize_with_target_type(v, "number")
+    }
+
+    /// Same as `normalize`, but the error names the type the number was meant to be parsed as.
+    pub(crate) fn normalize_for(&self, v: &str, target_value_type: &ValueType) -> Result<String> {
+        self.normalize_with_target_type(v, &format!("Value::{target_value_type}"))
+    }
+
+    fn normalize_with_target_type(&self, v: &str, target_type: &str) -> Result<String> {
+        self.try_normalize(v).map_err(|details| {
+            VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::from(v),
+                target_type: String::from(target_type),
+                details: Some(format!("Number format: {self:?}. Reason: {details}")),
+            })
+        })
+    }
+}
+
+impl Value {
+    /// Like `from_str_and_type`, but numbers (ints, floats and decimals) are expected in the given number format.
+    /// All other types are parsed as usual.
+    pub fn from_str_and_type_with_number_format(
+        value: &str,
+        target_value_type: &ValueType,
+        number_format: &NumberFormat,
+    ) -> Result<Value> {
+        Value::parse(
+            value,
+            target_value_type,
+            &ParseOptions::new().with_number_format(number_format.clone()),
+        )
+    }
+
+    /// Like `try_convert_to`, but a `Value::String` converted to a number is expected in the given number format.
+    pub fn try_convert_to_with_number_format(
+        &self,
+        target_type: &ValueType,
+        number_format: &NumberFormat,
+    ) -> Result<Value> {
+        match self {
+            Value::String(s) if !s.is_empty() && target_type.is_some_numeric_type() => {
+                let not_representable = || {
+                    VenumError::Conversion(ConversionError::NotRepresentableAs {
+                        src: self.clone(),
+                        target_type: target_type.clone(),
+                    })
+                };
+                let normalized = number_format
+                    .try_normalize(s)
+                    .map_err(|_| not_representable())?;
+                Value::String(normalized)
+                    .try_convert_to(target_type)
+                    .map_err(|_| not_representable())
+            }
+            _ => self.try_convert_to(target_type),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    mod normalize {
+        use super::*;
+
+        #[test]
+        pub fn default_format() {
+            let f = NumberFormat::default();
+            assert_eq!(Ok(String::from("-1234.5")), f.normalize("-1234.5"));
+            assert_eq!(Ok(String::from("1234")), f.normalize("+1234"));
+            assert_eq!(Ok(String::from("1.5e-3")), f.normalize("1.5e-3"));
+            assert_eq!(Ok(String::from(".5")), f.normalize(".5"));
+            assert!(f.normalize("1,234.5").is_err());
+            assert!(f.normalize(" 1").is_err());
+            assert!(f.normalize("1-").is_err());
+            assert!(f.normalize("NaN").is_err());
+            assert!(f.normalize("-").is_err());
+            assert!(f.normalize("1e").is_err());
+        }
+
+        #[test]
+        pub fn decimal_comma() {
+            let f = NumberFormat::decimal_comma();
+            assert_eq!(Ok(String::from("1234567.89")), f.normalize("1.234.567,89"));
+            assert_eq!(Ok(String::from("-1234")), f.normalize("-1.234"));
+            assert_eq!(Ok(String::from("0.5")), f.normalize("0,5"));
+            assert!(f.normalize("1..234").is_err());
+            assert!(f.normalize(".123").is_err());
+            assert!(f.normalize("123.").is_err());
+            assert!(f.normalize("1,234,5").is_err());
+            assert!(f.normalize("1,2.3").is_err());
+        }
+
+        #[test]
+        pub fn whitespace() {
+            let f = NumberFormat::new(',', None).with_whitespace();
+            assert_eq!(Ok(String::from("1234.5")), f.normalize("1 234,5"));
+            assert_eq!(Ok(String::from("1234.5")), f.normalize(" 1\u{a0}234,5 "));
+            assert_eq!(Ok(String::from("-1234.5")), f.normalize("- 1\u{202f}234,5"));
+            assert!(f.normalize("1  234").is_err());
+            assert!(NumberFormat::new(',', None).normalize("1 234,5").is_err());
+        }
+
+        #[test]
+        pub fn signs() {
+            let f = NumberFormat::default().without_leading_plus();
+            assert!(f.normalize("+1").is_err());
+            assert_eq!(Ok(String::from("-1")), f.normalize("-1"));
+
+            let f = NumberFormat::decimal_comma().with_trailing_minus();
+            assert_eq!(Ok(String::from("-1234.5")), f.normalize("1.234,5-"));
+            assert!(f.normalize("-1-").is_err());
+        }
+    }
+
+    mod from_str_and_type_with_number_format {
+        use super::*;
+
+        #[test]
+        pub fn numbers() {
+            let f = NumberFormat::decimal_comma();
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::new(123456789, 2))),
+                Value::from_str_and_type_with_number_format(
+                    "1.234.567,89",
+                    &ValueType::Decimal,
+                    &f
+                )
+            );
+            assert_eq!(
+                Ok(Value::Float64(1234.5)),
+                Value::from_str_and_type_with_number_format("1.234,5", &ValueType::Float64, &f)
+            );
+            assert_eq!(
+                Ok(Value::Int32(-1234567)),
+                Value::from_str_and_type_with_number_format("-1.234.567", &ValueType::Int32, &f)
+            );
+            assert_eq!(
+                Ok(Value::UInt16(1234)),
+                Value::from_str_and_type_with_number_format("1.234", &ValueType::UInt16, &f)
+            );
+            assert_eq!(
+                Ok(Value::Null(ValueType::Float32)),
+                Value::from_str_and_type_with_number_format("", &ValueType::Float32, &f)
+            );
+        }
+
+        #[test]
+        pub fn other_types_unaffected() {
+            let f = NumberFormat::decimal_comma();
+            assert_eq!(
+                Ok(Value::String(String::from("1.234,5"))),
+                Value::from_str_and_type_with_number_format("1.234,5", &ValueType::String, &f)
+            );
+            assert_eq!(
+                Ok(Value::Bool(true)),
+                Value::from_str_and_type_with_number_format("true", &ValueType::Bool, &f)
+            );
+        }
+
+        #[test]
+        pub fn errors() {
+            let f = NumberFormat::decimal_comma();
+            assert!(matches!(
+                Value::from_str_and_type_with_number_format("1,2,3", &ValueType::Float64, &f),
+                Err(VenumError::Parsing(ParseError::ValueFromStringFailed { target_type, .. }))
+                    if target_type == "Value::Float64"
+            ));
+            // normalizes fine, but doesn't fit
+            assert!(matches!(
+                Value::from_str_and_type_with_number_format("1.000", &ValueType::Int8, &f),
+                Err(VenumError::Parsing(ParseError::ValueFromStringFailed { src_value, .. }))
+                    if src_value == "1.000"
+            ));
+        }
+    }
+
+    mod try_convert_to_with_number_format {
+        use super::*;
+
+        #[test]
+        pub fn from_string() {
+            let f = NumberFormat::new(',', None).with_whitespace();
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::new(12345, 1))),
+                Value::String(String::from("1 234,5"))
+                    .try_convert_to_with_number_format(&ValueType::Decimal, &f)
+            );
+            assert_eq!(
+                Ok(Value::Int64(1234)),
+                Value::String(String::from("1 234"))
+                    .try_convert_to_with_number_format(&ValueType::Int64, &f)
+            );
+            assert_eq!(
+                Err(VenumError::Conversion(
+                    ConversionError::NotRepresentableAs {
+                        src: Value::String(String::from("1.5")),
+                        target_type: ValueType::Float64,
+                    }
+                )),
+                Value::String(String::from("1.5"))
+                    .try_convert_to_with_number_format(&ValueType::Float64, &f)
+            );
+        }
+
+        #[test]
+        pub fn others_unaffected() {
+            let f = NumberFormat::decimal_comma();
+            assert_eq!(
+                Ok(Value::Float64(1.5)),
+                Value::Float32(1.5).try_convert_to_with_number_format(&ValueType::Float64, &f)
+            );
+            assert_eq!(
+                Ok(Value::String(String::from("1.5"))),
+                Value::Float64(1.5).try_convert_to_with_number_format(&ValueType::String, &f)
+            );
+            assert_eq!(
+                Ok(Value::Null(ValueType::Int8)),
+                Value::Null(ValueType::String)
+                    .try_convert_to_with_number_format(&ValueType::Int8, &f)
+            );
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use crate::value::BytesEncoding;
+use crate::value_bool_format::BoolFormat;
+use crate::value_date_patterns::DatePatterns;
+use crate::value_epoch::EpochUnit;
+use crate::value_number_format::NumberFormat;
+
+/// What an empty string (after trimming, if enabled) becomes, see: `ParseOptions::empty`
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyPolicy {
+    /// `Value::Null(target type)`
+    #[default]
+    Null,
+    /// The untyped `Value::None`
+    None,
+    /// `Value::String("")` for `ValueType::String`, `Value::Null(target type)` for everything else
+    EmptyString,
+    Error,
+}
+
+/// All the knobs of `Value::parse`. The default is what `Value::from_str_and_type` does.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ParseOptions {
+    /// Values that always become `Value::Null(target type)`, e.g. "n/a" or "NULL"
+    pub none_markers: Vec<String>,
+    pub empty: EmptyPolicy,
+    /// For all date (time) types. Anything but date types and lists of them fails to parse with a chrono pattern!
+    pub chrono_pattern: Option<String>,
+    /// Tried in order per date (time) type. Takes precedence over `chrono_pattern`.
+    pub date_patterns: Option<DatePatterns>,
+    /// "inf", "-inf", "NaN", ... for `Float32` and `Float64`
+    pub allow_inf_nan: bool,
+    /// Ignore leading and trailing whitespace
+    pub trim: bool,
+    /// For ints, floats and decimals
+    pub number_format: Option<NumberFormat>,
+    pub bool_format: Option<BoolFormat>,
+    /// Numeric strings are Unix timestamps in this unit for `NaiveDateTime` and `DateTime`, e.g. "1700000000".
+    /// Other strings are parsed as usual.
+    pub epoch_unit: Option<EpochUnit>,
+    /// For `Bytes`. Only this encoding is tried, as e.g. "AAAA" is valid hex and valid base64.
+    pub bytes_encoding: BytesEncoding,
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        ParseOptions::default()
+    }
+
+    pub fn with_none_markers(mut self, none_markers: &[&str]) -> Self {
+        self.none_markers = none_markers.iter().map(|m| String::from(*m)).collect();
+        self
+    }
+
+    pub fn with_empty(mut self, empty: EmptyPolicy) -> Self {
+        self.empty = empty;
+        self
+    }
+
+    pub fn with_chrono_pattern(mut self, chrono_pattern: &str) -> Self {
+        self.chrono_pattern = Some(String::from(chrono_pattern));
+        self
+    }
+
+    pub fn with_date_patterns(mut self, date_patterns: DatePatterns) -> Self {
+        self.date_patterns = Some(date_patterns);
+        self
+    }
+
+    pub fn with_inf_nan(mut self) -> Self {
+        self.allow_inf_nan = true;
+        self
+    }
+
+    pub fn with_trim(mut self) -> Self {
+        self.trim = true;
+        self
+    }
+
+    pub fn with_number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = Some(number_format);
+        self
+    }
+
+    pub fn with_bool_format(mut self, bool_format: BoolFormat) -> Self {
+        self.bool_format = Some(bool_format);
+        self
+    }
+
+    pub fn with_epoch_unit(mut self, epoch_unit: EpochUnit) -> Self {
+        self.epoch_unit = Some(epoch_unit);
+        self
+    }
+
+    pub fn with_bytes_encoding(mut self, bytes_encoding: BytesEncoding) -> Self {
+        self.bytes_encoding = bytes_encoding;
+        self
+    }
+
+    pub(crate) fn is_none_marker(&self, v: &str) -> bool {
+        self.none_markers.iter().any(|m| m == v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use super::*;
+    use crate::errors_result::{ParseError, VenumError};
+    use crate::value::Value;
+    use crate::value_date_patterns::DatePattern;
+    use crate::value_type::ValueType;
+
+    #[test]
+    pub fn default_is_from_str_and_type() {
+        let options = ParseOptions::default();
+        for (v, t) in [
+            ("", ValueType::Int8),
+            ("1", ValueType::Int8),
+            ("x", ValueType::Int8),
+            ("1.5", ValueType::Decimal),
+            ("1e3", ValueType::Decimal),
+            ("inf", ValueType::Float64),
+            ("TRUE", ValueType::Bool),
+            ("2022-12-31", ValueType::NaiveDate),
+            ("2022-12-31T12:00:00.123", ValueType::NaiveDateTime),
+            ("08:30", ValueType::NaiveTime),
+            (" 1", ValueType::Int8),
+            ("1|2||3", ValueType::List(Box::new(ValueType::Int8))),
+            ("a", ValueType::Map),
+        ] {
+            assert_eq!(
+                Value::from_str_and_type(v, &t),
+                Value::parse(v, &t, &options),
+                "{v}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn empty_policy() {
+        let parse = |t: ValueType, empty: EmptyPolicy| {
+            Value::parse("", &t, &ParseOptions::new().with_empty(empty))
+        };
+        assert_eq!(
+            Ok(Value::Null(ValueType::Int8)),
+            parse(ValueType::Int8, EmptyPolicy::Null)
+        );
+        assert_eq!(Ok(Value::None), parse(ValueType::Int8, EmptyPolicy::None));
+        assert_eq!(
+            Ok(Value::String(String::new())),
+            parse(ValueType::String, EmptyPolicy::EmptyString)
+        );
+        assert_eq!(
+            Ok(Value::Null(ValueType::Int8)),
+            parse(ValueType::Int8, EmptyPolicy::EmptyString)
+        );
+        assert_eq!(
+            Err(VenumError::Parsing(ParseError::ValueFromStringFailed {
+                src_value: String::new(),
+                target_type: String::from("Value::Int8"),
+                details: Some(String::from("Empty string")),
+            })),
+            parse(ValueType::Int8, EmptyPolicy::Error)
+        );
+    }
+
+    #[test]
+    pub fn none_markers_and_trim() {
+        let options = ParseOptions::new()
+            .with_none_markers(&["n/a"])
+            .with_trim()
+            .with_empty(EmptyPolicy::Error);
+        assert_eq!(
+            Ok(Value::Null(ValueType::Int8)),
+            Value::parse(" n/a ", &ValueType::Int8, &options)
+        );
+        assert_eq!(
+            Ok(Value::Int8(1)),
+            Value::parse(" 1 ", &ValueType::Int8, &options)
+        );
+        assert!(Value::parse("  ", &ValueType::Int8, &options).is_err());
+        assert_eq!(
+            Ok(Value::String(String::from("a"))),
+            Value::parse(" a ", &ValueType::String, &options)
+        );
+    }
+
+    #[test]
+    pub fn inf_nan() {
+        let options = ParseOptions::new().with_inf_nan();
+        assert_eq!(
+            Ok(Value::Float64(f64::INFINITY)),
+            Value::parse("inf", &ValueType::Float64, &options)
+        );
+        assert_eq!(
+            Ok(Value::Float32(f32::NEG_INFINITY)),
+            Value::parse("-inf", &ValueType::Float32, &options)
+        );
+        assert!(matches!(
+            Value::parse("NaN", &ValueType::Float64, &options),
+            Ok(Value::Float64(f)) if f.is_nan()
+        ));
+        // also with a number format
+        let options = options.with_number_format(NumberFormat::decimal_comma());
+        assert_eq!(
+            Ok(Value::Float64(f64::INFINITY)),
+            Value::parse("inf", &ValueType::Float64, &options)
+        );
+        assert_eq!(
+            Ok(Value::Float64(1234.5)),
+            Value::parse("1.234,5", &ValueType::Float64, &options)
+        );
+        assert!(Value::parse("inf", &ValueType::Decimal, &options).is_err());
+    }
+
+    #[test]
+    pub fn formats() {
+        let options = ParseOptions::new()
+            .with_number_format(NumberFormat::decimal_comma())
+            .with_bool_format(BoolFormat::new(&["ja"], &["nein"]));
+        assert_eq!(
+            Ok(Value::Decimal(Decimal::new(12345, 1))),
+            Value::parse("1.234,5", &ValueType::Decimal, &options)
+        );
+        assert_eq!(
+            Ok(Value::Bool(false)),
+            Value::parse("nein", &ValueType::Bool, &options)
+        );
+        assert_eq!(
+            Ok(Value::List(vec![Value::Int32(1000), Value::Int32(2000)])),
+            Value::parse(
+                "1.000|2.000",
+                &ValueType::List(Box::new(ValueType::Int32)),
+                &options
+            )
+        );
+    }
+
+    #[test]
+    pub fn date_patterns() {
+        let date_patterns = DatePatterns::empty().with_patterns(
+            ValueType::NaiveDate,
+            &[DatePattern::from("%d.%m.%Y"), DatePattern::from("%m/%d/%Y")],
+        );
+        let options = ParseOptions::new()
+            .with_chrono_pattern("%Y")
+            .with_date_patterns(date_patterns);
+        let expected = Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap());
+        assert_eq!(
+            Ok(expected.clone()),
+            Value::parse("31.12.2022", &ValueType::NaiveDate, &options)
+        );
+        assert_eq!(
+            Ok(Value::List(vec![expected.clone(), expected])),
+            Value::parse(
+                "31.12.2022|12/31/2022",
+                &ValueType::List(Box::new(ValueType::NaiveDate)),
+                &options
+            )
+        );
+        // no patterns for this type
+        assert!(Value::parse("2022-12-31T12:00:00", &ValueType::NaiveDateTime, &options).is_err());
+    }
+
+    #[test]
+    pub fn epoch_unit() {
+        let options = ParseOptions::new().with_epoch_unit(EpochUnit::Millis);
+        let expected = NaiveDate::from_ymd_opt(2023, 11, 14)
+            .unwrap()
+            .and_hms_opt(22, 13, 20)
+            .unwrap();
+        assert_eq!(
+            Ok(Value::NaiveDateTime(expected)),
+            Value::parse("1700000000000", &ValueType::NaiveDateTime, &options)
+        );
+        // not numeric
+        assert_eq!(
+            Ok(Value::NaiveDateTime(expected)),
+            Value::parse("2023-11-14T22:13:20", &ValueType::NaiveDateTime, &options)
+        );
+        assert_eq!(
+            Ok(Value::Int64(1700000000000)),
+            Value::parse("1700000000000", &ValueType::Int64, &options)
+        );
+        // no epoch unit, no timestamps
+        assert!(Value::parse(
+            "1700000000000",
+            &ValueType::NaiveDateTime,
+            &ParseOptions::new()
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn bytes_encoding() {
+        for bytes_encoding in [BytesEncoding::Hex, BytesEncoding::Base64] {
+            let bytes = Value::Bytes(vec![0, 0, 0]);
+            let s = bytes
+                .try_convert_to_string_with_bytes_encoding(bytes_encoding)
+                .unwrap();
+            assert_eq!(
+                Ok(bytes),
+                Value::parse(
+                    &String::try_from(s).unwrap(),
+                    &ValueType::Bytes,
+                    &ParseOptions::new().with_bytes_encoding(bytes_encoding)
+                ),
+                "{bytes_encoding:?}"
+            );
+        }
+        assert_eq!(
+            Ok(Value::Bytes(vec![0xaa, 0xaa])),
+            Value::parse("AAAA", &ValueType::Bytes, &ParseOptions::new())
+        );
+        assert!(Value::parse("yv4=", &ValueType::Bytes, &ParseOptions::new()).is_err());
+    }
+}
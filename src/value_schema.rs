@@ -0,0 +1,345 @@
+use std::marker::PhantomData;
+
+use crate::errors_result::{ParseError, Result, VenumError};
+use crate::value::Value;
+use crate::value_bool_format::BoolFormat;
+use crate::value_date_patterns::{DatePattern, DatePatterns};
+use crate::value_number_format::NumberFormat;
+use crate::value_parse_options::ParseOptions;
+use crate::value_type::ValueType;
+
+/// One column of a `Schema`, i.e. how to turn a (CSV) cell into a `Value`.
+/// See: `Value::parse`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub name: String,
+    pub value_type: ValueType,
+    /// If false, empty cells (and none-markers) are an error instead of a `Value::Null`
+    pub nullable: bool,
+    pub options: ParseOptions,
+}
+
+impl Column {
+    /// A nullable column, parsed with the default `ParseOptions`
+    pub fn new(name: &str, value_type: ValueType) -> Self {
+        Column {
+            name: String::from(name),
+            value_type,
+            nullable: true,
+            options: ParseOptions::default(),
+        }
+    }
+
+    pub fn not_null(mut self) -> Self {
+        self.nullable = false;
+        self
+    }
+
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_chrono_pattern(mut self, chrono_pattern: &str) -> Self {
+        self.options = self.options.with_chrono_pattern(chrono_pattern);
+        self
+    }
+
+    /// Tried in order, for columns with mixed date formats. Takes precedence over the chrono pattern.
+    pub fn with_date_patterns(mut self, date_patterns: &[DatePattern]) -> Self {
+        self.options = self.options.with_date_patterns(
+            DatePatterns::empty().with_patterns(self.value_type.clone(), date_patterns),
+        );
+        self
+    }
+
+    /// Cell contents that are treated like an empty cell, e.g. "n/a" or "NULL"
+    pub fn with_none_markers(mut self, none_markers: &[&str]) -> Self {
+        self.options = self.options.with_none_markers(none_markers);
+        self
+    }
+
+    pub fn with_number_format(mut self, number_format: NumberFormat) -> Self {
+        self.options = self.options.with_number_format(number_format);
+        self
+    }
+
+    pub fn with_bool_format(mut self, bool_format: BoolFormat) -> Self {
+        self.options = self.options.with_bool_format(bool_format);
+        self
+    }
+
+    /// Parses a single cell. The error is the plain parsing error, i.e. without any column information.
+    pub fn parse(&self, v: &str) -> Result<Value> {
+        Value::parse(v, &self.value_type, &self.options)
+    }
+}
+
+/// The columns of a record, e.g. the lines of a CSV file.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Schema {
+    pub columns: Vec<Column>,
+}
+
+impl Schema {
+    pub fn new(columns: Vec<Column>) -> Self {
+        Schema { columns }
+    }
+
+    /// Parses every cell of the record with its column. Fails on the first cell that can't be parsed, with
+    /// `ParseError::ColumnFailed` (or `ParseError::NullInNonNullableColumn`) telling which column it was.
+    pub fn parse_record<S: AsRef<str>>(&self, record: &[S]) -> Result<Vec<Value>> {
+        if record.len() != self.columns.len() {
+            return Err(VenumError::Parsing(ParseError::RecordLengthMismatch {
+                expected: self.columns.len(),
+                actual: record.len(),
+            }));
+        }
+        self.columns
+            .iter()
+            .zip(record)
+            .enumerate()
+            .map(|(column_index, (column, cell))| {
+                let cell = cell.as_ref();
+                let v = column.parse(cell).map_err(|err| {
+                    VenumError::Parsing(ParseError::ColumnFailed {
+                        column_index,
+                        column_name: column.name.clone(),
+                        src_value: String::from(cell),
+                        details: Box::new(err),
+                    })
+                })?;
+                if v.is_none() && !column.nullable {
+                    return Err(VenumError::Parsing(ParseError::NullInNonNullableColumn {
+                        column_index,
+                        column_name: column.name.clone(),
+                        src_value: String::from(cell),
+                    }));
+                }
+                Ok(v)
+            })
+            .collect()
+    }
+
+    /// Parses the rows one by one, see: `parse_record`. A failing row doesn't end the iteration.
+    pub fn parse_records<I, R, S>(&self, rows: I) -> ParseRecords<'_, I::IntoIter, S>
+    where
+        I: IntoIterator<Item = R>,
+        R: AsRef<[S]>,
+        S: AsRef<str>,
+    {
+        ParseRecords {
+            schema: self,
+            rows: rows.into_iter(),
+            cell_type: PhantomData,
+        }
+    }
+}
+
+/// Iterator over parsed rows, see: `Schema::parse_records`
+pub struct ParseRecords<'a, I, S> {
+    schema: &'a Schema,
+    rows: I,
+    cell_type: PhantomData<fn() -> S>,
+}
+
+impl<I, R, S> Iterator for ParseRecords<'_, I, S>
+where
+    I: Iterator<Item = R>,
+    R: AsRef<[S]>,
+    S: AsRef<str>,
+{
+    type Item = Result<Vec<Value>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows
+            .next()
+            .map(|row| self.schema.parse_record(row.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    fn schema() -> Schema {
+        Schema::new(vec![
+            Column::new("id", ValueType::UInt32).not_null(),
+            Column::new("amount", ValueType::Decimal).with_none_markers(&["n/a"]),
+            Column::new("booked", ValueType::NaiveDate).with_chrono_pattern("%d.%m.%Y"),
+        ])
+    }
+
+    #[test]
+    pub fn parse_record() {
+        assert_eq!(
+            Ok(vec![
+                Value::UInt32(1),
+                Value::Decimal(Decimal::new(1050, 2)),
+                Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap()),
+            ]),
+            schema().parse_record(&["1", "10.50", "31.12.2022"])
+        );
+        assert_eq!(
+            Ok(vec![
+                Value::UInt32(2),
+                Value::Null(ValueType::Decimal),
+                Value::Null(ValueType::NaiveDate),
+            ]),
+            schema().parse_record(&["2", "n/a", ""])
+        );
+    }
+
+    #[test]
+    pub fn parse_record_column_failed() {
+        assert_eq!(
+            Err(VenumError::Parsing(ParseError::ColumnFailed {
+                column_index: 2,
+                column_name: String::from("booked"),
+                src_value: String::from("2022-12-31"),
+                details: Box::new(VenumError::Parsing(ParseError::ValueFromStringFailed {
+                    src_value: String::from("2022-12-31"),
+                    target_type: String::from("Value::NaiveDate"),
+                    details: Some(String::from(
+                        "Chrono pattern: %d.%m.%Y. Original error: input contains invalid characters"
+                    )),
+                })),
+            })),
+            schema().parse_record(&["1", "10.50", "2022-12-31"])
+        );
+    }
+
+    #[test]
+    pub fn parse_record_null_in_non_nullable_column() {
+        assert_eq!(
+            Err(VenumError::Parsing(ParseError::NullInNonNullableColumn {
+                column_index: 0,
+                column_name: String::from("id"),
+                src_value: String::new(),
+            })),
+            schema().parse_record(&["", "10.50", "31.12.2022"])
+        );
+    }
+
+    #[test]
+    pub fn parse_record_length_mismatch() {
+        assert_eq!(
+            Err(VenumError::Parsing(ParseError::RecordLengthMismatch {
+                expected: 3,
+                actual: 2,
+            })),
+            schema().parse_record(&["1", "10.50"])
+        );
+    }
+
+    #[test]
+    pub fn parse_record_with_number_format() {
+        let schema = Schema::new(vec![
+            Column::new("amount", ValueType::Decimal)
+                .with_number_format(NumberFormat::decimal_comma())
+                .with_none_markers(&["n/a"]),
+            Column::new("label", ValueType::String)
+                .with_number_format(NumberFormat::decimal_comma()),
+        ]);
+        assert_eq!(
+            Ok(vec![
+                Value::Decimal(Decimal::new(123456, 2)),
+                Value::String(String::from("1.234,56")),
+            ]),
+            schema.parse_record(&["1.234,56", "1.234,56"])
+        );
+        assert_eq!(
+            Ok(vec![
+                Value::Null(ValueType::Decimal),
+                Value::String(String::from("n/a")),
+            ]),
+            schema.parse_record(&["n/a", "n/a"])
+        );
+    }
+
+    #[test]
+    pub fn parse_record_with_bool_format() {
+        let schema = Schema::new(vec![
+            Column::new("active", ValueType::Bool)
+                .with_bool_format(BoolFormat::new(&["Y"], &["N"]))
+                .with_none_markers(&["-"]),
+            Column::new("flag", ValueType::String).with_bool_format(BoolFormat::common()),
+        ]);
+        assert_eq!(
+            Ok(vec![Value::Bool(true), Value::String(String::from("Y"))]),
+            schema.parse_record(&["Y", "Y"])
+        );
+        assert_eq!(
+            Ok(vec![
+                Value::Null(ValueType::Bool),
+                Value::String(String::from("-"))
+            ]),
+            schema.parse_record(&["-", "-"])
+        );
+        assert!(schema.parse_record(&["true", "true"]).is_err());
+    }
+
+    #[test]
+    pub fn parse_record_with_date_patterns() {
+        let schema = Schema::new(vec![Column::new("booked", ValueType::NaiveDate)
+            .with_date_patterns(&[
+                DatePattern::from("%d.%m.%Y"),
+                DatePattern::from("%m/%d/%Y"),
+            ])]);
+        let expected = Ok(vec![Value::NaiveDate(
+            NaiveDate::from_ymd_opt(2022, 12, 31).unwrap(),
+        )]);
+        assert_eq!(expected, schema.parse_record(&["31.12.2022"]));
+        assert_eq!(expected, schema.parse_record(&["12/31/2022"]));
+        assert_eq!(
+            Ok(vec![Value::Null(ValueType::NaiveDate)]),
+            schema.parse_record(&[""])
+        );
+        assert!(schema.parse_record(&["2022-12-31"]).is_err());
+    }
+
+    #[test]
+    pub fn parse_records() {
+        let rows = vec![
+            vec!["1", "10.50", "31.12.2022"],
+            vec!["x", "10.50", "31.12.2022"],
+            vec!["3", "", ""],
+        ];
+        let schema = schema();
+        let parsed: Vec<Result<Vec<Value>>> = schema.parse_records(&rows).collect();
+        assert_eq!(3, parsed.len());
+        assert!(parsed[0].is_ok());
+        assert!(matches!(
+            parsed[1],
+            Err(VenumError::Parsing(ParseError::ColumnFailed {
+                column_index: 0,
+                ..
+            }))
+        ));
+        assert_eq!(
+            Ok(vec![
+                Value::UInt32(3),
+                Value::Null(ValueType::Decimal),
+                Value::Null(ValueType::NaiveDate),
+            ]),
+            parsed[2]
+        );
+
+        // owned strings work, too
+        let owned_rows = vec![vec![String::from("4"), String::new(), String::new()]];
+        assert_eq!(
+            1,
+            schema
+                .parse_records(owned_rows)
+                .filter(Result::is_ok)
+                .count()
+        );
+    }
+}
@@ -0,0 +1,689 @@
+//! Alternative serde representations for `Value`, besides the derived, externally tagged one (`{"Int32": 5}`).
+//! To be used with `#[serde(with = "...")]`, e.g.:
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Cell {
+//!     #[serde(with = "venum::value_serde::compact")]
+//!     value: Value,
+//! }
+//! ```
+//!
+//! - `untagged`: plain values, i.e. `5`, `"abc"` or `null`. Lossy, the type is inferred on deserialization.
+//! - `compact`: `{"t":"i32","v":5}`, round-trips every variant exactly.
+use chrono::SecondsFormat;
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+
+use crate::value::Value;
+use crate::value_duration;
+use crate::value_type::ValueType;
+
+// More precise than the formats used in value_conversions, as we need exact round trips.
+const NAIVE_DATE_FORMAT: &str = "%Y-%m-%d";
+const NAIVE_DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+const NAIVE_TIME_FORMAT: &str = "%H:%M:%S%.f";
+
+// Everything that has no natural representation in serde's data model, as string.
+pub(crate) fn as_string(v: &Value) -> Option<String> {
+    match v {
+        Value::Decimal(v) => Some(v.to_string()),
+        Value::NaiveDate(v) => Some(v.format(NAIVE_DATE_FORMAT).to_string()),
+        Value::NaiveDateTime(v) => Some(v.format(NAIVE_DATE_TIME_FORMAT).to_string()),
+        Value::DateTime(v) => Some(v.to_rfc3339_opts(SecondsFormat::AutoSi, false)),
+        Value::NaiveTime(v) => Some(v.format(NAIVE_TIME_FORMAT).to_string()),
+        Value::Duration(v) => Some(value_duration::format_iso8601(v)),
+        Value::Bytes(v) => Some(v.iter().map(|b| format!("{:02x}", b)).collect()),
+        #[cfg(feature = "uuid")]
+        Value::Uuid(v) => Some(v.hyphenated().to_string()),
+        _ => None,
+    }
+}
+
+/// Plain values without any type information, i.e. `5`, `"abc"`, `[1, "a"]` or `null`.
+/// - `Value::None` and typed nulls become unit (`null`)
+/// - decimals, dates, times, durations, bytes (hex) and uuids become strings
+///
+/// On deserialization, the type is inferred from what the format gives us, i.e. for JSON:
+/// - `null` becomes `Value::None`, arrays become lists, objects become maps
+/// - numbers become `Int64` if they fit, then `UInt64`, otherwise `Float64`
+/// - strings stay strings
+pub mod untagged {
+    use std::collections::BTreeMap;
+    use std::fmt;
+
+    use serde::de::{Error, MapAccess, SeqAccess, Unexpected, Visitor};
+    use serde::{Deserialize, Deserializer};
+
+    use super::*;
+
+    struct Untagged<'a>(&'a Value);
+
+    impl Serialize for Untagged<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize(self.0, serializer)
+        }
+    }
+
+    pub fn serialize<S: Serializer>(v: &Value, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Some(s) = as_string(v) {
+            return serializer.serialize_str(&s);
+        }
+        match v {
+            Value::None | Value::Null(_) => serializer.serialize_unit(),
+            Value::Char(v) => serializer.serialize_char(*v),
+            Value::String(v) => serializer.serialize_str(v),
+            Value::Int8(v) => serializer.serialize_i8(*v),
+            Value::Int16(v) => serializer.serialize_i16(*v),
+            Value::Int32(v) => serializer.serialize_i32(*v),
+            Value::Int64(v) => serializer.serialize_i64(*v),
+            Value::Int128(v) => serializer.serialize_i128(*v),
+            Value::UInt8(v) => serializer.serialize_u8(*v),
+            Value::UInt16(v) => serializer.serialize_u16(*v),
+            Value::UInt32(v) => serializer.serialize_u32(*v),
+            Value::UInt64(v) => serializer.serialize_u64(*v),
+            Value::UInt128(v) => serializer.serialize_u128(*v),
+            Value::Float32(v) => serializer.serialize_f32(*v),
+            Value::Float64(v) => serializer.serialize_f64(*v),
+            Value::Bool(v) => serializer.serialize_bool(*v),
+            Value::List(v) => {
+                let mut seq = serializer.serialize_seq(Some(v.len()))?;
+                for e in v {
+                    seq.serialize_element(&Untagged(e))?;
+                }
+                seq.end()
+            }
+            Value::Map(v) => {
+                let mut map = serializer.serialize_map(Some(v.len()))?;
+                for (k, v) in v {
+                    map.serialize_entry(k, &Untagged(v))?;
+                }
+                map.end()
+            }
+            _ => unreachable!("handled by as_string"),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(UntaggedVisitor)
+    }
+
+    struct UntaggedOwned(Value);
+
+    impl<'de> Deserialize<'de> for UntaggedOwned {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize(deserializer).map(UntaggedOwned)
+        }
+    }
+
+    // serde_json, with its arbitrary_precision feature enabled (e.g. by rust_decimal/serde-with-arbitrary-precision),
+    // hands out numbers as a map with this single key and the number as string.
+    const ARBITRARY_PRECISION_NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+    fn number_from_str(v: &str) -> Option<Value> {
+        if let Ok(v) = v.parse::<i64>() {
+            Some(Value::Int64(v))
+        } else if let Ok(v) = v.parse::<u64>() {
+            Some(Value::UInt64(v))
+        } else {
+            v.parse::<f64>().ok().map(Value::Float64)
+        }
+    }
+
+    struct UntaggedVisitor;
+
+    impl<'de> Visitor<'de> for UntaggedVisitor {
+        type Value = Value;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("any value")
+        }
+
+        fn visit_bool<E>(self, v: bool) -> Result<Value, E> {
+            Ok(Value::Bool(v))
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Value, E> {
+            Ok(Value::Int64(v))
+        }
+
+        fn visit_i128<E>(self, v: i128) -> Result<Value, E> {
+            Ok(Value::Int128(v))
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Value, E> {
+            Ok(i64::try_from(v).map_or(Value::UInt64(v), Value::Int64))
+        }
+
+        fn visit_u128<E>(self, v: u128) -> Result<Value, E> {
+            Ok(Value::UInt128(v))
+        }
+
+        fn visit_f32<E>(self, v: f32) -> Result<Value, E> {
+            Ok(Value::Float32(v))
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Value, E> {
+            Ok(Value::Float64(v))
+        }
+
+        fn visit_char<E>(self, v: char) -> Result<Value, E> {
+            Ok(Value::Char(v))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Value, E> {
+            Ok(Value::String(String::from(v)))
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Value, E> {
+            Ok(Value::String(v))
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Value, E> {
+            Ok(Value::Bytes(v.to_vec()))
+        }
+
+        fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Value, E> {
+            Ok(Value::Bytes(v))
+        }
+
+        fn visit_none<E>(self) -> Result<Value, E> {
+            Ok(Value::None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+            deserialize(deserializer)
+        }
+
+        fn visit_unit<E>(self) -> Result<Value, E> {
+            Ok(Value::None)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+            let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(UntaggedOwned(e)) = seq.next_element()? {
+                elements.push(e);
+            }
+            Ok(Value::List(elements))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+            let mut entries = BTreeMap::new();
+            while let Some(k) = map.next_key::<String>()? {
+                if k == ARBITRARY_PRECISION_NUMBER_TOKEN && entries.is_empty() {
+                    let number = map.next_value::<String>()?;
+                    return number_from_str(&number).ok_or_else(|| {
+                        A::Error::invalid_value(Unexpected::Str(&number), &"a number")
+                    });
+                }
+                let UntaggedOwned(v) = map.next_value()?;
+                entries.insert(k, v);
+            }
+            Ok(Value::Map(entries))
+        }
+    }
+}
+
+/// The type tag used by `compact`, e.g. "i32" for `ValueType::Int32` or "list<i32>" for `ValueType::List(Int32)`.
+pub fn type_tag(t: &ValueType) -> String {
+    match t {
+        ValueType::Char => String::from("char"),
+        ValueType::String => String::from("str"),
+        ValueType::Int8 => String::from("i8"),
+        ValueType::Int16 => String::from("i16"),
+        ValueType::Int32 => String::from("i32"),
+        ValueType::Int64 => String::from("i64"),
+        ValueType::Int128 => String::from("i128"),
+        ValueType::UInt8 => String::from("u8"),
+        ValueType::UInt16 => String::from("u16"),
+        ValueType::UInt32 => String::from("u32"),
+        ValueType::UInt64 => String::from("u64"),
+        ValueType::UInt128 => String::from("u128"),
+        ValueType::Float32 => String::from("f32"),
+        ValueType::Float64 => String::from("f64"),
+        ValueType::Bool => String::from("bool"),
+        ValueType::Decimal => String::from("dec"),
+        ValueType::NaiveDate => String::from("date"),
+        ValueType::NaiveDateTime => String::from("datetime"),
+        ValueType::DateTime => String::from("datetime_tz"),
+        ValueType::NaiveTime => String::from("time"),
+        ValueType::Duration => String::from("duration"),
+        ValueType::List(element_type) => format!("list<{}>", type_tag(element_type)),
+        ValueType::Map => String::from("map"),
+        ValueType::Bytes => String::from("bytes"),
+        #[cfg(feature = "uuid")]
+        ValueType::Uuid => String::from("uuid"),
+    }
+}
+
+/// The inverse of `type_tag`.
+pub fn parse_type_tag(tag: &str) -> Option<ValueType> {
+    match tag {
+        "char" => Some(ValueType::Char),
+        "str" => Some(ValueType::String),
+        "i8" => Some(ValueType::Int8),
+        "i16" => Some(ValueType::Int16),
+        "i32" => Some(ValueType::Int32),
+        "i64" => Some(ValueType::Int64),
+        "i128" => Some(ValueType::Int128),
+        "u8" => Some(ValueType::UInt8),
+        "u16" => Some(ValueType::UInt16),
+        "u32" => Some(ValueType::UInt32),
+        "u64" => Some(ValueType::UInt64),
+        "u128" => Some(ValueType::UInt128),
+        "f32" => Some(ValueType::Float32),
+        "f64" => Some(ValueType::Float64),
+        "bool" => Some(ValueType::Bool),
+        "dec" => Some(ValueType::Decimal),
+        "date" => Some(ValueType::NaiveDate),
+        "datetime" => Some(ValueType::NaiveDateTime),
+        "datetime_tz" => Some(ValueType::DateTime),
+        "time" => Some(ValueType::NaiveTime),
+        "duration" => Some(ValueType::Duration),
+        "map" => Some(ValueType::Map),
+        "bytes" => Some(ValueType::Bytes),
+        #[cfg(feature = "uuid")]
+        "uuid" => Some(ValueType::Uuid),
+        _ => {
+            let element_tag = tag.strip_prefix("list<")?.strip_suffix('>')?;
+            Some(ValueType::List(Box::new(parse_type_tag(element_tag)?)))
+        }
+    }
+}
+
+/// A type tag plus the value, i.e. `{"t":"i32","v":5}`. Round-trips every variant exactly:
+/// - `Value::None` is `{"t":"none","v":null}`, typed nulls are `{"t":"i32","v":null}`
+/// - lists are `{"t":"list","v":[...]}` and maps `{"t":"map","v":{...}}`, where the elements are tagged themselves
+/// - `Int128`, `UInt128` and `Decimal` are strings, as JSON numbers can't hold them (exactly)
+/// - non-finite floats are the strings "NaN", "inf" and "-inf"
+/// - dates and times are strings with full (nanosecond) precision, bytes are hex strings
+///
+/// NOTE: On deserialization, the order of "t" and "v" doesn't matter. Ints may also be given as strings.
+pub mod compact {
+    use std::collections::BTreeMap;
+
+    use rust_decimal::Decimal;
+    use serde::de::Error;
+    use serde::Deserializer;
+
+    use super::*;
+
+    const TAG: &str = "t";
+    const VALUE: &str = "v";
+
+    struct Compact<'a>(&'a Value);
+
+    impl Serialize for Compact<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize(self.0, serializer)
+        }
+    }
+
+    struct Float<T>(T);
+
+    impl<T: Serialize + ToString + Copy + Into<f64>> Serialize for Float<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if self.0.into().is_finite() {
+                self.0.serialize(serializer)
+            } else {
+                serializer.serialize_str(&self.0.to_string())
+            }
+        }
+    }
+
+    pub fn serialize<S: Serializer>(v: &Value, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        match v {
+            Value::None => map.serialize_entry(TAG, "none")?,
+            Value::Null(t) => map.serialize_entry(TAG, &type_tag(t))?,
+            Value::List(_) => map.serialize_entry(TAG, "list")?,
+            _ => {
+                let t = ValueType::try_from(v).expect("only lists and nulls can be untyped");
+                map.serialize_entry(TAG, &type_tag(&t))?
+            }
+        }
+        match v {
+            Value::None | Value::Null(_) => map.serialize_entry(VALUE, &())?,
+            Value::Char(v) => map.serialize_entry(VALUE, v)?,
+            Value::String(v) => map.serialize_entry(VALUE, v)?,
+            Value::Int8(v) => map.serialize_entry(VALUE, v)?,
+            Value::Int16(v) => map.serialize_entry(VALUE, v)?,
+            Value::Int32(v) => map.serialize_entry(VALUE, v)?,
+            Value::Int64(v) => map.serialize_entry(VALUE, v)?,
+            Value::Int128(v) => map.serialize_entry(VALUE, &v.to_string())?,
+            Value::UInt8(v) => map.serialize_entry(VALUE, v)?,
+            Value::UInt16(v) => map.serialize_entry(VALUE, v)?,
+            Value::UInt32(v) => map.serialize_entry(VALUE, v)?,
+            Value::UInt64(v) => map.serialize_entry(VALUE, v)?,
+            Value::UInt128(v) => map.serialize_entry(VALUE, &v.to_string())?,
+            Value::Float32(v) => map.serialize_entry(VALUE, &Float(*v))?,
+            Value::Float64(v) => map.serialize_entry(VALUE, &Float(*v))?,
+            Value::Bool(v) => map.serialize_entry(VALUE, v)?,
+            Value::List(v) => {
+                map.serialize_entry(VALUE, &v.iter().map(Compact).collect::<Vec<Compact>>())?
+            }
+            Value::Map(v) => map.serialize_entry(
+                VALUE,
+                &v.iter()
+                    .map(|(k, v)| (k, Compact(v)))
+                    .collect::<BTreeMap<&String, Compact>>(),
+            )?,
+            _ => map.serialize_entry(VALUE, &as_string(v).expect("all others are strings"))?,
+        }
+        map.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Value, D::Error> {
+        // We go through the untagged form, so we don't depend on the order of "t" and "v".
+        let untagged = untagged::deserialize(deserializer)?;
+        from_untagged(untagged).map_err(D::Error::custom)
+    }
+
+    fn from_untagged(untagged: Value) -> std::result::Result<Value, String> {
+        let mut entries = match untagged {
+            Value::Map(entries) => entries,
+            other => {
+                return Err(format!(
+                    "expected {{\"t\": .., \"v\": ..}}, got {:?}",
+                    other
+                ))
+            }
+        };
+        let tag = match entries.remove(TAG) {
+            Some(Value::String(tag)) => tag,
+            other => return Err(format!("expected a string as type tag, got {:?}", other)),
+        };
+        let v = entries.remove(VALUE).unwrap_or(Value::None);
+        if let Some(k) = entries.keys().next() {
+            return Err(format!("unexpected key {:?}", k));
+        }
+
+        match (tag.as_str(), v) {
+            ("none", Value::None) => Ok(Value::None),
+            ("list", Value::List(elements)) => elements
+                .into_iter()
+                .map(from_untagged)
+                .collect::<std::result::Result<Vec<Value>, String>>()
+                .map(Value::List),
+            ("map", Value::Map(entries)) => entries
+                .into_iter()
+                .map(|(k, v)| from_untagged(v).map(|v| (k, v)))
+                .collect::<std::result::Result<BTreeMap<String, Value>, String>>()
+                .map(Value::Map),
+            (_, v) => {
+                let t =
+                    parse_type_tag(&tag).ok_or_else(|| format!("unknown type tag {:?}", tag))?;
+                if v == Value::None {
+                    return Ok(Value::Null(t));
+                }
+                scalar_from_untagged(&t, &v)
+                    .ok_or_else(|| format!("{:?} is not a valid {}", v, tag))
+            }
+        }
+    }
+
+    fn scalar_from_untagged(t: &ValueType, v: &Value) -> Option<Value> {
+        match (t, v) {
+            (ValueType::Char, Value::String(s)) => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Some(Value::Char(c)),
+                    _ => None,
+                }
+            }
+            (ValueType::Float32, Value::Float64(f)) => Some(Value::Float32(*f as f32)),
+            (ValueType::Float32, Value::String(s)) => s.parse::<f32>().ok().map(Value::Float32),
+            (ValueType::Float64, Value::String(s)) => s.parse::<f64>().ok().map(Value::Float64),
+            (ValueType::Decimal, Value::String(s)) => {
+                Decimal::from_str_exact(s).ok().map(Value::Decimal)
+            }
+            (ValueType::NaiveDate, Value::String(s)) => {
+                Value::parse_naive_date_from_str(s, NAIVE_DATE_FORMAT).ok()
+            }
+            (ValueType::NaiveDateTime, Value::String(s)) => {
+                Value::parse_naive_date_time_from_str(s, NAIVE_DATE_TIME_FORMAT).ok()
+            }
+            (ValueType::DateTime, Value::String(s)) => {
+                Value::parse_date_time_from_str_rfc3339(s).ok()
+            }
+            (ValueType::NaiveTime, Value::String(s)) => {
+                Value::parse_naive_time_from_str(s, NAIVE_TIME_FORMAT).ok()
+            }
+            (ValueType::Duration, Value::String(s)) => {
+                value_duration::parse_iso8601(s).map(Value::Duration)
+            }
+            (ValueType::Bytes, Value::String(s)) if s.is_empty() => Some(Value::Bytes(vec![])),
+            (ValueType::Bytes, Value::String(s)) => Value::parse_bytes_from_str_hex(s).ok(),
+            #[cfg(feature = "uuid")]
+            (ValueType::Uuid, Value::String(s)) => Value::parse_uuid_from_str(s).ok(),
+            (ValueType::String, Value::String(_)) => Some(v.clone()),
+            (ValueType::String, _) => None, // no stringification of numbers and the like
+            (t, Value::String(s)) if t.is_some_int_type() => Value::from_str_and_type(s, t).ok(),
+            (ValueType::List(_) | ValueType::Map, _) => None, // handled by the caller
+            (t, _) => v.try_convert_to(t).ok(),
+        }
+        .filter(|v| !v.is_none()) // the parse_* functions give back None for ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{Duration, FixedOffset, NaiveDate, NaiveTime};
+    use rust_decimal::Decimal;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct UntaggedCell {
+        #[serde(with = "untagged")]
+        value: Value,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct CompactCell {
+        #[serde(with = "compact")]
+        value: Value,
+    }
+
+    fn all_variants() -> Vec<Value> {
+        #[allow(unused_mut)]
+        let mut values = vec![
+            Value::None,
+            Value::Null(ValueType::Int32),
+            Value::Null(ValueType::List(Box::new(ValueType::Decimal))),
+            Value::Char('a'),
+            Value::String(String::from("abc")),
+            Value::Int8(i8::MIN),
+            Value::Int16(i16::MIN),
+            Value::Int32(i32::MIN),
+            Value::Int64(i64::MIN),
+            Value::Int128(i128::MIN),
+            Value::UInt8(u8::MAX),
+            Value::UInt16(u16::MAX),
+            Value::UInt32(u32::MAX),
+            Value::UInt64(u64::MAX),
+            Value::UInt128(u128::MAX),
+            Value::Float32(1.1),
+            Value::Float32(f32::NEG_INFINITY),
+            Value::Float64(0.1),
+            Value::Float64(f64::INFINITY),
+            Value::Bool(true),
+            Value::Decimal(Decimal::new(110, 2)),
+            Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap()),
+            Value::NaiveDateTime(
+                NaiveDate::from_ymd_opt(2022, 12, 31)
+                    .unwrap()
+                    .and_hms_nano_opt(12, 0, 0, 123_456_789)
+                    .unwrap(),
+            ),
+            Value::DateTime(
+                NaiveDate::from_ymd_opt(2022, 12, 31)
+                    .unwrap()
+                    .and_hms_nano_opt(12, 0, 0, 1)
+                    .unwrap()
+                    .and_local_timezone(FixedOffset::east_opt(5400).unwrap())
+                    .unwrap(),
+            ),
+            Value::NaiveTime(NaiveTime::from_hms_micro_opt(8, 30, 0, 1).unwrap()),
+            Value::Duration(Duration::nanoseconds(-1)),
+            Value::List(vec![]),
+            Value::List(vec![
+                Value::Int8(1),
+                Value::None,
+                Value::String(String::new()),
+            ]),
+            Value::Map(BTreeMap::from([
+                (String::from("a"), Value::Int128(1)),
+                (String::from("b"), Value::List(vec![Value::Bool(false)])),
+            ])),
+            Value::Bytes(vec![]),
+            Value::Bytes(vec![0xca, 0xfe]),
+        ];
+        #[cfg(feature = "uuid")]
+        values.push(Value::Uuid(uuid::Uuid::from_u128(1)));
+        values
+    }
+
+    mod untagged_repr {
+        use super::*;
+
+        #[test]
+        pub fn serialize() {
+            assert_eq!(
+                json!({"value": 5}),
+                serde_json::to_value(UntaggedCell {
+                    value: Value::Int32(5)
+                })
+                .unwrap()
+            );
+            assert_eq!(
+                json!({"value": null}),
+                serde_json::to_value(UntaggedCell {
+                    value: Value::Null(ValueType::Int32)
+                })
+                .unwrap()
+            );
+            assert_eq!(
+                json!({"value": ["abc", "1.10", "2022-12-31", {"a": true}]}),
+                serde_json::to_value(UntaggedCell {
+                    value: Value::List(vec![
+                        Value::String(String::from("abc")),
+                        Value::Decimal(Decimal::new(110, 2)),
+                        Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap()),
+                        Value::Map(BTreeMap::from([(String::from("a"), Value::Bool(true))]))
+                    ])
+                })
+                .unwrap()
+            );
+        }
+
+        #[test]
+        pub fn deserialize() {
+            assert_eq!(
+                UntaggedCell {
+                    value: Value::List(vec![
+                        Value::Int64(-5),
+                        Value::UInt64(u64::MAX),
+                        Value::Float64(1.5),
+                        Value::String(String::from("abc")),
+                        Value::None,
+                        Value::Map(BTreeMap::from([(String::from("a"), Value::Bool(true))]))
+                    ])
+                },
+                serde_json::from_value(json!({
+                    "value": [-5, u64::MAX, 1.5, "abc", null, {"a": true}]
+                }))
+                .unwrap()
+            );
+        }
+    }
+
+    mod compact_repr {
+        use super::*;
+
+        #[test]
+        pub fn serialize() {
+            assert_eq!(
+                json!({"value": {"t": "i32", "v": 5}}),
+                serde_json::to_value(CompactCell {
+                    value: Value::Int32(5)
+                })
+                .unwrap()
+            );
+            assert_eq!(
+                json!({"value": {"t": "i128", "v": "-170141183460469231731687303715884105728"}}),
+                serde_json::to_value(CompactCell {
+                    value: Value::Int128(i128::MIN)
+                })
+                .unwrap()
+            );
+            assert_eq!(
+                json!({"value": {"t": "list", "v": [{"t": "none", "v": null}, {"t": "i8", "v": null}]}}),
+                serde_json::to_value(CompactCell {
+                    value: Value::List(vec![Value::None, Value::Null(ValueType::Int8)])
+                })
+                .unwrap()
+            );
+        }
+
+        #[test]
+        pub fn round_trip_all_variants() {
+            for value in all_variants() {
+                let cell = CompactCell { value };
+                let json = serde_json::to_string(&cell).unwrap();
+                assert_eq!(cell, serde_json::from_str(&json).unwrap(), "{}", json);
+            }
+        }
+
+        #[test]
+        pub fn round_trip_nan() {
+            let json = serde_json::to_string(&CompactCell {
+                value: Value::Float64(f64::NAN),
+            })
+            .unwrap();
+            assert_eq!(r#"{"value":{"t":"f64","v":"NaN"}}"#, json);
+            let cell: CompactCell = serde_json::from_str(&json).unwrap();
+            assert!(matches!(cell.value, Value::Float64(v) if v.is_nan()));
+        }
+
+        #[test]
+        pub fn deserialize_any_order() {
+            assert_eq!(
+                CompactCell {
+                    value: Value::UInt8(5)
+                },
+                serde_json::from_str(r#"{"value": {"v": 5, "t": "u8"}}"#).unwrap()
+            );
+        }
+
+        #[test]
+        pub fn deserialize_err() {
+            for json in [
+                r#"{"value": 5}"#,
+                r#"{"value": {"t": "i8", "v": 300}}"#,
+                r#"{"value": {"t": "i9", "v": 1}}"#,
+                r#"{"value": {"t": "str", "v": 1}}"#,
+                r#"{"value": {"t": "date", "v": ""}}"#,
+                r#"{"value": {"t": "list", "v": [1]}}"#,
+                r#"{"value": {"t": "i8", "v": 1, "x": 1}}"#,
+            ] {
+                assert!(
+                    serde_json::from_str::<CompactCell>(json).is_err(),
+                    "{}",
+                    json
+                );
+            }
+        }
+
+        #[test]
+        pub fn type_tags() {
+            let t = ValueType::List(Box::new(ValueType::List(Box::new(ValueType::DateTime))));
+            assert_eq!("list<list<datetime_tz>>", type_tag(&t));
+            assert_eq!(Some(t), parse_type_tag("list<list<datetime_tz>>"));
+            assert_eq!(None, parse_type_tag("list<>"));
+        }
+    }
+}
@@ -0,0 +1,401 @@
+//! Conversions between `Value` and `serde_json::Value`.
+//!
+//! From `Value` to JSON:
+//! - `None` and typed nulls become `null`
+//! - ints become JSON numbers, except for `Int128` / `UInt128` values that don't fit into an i64 / u64. These
+//!   become strings, as serde_json can't represent them as numbers (without its `arbitrary_precision` feature).
+//! - floats become JSON numbers, NaN and +/-inf become `null` (JSON has no representation for them).
+//!   `Float32` is converted via its shortest string representation, i.e. 1.1f32 becomes 1.1 and not 1.100000023841858.
+//! - `Decimal` becomes a string, e.g. "1.10", so neither precision nor scale get lost on the way through f64.
+//! - dates, times, durations, bytes and uuids become strings, formatted just like `try_convert_to_string` does,
+//!   i.e. "%Y-%m-%d" (`DATE_FORMAT`), "%Y-%m-%dT%H:%M:%S%.3f" (`DATE_TIME_FORMAT`), RFC 3339 for `DateTime`,
+//!   ISO-8601 for `Duration` and hex for `Bytes`.
+//! - lists become arrays, maps become objects
+//!
+//! From JSON to `Value` (untyped, see: `TryFrom<serde_json::Value>`):
+//! - `null` becomes `Value::None`, arrays become lists, objects become maps
+//! - numbers become `Int64` if they fit, then `UInt64`, otherwise `Float64`
+//! - strings stay strings. There is no guessing of dates and the like. Use the schema-driven variant
+//!   `Value::try_from_json_with_type` for that.
+use serde_json::{Map, Number};
+
+use crate::errors_result::{ConversionError, Result, VenumError};
+use crate::value::Value;
+use crate::value_type::ValueType;
+
+fn mk_not_rep_err(json: &serde_json::Value, target_type: &ValueType) -> VenumError {
+    VenumError::Conversion(ConversionError::NotRepresentableAs {
+        src: Value::try_from(json.clone()).unwrap_or(Value::None),
+        target_type: target_type.clone(),
+    })
+}
+
+fn float_to_json(v: f64) -> serde_json::Value {
+    Number::from_f64(v).map_or(serde_json::Value::Null, serde_json::Value::Number)
+}
+
+fn as_json_string(v: &Value) -> serde_json::Value {
+    match v.try_convert_to_string() {
+        Ok(Value::String(s)) => serde_json::Value::String(s),
+        _ => unreachable!("all variants, except maps, are convertible to strings"),
+    }
+}
+
+impl From<Value> for serde_json::Value {
+    fn from(item: Value) -> Self {
+        match item {
+            Value::None | Value::Null(_) => serde_json::Value::Null,
+            Value::Char(v) => serde_json::Value::String(v.to_string()),
+            Value::String(v) => serde_json::Value::String(v),
+            Value::Int8(v) => serde_json::Value::from(v),
+            Value::Int16(v) => serde_json::Value::from(v),
+            Value::Int32(v) => serde_json::Value::from(v),
+            Value::Int64(v) => serde_json::Value::from(v),
+            Value::Int128(v) => match i64::try_from(v) {
+                Ok(v) => serde_json::Value::from(v),
+                Err(_) => serde_json::Value::String(v.to_string()),
+            },
+            Value::UInt8(v) => serde_json::Value::from(v),
+            Value::UInt16(v) => serde_json::Value::from(v),
+            Value::UInt32(v) => serde_json::Value::from(v),
+            Value::UInt64(v) => serde_json::Value::from(v),
+            Value::UInt128(v) => match u64::try_from(v) {
+                Ok(v) => serde_json::Value::from(v),
+                Err(_) => serde_json::Value::String(v.to_string()),
+            },
+            Value::Float32(v) => float_to_json(v.to_string().parse::<f64>().unwrap_or(f64::NAN)),
+            Value::Float64(v) => float_to_json(v),
+            Value::Bool(v) => serde_json::Value::Bool(v),
+            Value::Decimal(v) => serde_json::Value::String(v.to_string()),
+            Value::NaiveDate(_)
+            | Value::NaiveDateTime(_)
+            | Value::DateTime(_)
+            | Value::NaiveTime(_)
+            | Value::Duration(_)
+            | Value::Bytes(_) => as_json_string(&item),
+            #[cfg(feature = "uuid")]
+            Value::Uuid(_) => as_json_string(&item),
+            Value::List(v) => {
+                serde_json::Value::Array(v.into_iter().map(serde_json::Value::from).collect())
+            }
+            Value::Map(v) => serde_json::Value::Object(
+                v.into_iter()
+                    .map(|(k, v)| (k, serde_json::Value::from(v)))
+                    .collect::<Map<String, serde_json::Value>>(),
+            ),
+        }
+    }
+}
+
+impl From<&Value> for serde_json::Value {
+    fn from(item: &Value) -> Self {
+        serde_json::Value::from(item.clone())
+    }
+}
+
+impl TryFrom<serde_json::Value> for Value {
+    type Error = VenumError;
+
+    fn try_from(item: serde_json::Value) -> Result<Self> {
+        match item {
+            serde_json::Value::Null => Ok(Value::None),
+            serde_json::Value::Bool(v) => Ok(Value::Bool(v)),
+            serde_json::Value::Number(v) => {
+                if let Some(v) = v.as_i64() {
+                    Ok(Value::Int64(v))
+                } else if let Some(v) = v.as_u64() {
+                    Ok(Value::UInt64(v))
+                } else {
+                    // can only fail with serde_json's arbitrary_precision feature
+                    v.as_f64().map(Value::Float64).ok_or_else(|| {
+                        VenumError::Conversion(ConversionError::Generic {
+                            msg: format!("JSON number {} is not representable as Float64", v),
+                        })
+                    })
+                }
+            }
+            serde_json::Value::String(v) => Ok(Value::String(v)),
+            serde_json::Value::Array(v) => Ok(Value::List(
+                v.into_iter()
+                    .map(Value::try_from)
+                    .collect::<Result<Vec<Value>>>()?,
+            )),
+            serde_json::Value::Object(v) => Ok(Value::Map(
+                v.into_iter()
+                    .map(|(k, v)| Value::try_from(v).map(|v| (k, v)))
+                    .collect::<Result<_>>()?,
+            )),
+        }
+    }
+}
+
+impl Value {
+    /// Converts a JSON value to the given type, i.e. the JSON is read with a schema in mind:
+    /// - `null` becomes a typed null, i.e. `Value::Null(target_type)`
+    /// - strings are parsed (see: `Value::from_str_and_type`), so "2022-12-31" can become a `NaiveDate`,
+    ///   "1.10" a `Decimal` or "170141183460469231731687303715884105727" an `Int128`.
+    ///   An empty string becomes a typed null, except for `ValueType::String`, where it stays an empty string.
+    /// - numbers are converted (see: `Value::try_convert_to`), except for `Decimal` and `Float32`, which are parsed
+    ///   from the textual representation of the number, to not lose precision on the way through f64
+    /// - arrays are converted element-wise for `ValueType::List(element_type)`
+    /// - objects are converted untyped for `ValueType::Map`, as maps don't have a value type
+    pub fn try_from_json_with_type(
+        json: &serde_json::Value,
+        target_type: &ValueType,
+    ) -> Result<Value> {
+        match (json, target_type) {
+            (serde_json::Value::Null, _) => Ok(Value::Null(target_type.clone())),
+            (serde_json::Value::String(v), _) => {
+                if v.is_empty() && *target_type == ValueType::String {
+                    return Ok(Value::String(String::new()));
+                }
+                Value::from_str_and_type(v, target_type)
+                    .map_err(|_err| mk_not_rep_err(json, target_type))
+            }
+            (serde_json::Value::Number(v), ValueType::Decimal | ValueType::Float32) => {
+                Value::from_str_and_type(&v.to_string(), target_type)
+                    .or_else(|_err| Value::parse_decimal_from_str_scientific(&v.to_string()))
+                    .map_err(|_err| mk_not_rep_err(json, target_type))
+            }
+            (serde_json::Value::Array(v), ValueType::List(element_type)) => Ok(Value::List(
+                v.iter()
+                    .map(|e| Value::try_from_json_with_type(e, element_type))
+                    .collect::<Result<Vec<Value>>>()?,
+            )),
+            (serde_json::Value::Object(_), ValueType::Map) => Value::try_from(json.clone()),
+            (serde_json::Value::Bool(_) | serde_json::Value::Number(_), _) => {
+                Value::try_from(json.clone())?
+                    .try_convert_to(target_type)
+                    .map_err(|_err| mk_not_rep_err(json, target_type))
+            }
+            _ => Err(mk_not_rep_err(json, target_type)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{Duration, FixedOffset, NaiveDate};
+    use rust_decimal::Decimal;
+    use serde_json::json;
+
+    use super::*;
+
+    mod to_json {
+        use super::*;
+
+        #[test]
+        pub fn scalars() {
+            assert_eq!(json!(null), serde_json::Value::from(Value::None));
+            assert_eq!(
+                json!(null),
+                serde_json::Value::from(Value::Null(ValueType::Int8))
+            );
+            assert_eq!(json!("a"), serde_json::Value::from(Value::Char('a')));
+            assert_eq!(json!(-1), serde_json::Value::from(Value::Int8(-1)));
+            assert_eq!(json!(1), serde_json::Value::from(Value::UInt64(1)));
+            assert_eq!(json!(true), serde_json::Value::from(Value::Bool(true)));
+            assert_eq!(json!(1.1), serde_json::Value::from(Value::Float32(1.1)));
+            assert_eq!(json!(1.5), serde_json::Value::from(Value::Float64(1.5)));
+            assert_eq!(
+                json!(null),
+                serde_json::Value::from(Value::Float64(f64::NAN))
+            );
+        }
+
+        #[test]
+        pub fn big_ints() {
+            assert_eq!(json!(1), serde_json::Value::from(Value::Int128(1)));
+            assert_eq!(
+                json!("-170141183460469231731687303715884105728"),
+                serde_json::Value::from(Value::Int128(i128::MIN))
+            );
+            assert_eq!(
+                json!(u64::MAX),
+                serde_json::Value::from(Value::UInt128(u128::from(u64::MAX)))
+            );
+            assert_eq!(
+                json!("18446744073709551616"),
+                serde_json::Value::from(Value::UInt128(u128::from(u64::MAX) + 1))
+            );
+        }
+
+        #[test]
+        pub fn stringy() {
+            assert_eq!(
+                json!("1.10"),
+                serde_json::Value::from(Value::Decimal(Decimal::new(110, 2)))
+            );
+            assert_eq!(
+                json!("2022-12-31"),
+                serde_json::Value::from(Value::NaiveDate(
+                    NaiveDate::from_ymd_opt(2022, 12, 31).unwrap()
+                ))
+            );
+            assert_eq!(
+                json!("2022-12-31T12:00:00.000+01:00"),
+                serde_json::Value::from(Value::DateTime(
+                    NaiveDate::from_ymd_opt(2022, 12, 31)
+                        .unwrap()
+                        .and_hms_opt(12, 0, 0)
+                        .unwrap()
+                        .and_local_timezone(FixedOffset::east_opt(3600).unwrap())
+                        .unwrap()
+                ))
+            );
+            assert_eq!(
+                json!("PT1H30M"),
+                serde_json::Value::from(Value::Duration(Duration::try_minutes(90).unwrap()))
+            );
+            assert_eq!(
+                json!("cafe"),
+                serde_json::Value::from(Value::Bytes(vec![0xca, 0xfe]))
+            );
+        }
+
+        #[test]
+        pub fn nested() {
+            assert_eq!(
+                json!({"a": [1, null], "b": {}}),
+                serde_json::Value::from(Value::Map(BTreeMap::from([
+                    (
+                        String::from("a"),
+                        Value::List(vec![Value::Int32(1), Value::None])
+                    ),
+                    (String::from("b"), Value::Map(BTreeMap::new()))
+                ])))
+            );
+        }
+    }
+
+    mod from_json {
+        use super::*;
+
+        #[test]
+        pub fn untyped() {
+            assert_eq!(Ok(Value::None), Value::try_from(json!(null)));
+            assert_eq!(Ok(Value::Bool(true)), Value::try_from(json!(true)));
+            assert_eq!(Ok(Value::Int64(-1)), Value::try_from(json!(-1)));
+            assert_eq!(
+                Ok(Value::UInt64(u64::MAX)),
+                Value::try_from(json!(u64::MAX))
+            );
+            assert_eq!(Ok(Value::Float64(1.5)), Value::try_from(json!(1.5)));
+            assert_eq!(
+                Ok(Value::String(String::from("2022-12-31"))),
+                Value::try_from(json!("2022-12-31"))
+            );
+            assert_eq!(
+                Ok(Value::Map(BTreeMap::from([(
+                    String::from("a"),
+                    Value::List(vec![Value::Int64(1), Value::None])
+                )]))),
+                Value::try_from(json!({"a": [1, null]}))
+            );
+        }
+
+        #[test]
+        pub fn with_type() {
+            assert_eq!(
+                Ok(Value::Null(ValueType::Int8)),
+                Value::try_from_json_with_type(&json!(null), &ValueType::Int8)
+            );
+            assert_eq!(
+                Ok(Value::Int8(1)),
+                Value::try_from_json_with_type(&json!(1), &ValueType::Int8)
+            );
+            assert_eq!(
+                Ok(Value::Int128(i128::MAX)),
+                Value::try_from_json_with_type(
+                    &json!("170141183460469231731687303715884105727"),
+                    &ValueType::Int128
+                )
+            );
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::new(11, 1))),
+                Value::try_from_json_with_type(&json!(1.1), &ValueType::Decimal)
+            );
+            assert_eq!(
+                Ok(Value::Decimal(Decimal::new(110, 2))),
+                Value::try_from_json_with_type(&json!("1.10"), &ValueType::Decimal)
+            );
+            assert_eq!(
+                Ok(Value::NaiveDate(
+                    NaiveDate::from_ymd_opt(2022, 12, 31).unwrap()
+                )),
+                Value::try_from_json_with_type(&json!("2022-12-31"), &ValueType::NaiveDate)
+            );
+            assert_eq!(
+                Ok(Value::String(String::new())),
+                Value::try_from_json_with_type(&json!(""), &ValueType::String)
+            );
+            assert_eq!(
+                Ok(Value::List(vec![
+                    Value::Float64(1.0),
+                    Value::Null(ValueType::Float64)
+                ])),
+                Value::try_from_json_with_type(
+                    &json!([1, null]),
+                    &ValueType::List(Box::new(ValueType::Float64))
+                )
+            );
+        }
+
+        #[test]
+        pub fn with_type_err() {
+            assert_eq!(
+                Err(VenumError::Conversion(
+                    ConversionError::NotRepresentableAs {
+                        src: Value::Int64(300),
+                        target_type: ValueType::Int8
+                    }
+                )),
+                Value::try_from_json_with_type(&json!(300), &ValueType::Int8)
+            );
+            assert_eq!(
+                Err(VenumError::Conversion(
+                    ConversionError::NotRepresentableAs {
+                        src: Value::String(String::from("abc")),
+                        target_type: ValueType::NaiveDate
+                    }
+                )),
+                Value::try_from_json_with_type(&json!("abc"), &ValueType::NaiveDate)
+            );
+            assert_eq!(
+                Err(VenumError::Conversion(
+                    ConversionError::NotRepresentableAs {
+                        src: Value::List(vec![]),
+                        target_type: ValueType::Map
+                    }
+                )),
+                Value::try_from_json_with_type(&json!([]), &ValueType::Map)
+            );
+        }
+
+        #[test]
+        pub fn round_trip() {
+            for value in [
+                Value::Int128(i128::MAX),
+                Value::UInt128(u128::MAX),
+                Value::Decimal(Decimal::new(110, 2)),
+                Value::Float32(1.1),
+                Value::NaiveDate(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap()),
+                Value::Duration(Duration::try_minutes(90).unwrap()),
+                Value::Bytes(vec![0xca, 0xfe]),
+                Value::Null(ValueType::Int32),
+            ] {
+                let value_type = match &value {
+                    Value::Null(value_type) => value_type.clone(),
+                    _ => ValueType::try_from(&value).unwrap(),
+                };
+                let json = serde_json::Value::from(&value);
+                assert_eq!(
+                    Ok(value),
+                    Value::try_from_json_with_type(&json, &value_type)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,465 @@
+use std::collections::BTreeMap;
+
+use serde::ser::{
+    self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+};
+
+use crate::errors_result::{Result, VenumError};
+use crate::value::Value;
+use crate::value_type::ValueType;
+
+/// Turns anything serializable into a `Value`, e.g. a `#[derive(Serialize)]` struct into a `Value::Map`.
+/// See: `ValueSerializer`
+pub fn to_value<T: Serialize + ?Sized>(v: &T) -> Result<Value> {
+    v.serialize(ValueSerializer)
+}
+
+/// A serde Serializer that emits `Value`s:
+/// - primitives become their variant, i.e. `i32` becomes `Value::Int32`, `char` becomes `Value::Char`
+/// - `None` and unit become `Value::None`
+/// - sequences and tuples become lists, maps and structs become maps (keys must be strings, chars, bools or numbers)
+/// - enum variants are externally tagged, i.e. unit variants become strings, all others a map with one entry
+///
+/// NOTE: Types with their own serde impl end up as whatever they serialize to, e.g. chrono's `NaiveDate`
+///       becomes a `Value::String`.
+/// NOTE2: serde serializes a `Vec<u8>` as a sequence, so it becomes a `Value::List` of `UInt8`s here,
+///        unlike `Value::from(Vec<u8>)`, which is a `Value::Bytes`. Only `serialize_bytes` (e.g. via `serde_bytes`)
+///        becomes a `Value::Bytes`.
+pub struct ValueSerializer;
+
+fn tagged(variant: &'static str, v: Value) -> Value {
+    Value::Map(BTreeMap::from([(String::from(variant), v)]))
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = VenumError;
+
+    type SerializeSeq = SerializeList;
+    type SerializeTuple = SerializeList;
+    type SerializeTupleStruct = SerializeList;
+    type SerializeTupleVariant = SerializeTaggedList;
+    type SerializeMap = SerializeEntries;
+    type SerializeStruct = SerializeEntries;
+    type SerializeStructVariant = SerializeTaggedEntries;
+
+    fn serialize_bool(self, v: bool) -> Result<Value> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value> {
+        Ok(Value::Int8(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value> {
+        Ok(Value::Int16(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value> {
+        Ok(Value::Int32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value> {
+        Ok(Value::Int64(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value> {
+        Ok(Value::Int128(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value> {
+        Ok(Value::UInt8(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value> {
+        Ok(Value::UInt16(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value> {
+        Ok(Value::UInt32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value> {
+        Ok(Value::UInt64(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value> {
+        Ok(Value::UInt128(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value> {
+        Ok(Value::Float32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value> {
+        Ok(Value::Float64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value> {
+        Ok(Value::Char(v))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value> {
+        Ok(Value::String(String::from(v)))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value> {
+        Ok(Value::Bytes(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Value> {
+        Ok(Value::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, v: &T) -> Result<Value> {
+        v.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value> {
+        Ok(Value::None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value> {
+        Ok(Value::None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value> {
+        Ok(Value::String(String::from(variant)))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        v: &T,
+    ) -> Result<Value> {
+        v.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        v: &T,
+    ) -> Result<Value> {
+        Ok(tagged(variant, to_value(v)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeList> {
+        Ok(SerializeList {
+            elements: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeList> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SerializeList> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeTaggedList> {
+        Ok(SerializeTaggedList {
+            variant,
+            list: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeEntries> {
+        Ok(SerializeEntries {
+            entries: BTreeMap::new(),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeEntries> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeTaggedEntries> {
+        Ok(SerializeTaggedEntries {
+            variant,
+            entries: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+pub struct SerializeList {
+    elements: Vec<Value>,
+}
+
+impl SerializeSeq for SerializeList {
+    type Ok = Value;
+    type Error = VenumError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<()> {
+        self.elements.push(to_value(v)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value> {
+        Ok(Value::List(self.elements))
+    }
+}
+
+impl SerializeTuple for SerializeList {
+    type Ok = Value;
+    type Error = VenumError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<()> {
+        SerializeSeq::serialize_element(self, v)
+    }
+
+    fn end(self) -> Result<Value> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeTupleStruct for SerializeList {
+    type Ok = Value;
+    type Error = VenumError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<()> {
+        SerializeSeq::serialize_element(self, v)
+    }
+
+    fn end(self) -> Result<Value> {
+        SerializeSeq::end(self)
+    }
+}
+
+pub struct SerializeTaggedList {
+    variant: &'static str,
+    list: SerializeList,
+}
+
+impl SerializeTupleVariant for SerializeTaggedList {
+    type Ok = Value;
+    type Error = VenumError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<()> {
+        SerializeSeq::serialize_element(&mut self.list, v)
+    }
+
+    fn end(self) -> Result<Value> {
+        Ok(tagged(self.variant, SerializeSeq::end(self.list)?))
+    }
+}
+
+pub struct SerializeEntries {
+    entries: BTreeMap<String, Value>,
+    next_key: Option<String>,
+}
+
+impl SerializeMap for SerializeEntries {
+    type Ok = Value;
+    type Error = VenumError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        let key = match to_value(key)? {
+            Value::String(key) => key,
+            key @ (Value::Char(_) | Value::Bool(_)) => key.try_convert_to_string()?.try_into()?,
+            key if ValueType::try_from(&key).map_or(false, |t| t.is_some_numeric_type()) => {
+                key.try_convert_to_string()?.try_into()?
+            }
+            key => {
+                return Err(VenumError::Generic {
+                    msg: format!("map keys must be strings, but got {:?}", key),
+                })
+            }
+        };
+        self.next_key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<()> {
+        let key = self
+            .next_key
+            .take()
+            .expect("serialize_value is called after serialize_key");
+        self.entries.insert(key, to_value(v)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value> {
+        Ok(Value::Map(self.entries))
+    }
+}
+
+impl SerializeStruct for SerializeEntries {
+    type Ok = Value;
+    type Error = VenumError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, v: &T) -> Result<()> {
+        self.entries.insert(String::from(key), to_value(v)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value> {
+        SerializeMap::end(self)
+    }
+}
+
+pub struct SerializeTaggedEntries {
+    variant: &'static str,
+    entries: SerializeEntries,
+}
+
+impl SerializeStructVariant for SerializeTaggedEntries {
+    type Ok = Value;
+    type Error = VenumError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, v: &T) -> Result<()> {
+        SerializeStruct::serialize_field(&mut self.entries, key, v)
+    }
+
+    fn end(self) -> Result<Value> {
+        Ok(tagged(self.variant, SerializeMap::end(self.entries)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    enum Kind {
+        Small,
+        Big(u32),
+        Pair(i8, i8),
+        Named { a: bool },
+    }
+
+    #[derive(Serialize)]
+    struct Record {
+        id: i32,
+        name: &'static str,
+        initial: char,
+        score: Option<f64>,
+        tags: Vec<u16>,
+        kind: Kind,
+    }
+
+    #[test]
+    pub fn primitives() {
+        assert_eq!(Ok(Value::Int32(1)), to_value(&1i32));
+        assert_eq!(Ok(Value::UInt128(u128::MAX)), to_value(&u128::MAX));
+        assert_eq!(Ok(Value::Char('a')), to_value(&'a'));
+        assert_eq!(Ok(Value::String(String::from("a"))), to_value("a"));
+        assert_eq!(Ok(Value::Float32(1.5)), to_value(&1.5f32));
+        assert_eq!(Ok(Value::None), to_value(&None::<i32>));
+        assert_eq!(Ok(Value::Int8(1)), to_value(&Some(1i8)));
+        assert_eq!(Ok(Value::None), to_value(&()));
+    }
+
+    #[test]
+    pub fn record() {
+        let record = Record {
+            id: 1,
+            name: "a",
+            initial: 'a',
+            score: None,
+            tags: vec![1, 2],
+            kind: Kind::Small,
+        };
+        assert_eq!(
+            Ok(Value::Map(BTreeMap::from([
+                (String::from("id"), Value::Int32(1)),
+                (String::from("name"), Value::String(String::from("a"))),
+                (String::from("initial"), Value::Char('a')),
+                (String::from("score"), Value::None),
+                (
+                    String::from("tags"),
+                    Value::List(vec![Value::UInt16(1), Value::UInt16(2)])
+                ),
+                (String::from("kind"), Value::String(String::from("Small"))),
+            ]))),
+            to_value(&record)
+        );
+    }
+
+    #[test]
+    pub fn enums() {
+        let tagged =
+            |variant: &str, v: Value| Value::Map(BTreeMap::from([(String::from(variant), v)]));
+        assert_eq!(Ok(tagged("Big", Value::UInt32(5))), to_value(&Kind::Big(5)));
+        assert_eq!(
+            Ok(tagged(
+                "Pair",
+                Value::List(vec![Value::Int8(1), Value::Int8(2)])
+            )),
+            to_value(&Kind::Pair(1, 2))
+        );
+        assert_eq!(
+            Ok(tagged(
+                "Named",
+                Value::Map(BTreeMap::from([(String::from("a"), Value::Bool(true))]))
+            )),
+            to_value(&Kind::Named { a: true })
+        );
+    }
+
+    #[test]
+    pub fn map_keys() {
+        assert_eq!(
+            Ok(Value::Map(BTreeMap::from([(
+                String::from("1"),
+                Value::Bool(true)
+            )]))),
+            to_value(&HashMap::from([(1u8, true)]))
+        );
+        assert!(matches!(
+            to_value(&HashMap::from([((1u8, 2u8), true)])),
+            Err(VenumError::Generic { .. })
+        ));
+    }
+
+    #[test]
+    pub fn bytes() {
+        assert_eq!(
+            Ok(Value::List(vec![Value::UInt8(1), Value::UInt8(2)])),
+            to_value(&vec![1u8, 2])
+        );
+        assert_eq!(Value::Bytes(vec![1, 2]), Value::from(vec![1u8, 2]));
+        assert_eq!(
+            Ok(Value::Bytes(vec![1, 2])),
+            serde::Serializer::serialize_bytes(ValueSerializer, &[1, 2])
+        );
+    }
+
+    #[test]
+    pub fn round_trip() {
+        let v = to_value(&(1i64, "a", vec![Some(1.5f64), None])).unwrap();
+        assert_eq!(
+            Ok((1i64, String::from("a"), vec![Some(1.5f64), None])),
+            crate::value_impl_deserializer_for_value::from_value(v)
+        );
+        assert_eq!(
+            Ok(ValueType::List(Box::new(ValueType::Int64))),
+            ValueType::try_from(to_value(&[1i64, 2]).unwrap())
+        );
+    }
+}
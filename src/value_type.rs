@@ -5,7 +5,7 @@ use crate::{
     value::Value,
 };
 
-#[derive(Default, Display, Debug, Clone, PartialEq, Eq, PartialOrd)]
+#[derive(Default, Display, Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ValueType {
     Char,
@@ -28,6 +28,13 @@ pub enum ValueType {
     NaiveDate,
     NaiveDateTime,
     DateTime,
+    NaiveTime,
+    Duration,
+    List(Box<ValueType>),
+    Map,
+    Bytes,
+    #[cfg(feature = "uuid")]
+    Uuid,
 }
 
 impl ValueType {
@@ -67,6 +74,95 @@ impl ValueType {
     pub fn is_some_float_type(&self) -> bool {
         matches!(self, ValueType::Float32 | ValueType::Float64)
     }
+
+    pub fn is_some_numeric_type(&self) -> bool {
+        self.is_some_int_type() || self.is_some_float_type() || *self == ValueType::Decimal
+    }
+
+    fn int_bit_width(&self) -> Option<u32> {
+        match self {
+            ValueType::Int8 | ValueType::UInt8 => Some(8),
+            ValueType::Int16 | ValueType::UInt16 => Some(16),
+            ValueType::Int32 | ValueType::UInt32 => Some(32),
+            ValueType::Int64 | ValueType::UInt64 => Some(64),
+            ValueType::Int128 | ValueType::UInt128 => Some(128),
+            _ => None,
+        }
+    }
+
+    fn signed_int_type_with_bit_width(bits: u32) -> ValueType {
+        match bits {
+            0..=8 => ValueType::Int8,
+            9..=16 => ValueType::Int16,
+            17..=32 => ValueType::Int32,
+            33..=64 => ValueType::Int64,
+            _ => ValueType::Int128,
+        }
+    }
+
+    fn unsigned_int_type_with_bit_width(bits: u32) -> ValueType {
+        match bits {
+            0..=8 => ValueType::UInt8,
+            9..=16 => ValueType::UInt16,
+            17..=32 => ValueType::UInt32,
+            33..=64 => ValueType::UInt64,
+            _ => ValueType::UInt128,
+        }
+    }
+
+    /// The type two numeric operands get promoted to (widening lattice), before doing arithmetic on them:
+    /// - same types stay as they are
+    /// - Decimal wins over everything else, i.e. `Int32` and `Float64` become `Decimal`
+    /// - Float64 wins over Float32 and all ints
+    /// - Float32 stays Float32 with 8- and 16-bit ints, otherwise it becomes Float64
+    /// - signed with signed (and unsigned with unsigned) ints become the wider one of both
+    /// - signed with unsigned ints become the smallest signed int that can hold both ranges (capped at `Int128`),
+    ///   i.e. `Int8` and `UInt8` become `Int16`, `Int32` and `UInt64` become `Int128`
+    ///
+    /// Returns `None`, if one of the types is not numeric.
+    /// NOTE: This is only the type. The arithmetic operators promote the actual values exactly, or fail with
+    ///       `ArithmeticError::Overflow`, e.g. `i64::MAX` with a `Float64` (it would be rounded to 2^63),
+    ///       or a `UInt128` bigger than `i128::MAX` with a signed int.
+    pub fn numeric_supertype(&self, other: &ValueType) -> Option<ValueType> {
+        if !self.is_some_numeric_type() || !other.is_some_numeric_type() {
+            return None;
+        }
+        if self == other {
+            return Some(self.clone());
+        }
+        match (self, other) {
+            (ValueType::Decimal, _) | (_, ValueType::Decimal) => Some(ValueType::Decimal),
+            (ValueType::Float64, _) | (_, ValueType::Float64) => Some(ValueType::Float64),
+            (ValueType::Float32, int_type) | (int_type, ValueType::Float32) => {
+                match int_type.int_bit_width() {
+                    Some(bits) if bits <= 16 => Some(ValueType::Float32),
+                    _ => Some(ValueType::Float64),
+                }
+            }
+            _ => {
+                // both are ints at this point
+                let self_bits = self.int_bit_width()?;
+                let other_bits = other.int_bit_width()?;
+                match (
+                    self.is_some_signed_int_type(),
+                    other.is_some_signed_int_type(),
+                ) {
+                    (true, true) => Some(ValueType::signed_int_type_with_bit_width(
+                        self_bits.max(other_bits),
+                    )),
+                    (false, false) => Some(ValueType::unsigned_int_type_with_bit_width(
+                        self_bits.max(other_bits),
+                    )),
+                    (true, false) => Some(ValueType::signed_int_type_with_bit_width(
+                        self_bits.max(2 * other_bits),
+                    )),
+                    (false, true) => Some(ValueType::signed_int_type_with_bit_width(
+                        other_bits.max(2 * self_bits),
+                    )),
+                }
+            }
+        }
+    }
 }
 
 impl TryFrom<&Value> for ValueType {
@@ -80,6 +176,7 @@ impl TryFrom<&Value> for ValueType {
                     value
                 ),
             }),
+            Value::Null(value_type) => Ok(value_type.clone()),
             Value::Char(_) => Ok(ValueType::Char),
             Value::String(_) => Ok(ValueType::String),
             Value::Int8(_) => Ok(ValueType::Int8),
@@ -99,6 +196,23 @@ impl TryFrom<&Value> for ValueType {
             Value::NaiveDate(_) => Ok(ValueType::NaiveDate),
             Value::NaiveDateTime(_) => Ok(ValueType::NaiveDateTime),
             Value::DateTime(_) => Ok(ValueType::DateTime),
+            Value::NaiveTime(_) => Ok(ValueType::NaiveTime),
+            Value::Duration(_) => Ok(ValueType::Duration),
+            Value::Map(_) => Ok(ValueType::Map),
+            Value::Bytes(_) => Ok(ValueType::Bytes),
+            #[cfg(feature = "uuid")]
+            Value::Uuid(_) => Ok(ValueType::Uuid),
+            // The element type is taken from the first element that has a type, i.e. is not `Value::None`.
+            // NOTE: A typed null, i.e. `Value::Null(_)`, does have one!
+            Value::List(elements) => match elements.iter().find(|e| **e != Value::None) {
+                Some(element) => Ok(ValueType::List(Box::new(ValueType::try_from(element)?))),
+                None => Err(VenumError::Generic {
+                    msg: format!(
+                        "Cannot determine the element type of {:?}, as it has no typed elements",
+                        value
+                    ),
+                }),
+            },
         }
     }
 }